@@ -0,0 +1,296 @@
+use std::collections::HashSet;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::AppHandle;
+
+/// Daemon route listing the apps published on the Hugging Face hub
+const CATALOG_PATH: &str = "/api/apps/list-available/hf_space";
+/// Page size requested from the daemon, which may cap its responses
+const DAEMON_PAGE_SIZE: usize = 100;
+/// Upper bound on daemon pages fetched for one listing
+const MAX_DAEMON_PAGES: usize = 50;
+const CATALOG_TIMEOUT: Duration = Duration::from_secs(30);
+
+// ============================================================================
+// APP CATALOG
+// ============================================================================
+
+#[derive(Clone, Copy, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AppSort {
+    /// Most liked first
+    #[default]
+    Popular,
+    /// Most recently created first
+    Recent,
+}
+
+#[derive(Serialize)]
+pub struct AppPage {
+    /// Apps as returned by the daemon
+    pub apps: Vec<Value>,
+    /// Number of apps in the whole catalog
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+/// Read a field from an app entry, or from its "extra" metadata
+fn app_field<'a>(app: &'a Value, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter().find_map(|key| {
+        app.get(*key)
+            .or_else(|| app.get("extra").and_then(|extra| extra.get(*key)))
+    })
+}
+
+fn app_id(app: &Value) -> String {
+    app_field(app, &["id", "name"])
+        .map(|id| id.as_str().map(str::to_string).unwrap_or_else(|| id.to_string()))
+        .unwrap_or_default()
+}
+
+fn app_likes(app: &Value) -> u64 {
+    app_field(app, &["likes"]).and_then(Value::as_u64).unwrap_or(0)
+}
+
+/// ISO 8601 creation date (compares chronologically as a string)
+fn app_created_at(app: &Value) -> String {
+    app_field(app, &["created_at", "createdAt"])
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Fetch the whole catalog from the daemon, page by page
+///
+/// The daemon may cap each response, so pages are requested until one
+/// brings nothing new (also covers daemons ignoring limit/offset).
+fn fetch_catalog(app_handle: &AppHandle) -> Result<Vec<Value>, String> {
+    let mut apps = Vec::new();
+    let mut seen = HashSet::new();
+
+    for page in 0..MAX_DAEMON_PAGES {
+        let path = format!("{}?limit={}&offset={}", CATALOG_PATH, DAEMON_PAGE_SIZE, page * DAEMON_PAGE_SIZE);
+        let response = crate::daemon::api_request(app_handle, "GET", &path, None, CATALOG_TIMEOUT)?;
+        if !response.is_success() {
+            return Err(format!("App catalog request failed (HTTP {}): {}", response.status, response.body.trim()));
+        }
+
+        let page_apps: Vec<Value> = serde_json::from_str(&response.body)
+            .map_err(|e| format!("Invalid app catalog response: {}", e))?;
+        let page_len = page_apps.len();
+
+        let before = apps.len();
+        for app in page_apps {
+            if seen.insert(app_id(&app)) {
+                apps.push(app);
+            }
+        }
+
+        if page_len < DAEMON_PAGE_SIZE || apps.len() == before {
+            break;
+        }
+    }
+
+    Ok(apps)
+}
+
+/// List app-store apps, sorted by likes or creation date, one page at a time
+#[tauri::command]
+pub async fn list_store_apps(
+    app_handle: AppHandle,
+    sort: Option<AppSort>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+) -> Result<AppPage, String> {
+    let sort = sort.unwrap_or_default();
+    let limit = limit.unwrap_or(20);
+    let offset = offset.unwrap_or(0);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut apps = fetch_catalog(&app_handle)?;
+
+        match sort {
+            AppSort::Popular => apps.sort_by_key(|app| std::cmp::Reverse(app_likes(app))),
+            AppSort::Recent => apps.sort_by_key(|app| std::cmp::Reverse(app_created_at(app))),
+        }
+
+        let total = apps.len();
+        let apps = apps.into_iter().skip(offset).take(limit).collect();
+
+        Ok(AppPage { apps, total, offset, limit })
+    })
+    .await
+    .map_err(|e| format!("Failed to execute app listing task: {}", e))?
+}
+
+// ============================================================================
+// APP MANAGEMENT
+// ============================================================================
+
+const INSTALL_PATH: &str = "/api/apps/install";
+const REMOVE_PATH: &str = "/api/apps/remove";
+const JOB_STATUS_PATH: &str = "/api/apps/job-status";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const JOB_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Give up on a job that hasn't reached "done" or "failed" after this long
+const JOB_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+/// Payload of the "app-job-progress" and "app-job-finished" events
+#[derive(Clone, Serialize)]
+pub struct AppJobEvent {
+    pub app_id: String,
+    /// "install" or "uninstall"
+    pub action: String,
+    /// Job status reported by the daemon (pending, in_progress, done, failed)
+    pub status: String,
+    /// Output lines produced since the previous event
+    pub logs: Vec<String>,
+    /// Failure reason, on the final event only
+    pub error: Option<String>,
+}
+
+/// Start an app job on the daemon and return its id
+fn start_job(app_handle: &AppHandle, path: &str, body: Option<&str>) -> Result<String, String> {
+    let response = crate::daemon::api_request(app_handle, "POST", path, body, REQUEST_TIMEOUT)?;
+    if !response.is_success() {
+        return Err(format!("HTTP {}: {}", response.status, response.body.trim()));
+    }
+
+    let job: Value = serde_json::from_str(&response.body)
+        .map_err(|e| format!("Invalid job response: {}", e))?;
+    job.get("job_id")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| format!("No job id in daemon response: {}", response.body.trim()))
+}
+
+/// Poll a daemon job until it completes or JOB_TIMEOUT expires, forwarding its progress as events
+fn follow_job(app_handle: AppHandle, app_id: String, action: &'static str, job_id: String) {
+    use tauri::Emitter;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut logs_sent = 0;
+        let start = std::time::Instant::now();
+
+        loop {
+            std::thread::sleep(JOB_POLL_INTERVAL);
+
+            if start.elapsed() >= JOB_TIMEOUT {
+                let error = format!("The {} job for {} didn't finish within {} minutes", action, app_id, JOB_TIMEOUT.as_secs() / 60);
+                println!("[tauri] ❌ {}", error);
+                let _ = app_handle.emit("app-job-finished", AppJobEvent {
+                    app_id: app_id.clone(),
+                    action: action.to_string(),
+                    status: "failed".to_string(),
+                    logs: Vec::new(),
+                    error: Some(error),
+                });
+                return;
+            }
+
+            let path = format!("{}/{}", JOB_STATUS_PATH, job_id);
+            let job = crate::daemon::api_request(&app_handle, "GET", &path, None, REQUEST_TIMEOUT)
+                .and_then(|response| {
+                    if !response.is_success() {
+                        return Err(format!("HTTP {}: {}", response.status, response.body.trim()));
+                    }
+                    serde_json::from_str::<Value>(&response.body)
+                        .map_err(|e| format!("Invalid job status: {}", e))
+                });
+
+            let job = match job {
+                Ok(job) => job,
+                Err(e) => {
+                    let _ = app_handle.emit("app-job-finished", AppJobEvent {
+                        app_id: app_id.clone(),
+                        action: action.to_string(),
+                        status: "failed".to_string(),
+                        logs: Vec::new(),
+                        error: Some(format!("Lost track of {} job for {}: {}", action, app_id, e)),
+                    });
+                    return;
+                }
+            };
+
+            let status = job.get("status").and_then(Value::as_str).unwrap_or("unknown").to_string();
+            let all_logs: Vec<String> = job
+                .get("logs")
+                .and_then(Value::as_array)
+                .map(|logs| logs.iter().filter_map(|l| l.as_str().map(str::to_string)).collect())
+                .unwrap_or_default();
+            let logs = all_logs.get(logs_sent..).map(|l| l.to_vec()).unwrap_or_default();
+            logs_sent = all_logs.len();
+
+            match status.as_str() {
+                "done" | "failed" => {
+                    let error = (status == "failed").then(|| {
+                        let reason = all_logs.last().cloned().unwrap_or_else(|| "unknown error".to_string());
+                        format!("Failed to {} {}: {}", action, app_id, reason)
+                    });
+                    match error {
+                        Some(ref e) => println!("[tauri] ❌ {}", e),
+                        None => println!("[tauri] ✅ App {} {}ed", app_id, action),
+                    }
+                    let _ = app_handle.emit("app-job-finished", AppJobEvent {
+                        app_id: app_id.clone(),
+                        action: action.to_string(),
+                        status,
+                        logs,
+                        error,
+                    });
+                    return;
+                }
+                _ => {
+                    let _ = app_handle.emit("app-job-progress", AppJobEvent {
+                        app_id: app_id.clone(),
+                        action: action.to_string(),
+                        status,
+                        logs,
+                        error: None,
+                    });
+                }
+            }
+        }
+    });
+}
+
+/// Install an app-store app through the daemon
+/// Returns once the install started, progress comes as "app-job-*" events
+#[tauri::command]
+pub async fn install_app(app_handle: AppHandle, space_id: String) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let app = fetch_catalog(&app_handle)?
+            .into_iter()
+            .find(|app| app_id(app) == space_id)
+            .ok_or_else(|| format!("App {} is not in the app store catalog", space_id))?;
+
+        println!("[tauri] 📦 Installing app {}", space_id);
+        let job_id = start_job(&app_handle, INSTALL_PATH, Some(&app.to_string()))
+            .map_err(|e| format!("Failed to install {}: {}", space_id, e))?;
+
+        follow_job(app_handle, space_id, "install", job_id.clone());
+        Ok(job_id)
+    })
+    .await
+    .map_err(|e| format!("Failed to execute app install task: {}", e))?
+}
+
+/// Uninstall an app through the daemon
+/// Returns once the removal started, progress comes as "app-job-*" events
+#[tauri::command]
+pub async fn uninstall_app(app_handle: AppHandle, space_id: String) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        println!("[tauri] 🗑️  Uninstalling app {}", space_id);
+        // Hub ids look like "user/space", keep them a single path segment
+        let path = format!("{}/{}", REMOVE_PATH, space_id.replace('/', "%2F"));
+        let job_id = start_job(&app_handle, &path, None)
+            .map_err(|e| format!("Failed to uninstall {}: {}", space_id, e))?;
+
+        follow_job(app_handle, space_id, "uninstall", job_id.clone());
+        Ok(job_id)
+    })
+    .await
+    .map_err(|e| format!("Failed to execute app uninstall task: {}", e))?
+}
@@ -0,0 +1,351 @@
+use std::path::PathBuf;
+use serde::Serialize;
+
+// ============================================================================
+// SIDECARS
+// ============================================================================
+
+/// Sidecars declared in tauri.conf.json (bundle.externalBin)
+pub const REQUIRED_SIDECARS: &[&str] = &["uv-trampoline"];
+
+#[derive(Clone, Serialize)]
+pub struct SidecarStatus {
+    pub name: String,
+    pub expected_path: String,
+    pub present: bool,
+}
+
+/// Result of the sidecar check run once at startup
+#[derive(Clone, Serialize)]
+pub struct SidecarCheck {
+    pub ok: bool,
+    pub sidecars: Vec<SidecarStatus>,
+    /// What to do about the first missing sidecar, None when all are present
+    pub message: Option<String>,
+}
+
+/// Where the shell plugin runs a sidecar from: next to the app executable
+/// (Contents/MacOS, /usr/bin, the install folder, or target/<profile> in dev)
+fn sidecar_path(name: &str) -> Result<PathBuf, String> {
+    let exe = std::env::current_exe().map_err(|e| format!("Failed to get current executable path: {}", e))?;
+    let dir = exe.parent().ok_or("Failed to get executable directory")?;
+    let file = if cfg!(target_os = "windows") { format!("{}.exe", name) } else { name.to_string() };
+    Ok(dir.join(file))
+}
+
+fn sidecar_status(name: &str) -> SidecarStatus {
+    match sidecar_path(name) {
+        Ok(path) => SidecarStatus {
+            name: name.to_string(),
+            present: path.is_file(),
+            expected_path: path.display().to_string(),
+        },
+        Err(e) => SidecarStatus { name: name.to_string(), expected_path: e, present: false },
+    }
+}
+
+/// Explain a missing sidecar: a broken install for users, a build step in dev
+fn missing_message(status: &SidecarStatus) -> String {
+    if cfg!(debug_assertions) {
+        format!(
+            "Sidecar {} not found at {}. Build it with `yarn build:sidecar-{}` (it is copied from src-tauri/binaries/{}-<target triple>)",
+            status.name,
+            status.expected_path,
+            if cfg!(target_os = "macos") { "macos" } else if cfg!(target_os = "windows") { "windows" } else { "linux" },
+            status.name
+        )
+    } else {
+        format!(
+            "The app bundle is incomplete: {} is missing ({}). Please reinstall Reachy Mini Control.",
+            status.name, status.expected_path
+        )
+    }
+}
+
+/// Check every required sidecar
+pub fn check_sidecars() -> SidecarCheck {
+    let sidecars: Vec<SidecarStatus> = REQUIRED_SIDECARS.iter().map(|name| sidecar_status(name)).collect();
+    let message = sidecars.iter().find(|status| !status.present).map(missing_message);
+
+    match &message {
+        None => println!("[tauri] ✅ All sidecars present"),
+        Some(message) => eprintln!("[tauri] ❌ {}", message),
+    }
+
+    SidecarCheck {
+        ok: message.is_none(),
+        sidecars,
+        message,
+    }
+}
+
+/// Fail with a clear message before spawning a sidecar that isn't there
+/// (the shell plugin only reports a bare "not found" at spawn time)
+pub fn ensure_sidecar(name: &str) -> Result<(), String> {
+    let status = sidecar_status(name);
+    if status.present {
+        Ok(())
+    } else {
+        Err(missing_message(&status))
+    }
+}
+
+/// Sidecar check made at startup, for the UI to show a broken install
+#[tauri::command]
+pub fn get_sidecar_check(check: tauri::State<SidecarCheck>) -> SidecarCheck {
+    check.inner().clone()
+}
+
+// ============================================================================
+// BUNDLE INTEGRITY
+// ============================================================================
+
+/// One resource the app expects to find
+#[derive(Clone, Serialize)]
+pub struct BundleItem {
+    pub name: String,
+    pub expected_path: String,
+    pub present: bool,
+}
+
+#[derive(Clone, Serialize)]
+pub struct BundleIntegrity {
+    pub ok: bool,
+    pub items: Vec<BundleItem>,
+}
+
+fn item(name: &str, path: &std::path::Path, present: bool) -> BundleItem {
+    BundleItem {
+        name: name.to_string(),
+        expected_path: path.display().to_string(),
+        present,
+    }
+}
+
+/// python-entitlements.plist: in the bundle Resources, or in the source tree in dev
+#[cfg(target_os = "macos")]
+fn entitlements_item() -> BundleItem {
+    const NAME: &str = "python-entitlements.plist";
+
+    let candidates = match crate::signing::app_bundle_path() {
+        Some(bundle) => vec![bundle.join("Contents/Resources").join(NAME)],
+        None => {
+            let dir = std::env::current_dir().unwrap_or_default();
+            vec![dir.join(NAME), dir.join("src-tauri").join(NAME), dir.join("../scripts/signing").join(NAME)]
+        }
+    };
+    match candidates.iter().find(|path| path.is_file()) {
+        Some(path) => item(NAME, path, true),
+        None => item(NAME, &candidates[0], false),
+    }
+}
+
+/// Check every resource a working install needs: sidecars, uv, the bundled
+/// interpreter, the venv and, on macOS, the entitlements used to re-sign it
+pub fn check_bundle_integrity() -> BundleIntegrity {
+    let mut items: Vec<BundleItem> = REQUIRED_SIDECARS
+        .iter()
+        .map(|name| {
+            let status = sidecar_status(name);
+            BundleItem { name: status.name, expected_path: status.expected_path, present: status.present }
+        })
+        .collect();
+
+    match crate::python::find_uv_folder() {
+        Ok(uv_folder) => {
+            let uv = uv_folder.join(uv_wrapper::UV_EXE);
+            items.push(item(uv_wrapper::UV_EXE, &uv, uv.is_file()));
+
+            let cpython = uv_wrapper::list_cpython_folders(&uv_folder).ok().and_then(|folders| folders.into_iter().next());
+            match cpython {
+                Some(folder) => items.push(item("cpython", &uv_folder.join(folder), true)),
+                None => items.push(item("cpython", &uv_folder.join("cpython-*"), false)),
+            }
+        }
+        Err(e) => items.push(BundleItem { name: uv_wrapper::UV_EXE.to_string(), expected_path: e, present: false }),
+    }
+
+    match crate::python::find_venv_dir() {
+        Ok(venv) => items.push(item(".venv", &venv, venv.is_dir())),
+        Err(e) => items.push(BundleItem { name: ".venv".to_string(), expected_path: e, present: false }),
+    }
+
+    #[cfg(target_os = "macos")]
+    items.push(entitlements_item());
+
+    let missing: Vec<&str> = items.iter().filter(|item| !item.present).map(|item| item.name.as_str()).collect();
+    if missing.is_empty() {
+        println!("[tauri] ✅ Bundle integrity: {} resources present", items.len());
+    } else {
+        eprintln!("[tauri] ❌ Bundle integrity: missing {}", missing.join(", "));
+    }
+
+    BundleIntegrity {
+        ok: missing.is_empty(),
+        items,
+    }
+}
+
+/// Present/missing report of the resources the app expects
+#[tauri::command]
+pub fn verify_bundle_integrity() -> BundleIntegrity {
+    check_bundle_integrity()
+}
+
+// ============================================================================
+// SIDECAR PERMISSIONS
+// ============================================================================
+
+/// Filesystem state of a bundled binary the app spawns
+#[derive(Clone, Serialize)]
+pub struct BinaryPermissions {
+    pub name: String,
+    pub path: String,
+    pub present: bool,
+    /// Execute bit set (always true on Windows)
+    pub executable: bool,
+    /// Carries com.apple.quarantine (macOS only)
+    pub quarantined: bool,
+    /// What was repaired, e.g. "execute bit" or "quarantine"
+    pub fixed: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct SidecarPermissions {
+    /// Every present binary is executable and not quarantined
+    pub ok: bool,
+    pub binaries: Vec<BinaryPermissions>,
+}
+
+#[cfg(unix)]
+fn is_executable(path: &std::path::Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).map(|meta| meta.permissions().mode() & 0o111 != 0).unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &std::path::Path) -> bool {
+    true
+}
+
+#[cfg(unix)]
+fn set_executable(path: &std::path::Path) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = std::fs::metadata(path).map_err(|e| e.to_string())?.permissions();
+    // Execute for everyone who can read it
+    permissions.set_mode(permissions.mode() | (permissions.mode() & 0o444) >> 2);
+    std::fs::set_permissions(path, permissions).map_err(|e| e.to_string())
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &std::path::Path) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn is_quarantined(path: &std::path::Path) -> bool {
+    std::process::Command::new("xattr")
+        .arg("-p")
+        .arg(crate::signing::QUARANTINE_ATTRIBUTE)
+        .arg(path)
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_os = "macos"))]
+fn is_quarantined(_path: &std::path::Path) -> bool {
+    false
+}
+
+#[cfg(target_os = "macos")]
+fn clear_file_quarantine(path: &std::path::Path) -> Result<(), String> {
+    let output = std::process::Command::new("xattr")
+        .arg("-d")
+        .arg(crate::signing::QUARANTINE_ATTRIBUTE)
+        .arg(path)
+        .output()
+        .map_err(|e| format!("Failed to run xattr: {}", e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn clear_file_quarantine(_path: &std::path::Path) -> Result<(), String> {
+    Ok(())
+}
+
+/// Check one binary and, with `fix`, restore its execute bit and clear its quarantine
+fn binary_permissions(name: &str, path: &std::path::Path, fix: bool) -> BinaryPermissions {
+    let mut binary = BinaryPermissions {
+        name: name.to_string(),
+        path: path.display().to_string(),
+        present: path.is_file(),
+        executable: false,
+        quarantined: false,
+        fixed: Vec::new(),
+        error: None,
+    };
+    if !binary.present {
+        return binary;
+    }
+    binary.executable = is_executable(path);
+    binary.quarantined = is_quarantined(path);
+
+    if fix && !binary.executable {
+        match set_executable(path) {
+            Ok(()) => {
+                binary.executable = true;
+                binary.fixed.push("execute bit".to_string());
+            }
+            Err(e) => binary.error = Some(format!("Failed to make {} executable: {}", binary.path, e)),
+        }
+    }
+    if fix && binary.quarantined {
+        match clear_file_quarantine(path) {
+            Ok(()) => {
+                binary.quarantined = false;
+                binary.fixed.push("quarantine".to_string());
+            }
+            Err(e) => binary.error = Some(format!("Failed to clear the quarantine of {}: {}", binary.path, e)),
+        }
+    }
+    binary
+}
+
+/// Check that the sidecars and uv are executable and, on macOS, not
+/// quarantined; with `fix`, repair them where the app has permission
+pub fn check_sidecar_permissions(fix: bool) -> SidecarPermissions {
+    let mut binaries: Vec<BinaryPermissions> = REQUIRED_SIDECARS
+        .iter()
+        .filter_map(|name| sidecar_path(name).ok().map(|path| binary_permissions(name, &path, fix)))
+        .collect();
+    if let Ok(uv_folder) = crate::python::find_uv_folder() {
+        binaries.push(binary_permissions(uv_wrapper::UV_EXE, &uv_folder.join(uv_wrapper::UV_EXE), fix));
+    }
+
+    for binary in &binaries {
+        if !binary.fixed.is_empty() {
+            println!("[tauri] 🔧 Fixed {} on {}", binary.fixed.join(" and "), binary.path);
+        }
+        if let Some(ref error) = binary.error {
+            eprintln!("[tauri] ❌ {}", error);
+        }
+    }
+
+    SidecarPermissions {
+        ok: binaries.iter().all(|binary| !binary.present || (binary.executable && !binary.quarantined)),
+        binaries,
+    }
+}
+
+/// Restore the execute bit and clear the quarantine of the bundled binaries,
+/// reporting what was fixed
+#[tauri::command]
+pub fn repair_sidecar_permissions() -> SidecarPermissions {
+    check_sidecar_permissions(true)
+}
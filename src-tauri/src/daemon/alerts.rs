@@ -0,0 +1,79 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+use super::DaemonState;
+
+/// Error lines kept as the sample sent with "daemon-error-spike"
+const SAMPLE_SIZE: usize = 5;
+
+/// Recent error lines of the current run, pruned to the alert window
+#[derive(Default)]
+pub struct ErrorRate {
+    recent: VecDeque<(Instant, String)>,
+    /// Set once a spike was reported, cleared when the rate drops below the
+    /// threshold so a lasting flood is reported once
+    alerting: bool,
+}
+
+/// Payload of the "daemon-error-spike" event
+#[derive(Clone, Serialize)]
+pub struct ErrorSpike {
+    pub session_id: u64,
+    /// Error lines seen within the window
+    pub count: usize,
+    pub window_secs: u64,
+    /// Most recent error lines
+    pub sample: Vec<String>,
+}
+
+/// Error-level daemon output: Python log records and tracebacks
+fn is_error_line(line: &str) -> bool {
+    line.contains("ERROR") || line.contains("CRITICAL") || line.trim_start().starts_with("Traceback (most recent call last)")
+}
+
+/// Forget the errors of the previous run
+pub fn reset(state: &State<DaemonState>) {
+    *state.error_rate.lock().unwrap() = ErrorRate::default();
+}
+
+/// Count error lines of the daemon output and emit "daemon-error-spike" when
+/// more than the configured threshold show up within the window
+pub fn observe_line(app_handle: &AppHandle, line: &str) {
+    if !is_error_line(line) {
+        return;
+    }
+    let config = crate::settings::current(app_handle).error_alerts;
+    if !config.enabled {
+        return;
+    }
+
+    let state: State<DaemonState> = app_handle.state();
+    let now = Instant::now();
+    let window = Duration::from_secs(config.window_secs);
+    let spike = {
+        let mut rate = state.error_rate.lock().unwrap();
+        rate.recent.push_back((now, line.to_string()));
+        while rate.recent.front().is_some_and(|(seen, _)| now.duration_since(*seen) > window) {
+            rate.recent.pop_front();
+        }
+
+        let count = rate.recent.len();
+        if count < config.threshold as usize {
+            rate.alerting = false;
+            return;
+        }
+        if std::mem::replace(&mut rate.alerting, true) {
+            return;
+        }
+        ErrorSpike {
+            session_id: *state.session_id.lock().unwrap(),
+            count,
+            window_secs: config.window_secs,
+            sample: rate.recent.iter().rev().take(SAMPLE_SIZE).rev().map(|(_, line)| line.clone()).collect(),
+        }
+    };
+
+    eprintln!("[tauri] 🚨 {} daemon errors within {}s", spike.count, spike.window_secs);
+    let _ = app_handle.emit("daemon-error-spike", spike);
+}
@@ -0,0 +1,133 @@
+//! Minimal HTTP/1.1 client for the daemon's local API (no TLS, Connection: close)
+
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+use super::DaemonEndpoint;
+
+pub enum HttpError {
+    /// Nothing accepts connections on the daemon port (not up yet, or gone)
+    NotListening(String),
+    /// Connected, but the request failed or the response was invalid
+    Failed(String),
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::NotListening(e) => write!(f, "daemon not listening: {}", e),
+            HttpError::Failed(e) => write!(f, "daemon request failed: {}", e),
+        }
+    }
+}
+
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+fn connect(endpoint: &DaemonEndpoint, timeout: Duration) -> Result<TcpStream, HttpError> {
+    let addrs = (endpoint.host.as_str(), endpoint.port)
+        .to_socket_addrs()
+        .map_err(|e| HttpError::Failed(format!("invalid address {}:{}: {}", endpoint.host, endpoint.port, e)))?;
+
+    let mut last_error = format!("no address for {}:{}", endpoint.host, endpoint.port);
+    for addr in addrs {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    Err(HttpError::NotListening(last_error))
+}
+
+/// Decode a "Transfer-Encoding: chunked" body
+fn decode_chunked(mut body: &[u8]) -> Vec<u8> {
+    let mut decoded = Vec::new();
+    while let Some(line_end) = body.windows(2).position(|w| w == b"\r\n") {
+        let size_line = String::from_utf8_lossy(&body[..line_end]);
+        let size = usize::from_str_radix(size_line.split(';').next().unwrap_or("").trim(), 16).unwrap_or(0);
+        if size == 0 {
+            break;
+        }
+        let start = line_end + 2;
+        let end = (start + size).min(body.len());
+        decoded.extend_from_slice(&body[start..end]);
+        body = &body[(end + 2).min(body.len())..];
+    }
+    decoded
+}
+
+fn parse_response(raw: &[u8]) -> Result<HttpResponse, HttpError> {
+    let header_end = raw
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(|| HttpError::Failed("incomplete response".to_string()))?;
+
+    let head = String::from_utf8_lossy(&raw[..header_end]);
+    let mut lines = head.lines();
+
+    // "HTTP/1.1 200 OK"
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| HttpError::Failed("invalid status line".to_string()))?;
+
+    let chunked = lines.any(|line| {
+        let line = line.to_ascii_lowercase();
+        line.starts_with("transfer-encoding:") && line.contains("chunked")
+    });
+
+    let body = &raw[header_end + 4..];
+    let body = if chunked { decode_chunked(body) } else { body.to_vec() };
+
+    Ok(HttpResponse {
+        status,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// Send a request to the daemon API and wait for the full response
+pub fn request(
+    endpoint: &DaemonEndpoint,
+    method: &str,
+    path: &str,
+    json_body: Option<&str>,
+    timeout: Duration,
+) -> Result<HttpResponse, HttpError> {
+    let mut stream = connect(endpoint, timeout)?;
+    let _ = stream.set_read_timeout(Some(timeout));
+    let _ = stream.set_write_timeout(Some(timeout));
+
+    let body = json_body.unwrap_or("");
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}:{}\r\nAccept: application/json\r\nConnection: close\r\n",
+        method, path, endpoint.host, endpoint.port
+    );
+    if json_body.is_some() {
+        request.push_str("Content-Type: application/json\r\n");
+    }
+    request.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| HttpError::Failed(e.to_string()))?;
+
+    let mut raw = Vec::new();
+    stream
+        .read_to_end(&mut raw)
+        .map_err(|e| HttpError::Failed(e.to_string()))?;
+
+    parse_response(&raw)
+}
+
+pub fn get(endpoint: &DaemonEndpoint, path: &str, timeout: Duration) -> Result<HttpResponse, HttpError> {
+    request(endpoint, "GET", path, None, timeout)
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use super::http;
+use super::{is_current_session, DaemonEndpoint};
+
+const STATUS_PATH: &str = "/api/daemon/status";
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Payload of the "robot-link-up" / "robot-link-down" events
+#[derive(Clone, Serialize)]
+pub struct RobotLinkEvent {
+    pub session_id: u64,
+    /// Serial port of the robot, when known
+    pub port: Option<String>,
+    /// Why the link went down: "usb_disconnected", "daemon_disconnected" or "daemon_stopped"
+    pub reason: Option<String>,
+}
+
+/// Whether the robot is still plugged in (the selected one, or any robot)
+fn is_robot_plugged(port: Option<&str>) -> bool {
+    match crate::usb::find_robots() {
+        Ok(robots) => match port {
+            Some(port) => robots.iter().any(|robot| robot.port_name == port),
+            None => !robots.is_empty(),
+        },
+        // Enumeration errors are transient, don't report a disconnect for them
+        Err(_) => true,
+    }
+}
+
+/// Backend state reported by the daemon, None if the API isn't reachable
+fn daemon_backend_running(endpoint: &DaemonEndpoint) -> Option<bool> {
+    let response = http::get(endpoint, STATUS_PATH, REQUEST_TIMEOUT).ok()?;
+    if !response.is_success() {
+        return Some(false);
+    }
+    let status: serde_json::Value = serde_json::from_str(&response.body).ok()?;
+    Some(status.get("state").and_then(|s| s.as_str()) == Some("running"))
+}
+
+/// Track the robot connection of a hardware-mode daemon run
+///
+/// Combines USB presence and the daemon's own status, and emits
+/// "robot-link-up" / "robot-link-down" on every transition.
+pub fn spawn_robot_link_monitor(app_handle: AppHandle, session_id: u64, endpoint: DaemonEndpoint, port: Option<String>) {
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut link_up = false;
+
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+
+            if !is_current_session(&app_handle, session_id) {
+                if link_up {
+                    let _ = app_handle.emit("robot-link-down", RobotLinkEvent {
+                        session_id,
+                        port: port.clone(),
+                        reason: Some("daemon_stopped".to_string()),
+                    });
+                }
+                return;
+            }
+
+            let down_reason = if !is_robot_plugged(port.as_deref()) {
+                Some("usb_disconnected")
+            } else {
+                match daemon_backend_running(&endpoint) {
+                    Some(true) => None,
+                    Some(false) => Some("daemon_disconnected"),
+                    // API not up yet, keep the current state
+                    None if !link_up => continue,
+                    None => Some("daemon_disconnected"),
+                }
+            };
+
+            match (link_up, down_reason) {
+                (false, None) => {
+                    link_up = true;
+                    println!("[tauri] 🤖 Robot link up");
+                    let _ = app_handle.emit("robot-link-up", RobotLinkEvent {
+                        session_id,
+                        port: port.clone(),
+                        reason: None,
+                    });
+                }
+                (true, Some(reason)) => {
+                    link_up = false;
+                    println!("[tauri] ⚠️  Robot link down ({})", reason);
+                    let _ = app_handle.emit("robot-link-down", RobotLinkEvent {
+                        session_id,
+                        port: port.clone(),
+                        reason: Some(reason.to_string()),
+                    });
+                }
+                _ => {}
+            }
+        }
+    });
+}
@@ -0,0 +1,277 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+use crate::settings::LogRetention;
+use super::DaemonState;
+
+pub const LOG_FILE_NAME: &str = "daemon.log";
+/// The log is rotated to daemon.log.1 past this size
+const MAX_LOG_FILE_BYTES: u64 = 5 * 1024 * 1024;
+/// Largest chunk returned by one read_log_file call
+const MAX_READ_BYTES: u64 = 256 * 1024;
+
+/// Location of the log file in the app log dir
+pub fn log_file_path(app_handle: &AppHandle) -> Option<PathBuf> {
+    app_handle
+        .path()
+        .app_log_dir()
+        .ok()
+        .map(|dir| dir.join(LOG_FILE_NAME))
+}
+
+/// Directory of the log file, whether or not file logging is on
+fn log_dir(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    log_file_path(app_handle)
+        .as_deref()
+        .and_then(Path::parent)
+        .map(Path::to_path_buf)
+        .ok_or_else(|| "Log file location is unavailable".to_string())
+}
+
+/// Point the logger at the log file, or stop it writing when logs are off
+pub fn apply_retention(app_handle: &AppHandle, retention: LogRetention) {
+    let state: State<DaemonState> = app_handle.state();
+    let path = match retention {
+        LogRetention::Off => None,
+        LogRetention::Persistent | LogRetention::SessionOnly => log_file_path(app_handle),
+    };
+    println!(
+        "[tauri] 📝 File logging: {}",
+        match retention {
+            LogRetention::Persistent => "kept across runs",
+            LogRetention::SessionOnly => "this session only",
+            LogRetention::Off => "off",
+        }
+    );
+    *state.log_file.lock().unwrap() = path;
+}
+
+#[derive(Serialize)]
+pub struct LogPurge {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Delete the log file, its rotated copy and, with `include_captures`, saved captures
+///
+/// The automatic SessionOnly purge leaves captures alone: the user saved them on purpose.
+pub fn purge(app_handle: &AppHandle, include_captures: bool) -> Result<LogPurge, String> {
+    let dir = log_dir(app_handle)?;
+
+    let mut candidates = vec![dir.join(LOG_FILE_NAME), dir.join(LOG_FILE_NAME).with_extension("log.1")];
+    if include_captures {
+        if let Ok(entries) = std::fs::read_dir(dir.join(CAPTURES_DIR)) {
+            candidates.extend(entries.flatten().map(|entry| entry.path()));
+        }
+    }
+
+    let mut purge = LogPurge { files: 0, bytes: 0 };
+    for path in candidates {
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        std::fs::remove_file(&path).map_err(|e| format!("Failed to delete {}: {}", path.display(), e))?;
+        purge.files += 1;
+        purge.bytes += metadata.len();
+    }
+
+    if purge.files > 0 {
+        println!("[tauri] 🧹 Deleted {} log files ({} bytes)", purge.files, purge.bytes);
+    }
+    Ok(purge)
+}
+
+/// Delete every log written to disk, captures included, returning how many files and bytes were removed
+#[tauri::command]
+pub fn purge_log_files(app_handle: AppHandle) -> Result<LogPurge, String> {
+    purge(&app_handle, true)
+}
+
+/// Move a full log file aside (keeping a single previous file)
+fn rotate_if_needed(path: &Path) {
+    let too_big = std::fs::metadata(path)
+        .map(|metadata| metadata.len() >= MAX_LOG_FILE_BYTES)
+        .unwrap_or(false);
+
+    if too_big {
+        let _ = std::fs::rename(path, path.with_extension("log.1"));
+    }
+}
+
+/// Append a line to the log file, if file logging is available
+pub fn append(state: &State<DaemonState>, line: &str) {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+
+    if let Some(capture) = state.log_capture.lock().unwrap().as_mut() {
+        capture.lines.push(format!("{} {}", timestamp, line));
+    }
+
+    let path = match state.log_file.lock().unwrap().clone() {
+        Some(path) => path,
+        None => return,
+    };
+
+    rotate_if_needed(&path);
+
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+
+    if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(&path) {
+        let _ = writeln!(file, "{} {}", timestamp, line);
+    }
+}
+
+/// Identity of the file currently at the log path, changes on rotation
+#[cfg(unix)]
+fn file_id(metadata: &std::fs::Metadata) -> String {
+    use std::os::unix::fs::MetadataExt;
+    format!("{}:{}", metadata.dev(), metadata.ino())
+}
+
+#[cfg(not(unix))]
+fn file_id(metadata: &std::fs::Metadata) -> String {
+    metadata
+        .created()
+        .ok()
+        .and_then(|created| created.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos().to_string())
+        .unwrap_or_default()
+}
+
+#[derive(Serialize)]
+pub struct LogChunk {
+    /// Log text between the requested offset and `offset`
+    pub content: String,
+    /// Offset to pass to the next call
+    pub offset: u64,
+    /// Identity of the log file, to pass back with the offset
+    pub file_id: String,
+    /// True when reading restarted from 0 (file rotated or truncated)
+    pub reset: bool,
+}
+
+/// Read the log file from a byte offset, for incremental tailing
+///
+/// Pass back the returned `offset` and `file_id`; when the file was rotated
+/// in between, reading restarts from the beginning of the new file.
+/// With retention Off, this reads whatever an earlier run left there.
+#[tauri::command]
+pub fn read_log_file(app_handle: AppHandle, offset: Option<u64>, file_id: Option<String>) -> Result<LogChunk, String> {
+    let path = log_file_path(&app_handle).ok_or("Log file location is unavailable")?;
+
+    let mut file = match std::fs::File::open(&path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(LogChunk { content: String::new(), offset: 0, file_id: String::new(), reset: offset.unwrap_or(0) > 0 });
+        }
+        Err(e) => return Err(format!("Failed to open {}: {}", path.display(), e)),
+    };
+
+    let metadata = file.metadata().map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let current_id = self::file_id(&metadata);
+    let len = metadata.len();
+
+    let requested = offset.unwrap_or(0);
+    let rotated = file_id.as_deref().map(|id| id != current_id).unwrap_or(false);
+    let reset = requested > 0 && (rotated || requested > len);
+    let start = if reset { 0 } else { requested };
+
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let mut bytes = Vec::new();
+    file.take(MAX_READ_BYTES)
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    // When capped, stop after the last complete line so UTF-8 is never split
+    if start + (bytes.len() as u64) < len {
+        if let Some(last_newline) = bytes.iter().rposition(|&b| b == b'\n') {
+            bytes.truncate(last_newline + 1);
+        }
+    }
+
+    Ok(LogChunk {
+        content: String::from_utf8_lossy(&bytes).into_owned(),
+        offset: start + bytes.len() as u64,
+        file_id: current_id,
+        reset,
+    })
+}
+
+// ============================================================================
+// LOG CAPTURE
+// ============================================================================
+
+/// Subdirectory of the log dir holding finished captures
+const CAPTURES_DIR: &str = "captures";
+
+/// Every log line since start_log_capture, without the in-memory buffer limits
+pub struct LogCapture {
+    pub name: String,
+    pub started_at: u64,
+    pub lines: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct CaptureFile {
+    pub path: String,
+    pub lines: usize,
+}
+
+/// Start collecting every log line (app and daemon output) under a name,
+/// e.g. around the reproduction of a bug
+#[tauri::command]
+pub fn start_log_capture(state: State<DaemonState>, name: String) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err("Capture names may only contain letters, digits, '-' and '_'".to_string());
+    }
+
+    let mut capture = state.log_capture.lock().unwrap();
+    if let Some(ref active) = *capture {
+        return Err(format!("Capture '{}' is already running", active.name));
+    }
+
+    let started_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    println!("[tauri] 🎙️  Log capture '{}' started", name);
+    *capture = Some(LogCapture { name, started_at, lines: Vec::new() });
+
+    Ok(())
+}
+
+/// Stop the capture and write it to captures/<name>-<start time>.log in the log dir
+///
+/// Captures are saved on request, so this works even with log retention Off.
+#[tauri::command]
+pub fn stop_log_capture(app_handle: AppHandle) -> Result<CaptureFile, String> {
+    let log_dir = log_dir(&app_handle)?;
+    let state: State<DaemonState> = app_handle.state();
+    let capture = state.log_capture.lock().unwrap().take().ok_or("No log capture is running")?;
+
+    let dir = log_dir.join(CAPTURES_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let path = dir.join(format!("{}-{}.log", capture.name, capture.started_at));
+
+    let mut content = capture.lines.join("\n");
+    content.push('\n');
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("[tauri] 🎙️  Log capture '{}' saved to {} ({} lines)", capture.name, path.display(), capture.lines.len());
+
+    Ok(CaptureFile {
+        path: path.display().to_string(),
+        lines: capture.lines.len(),
+    })
+}
@@ -1,16 +1,228 @@
 use std::sync::Mutex;
 use std::collections::VecDeque;
+use serde::Serialize;
 use tauri::State;
+use crate::settings::ShutdownConfig;
 use tauri_plugin_shell::{
     process::CommandChild,
 };
 
+pub mod alerts;
+pub mod http;
+pub mod link;
+pub mod logfile;
+pub mod pose;
+pub mod readiness;
+pub mod recording;
+pub mod supervisor;
+pub mod viewer;
+pub mod wakeup;
+
+#[derive(Default)]
 pub struct DaemonState {
     pub process: Mutex<Option<CommandChild>>,
-    pub logs: Mutex<VecDeque<String>>,
+    pub logs: Mutex<VecDeque<LogEntry>>,
+    /// Incremented on every daemon start, 0 before the first run
+    pub session_id: Mutex<u64>,
+    /// Recent sidecar output, replayed to late subscribers
+    pub sidecar_output: Mutex<VecDeque<SidecarLine>>,
+    /// When the current daemon was spawned
+    pub started_at: Mutex<Option<std::time::Instant>>,
+    /// Where the launched daemon can be reached (used for probes and cleanup)
+    pub endpoint: Mutex<DaemonEndpoint>,
+    /// Last readiness probe result of the current run, None when not running
+    pub readiness: Mutex<Option<readiness::ReadinessStage>>,
+    /// True when using a daemon started outside the app (developer mode),
+    /// which the app must never kill
+    pub attached: Mutex<bool>,
+    /// How the current daemon was launched, None when not running
+    pub launch: Mutex<Option<DaemonLaunch>>,
+    /// On-disk log (app log dir), None until resolved at setup
+    pub log_file: Mutex<Option<std::path::PathBuf>>,
+    /// Sidecar lines held back while the log stream is paused, None when streaming
+    pub deferred_lines: Mutex<Option<DeferredLines>>,
+    /// True once the robot finished its wake-up motion in the current run
+    pub awake: Mutex<bool>,
+    /// Whether the MuJoCo viewer opened in the current sim run, None until reported
+    pub sim_viewer: Mutex<Option<viewer::SimViewer>>,
+    /// Named capture collecting every log line, None when not capturing
+    pub log_capture: Mutex<Option<logfile::LogCapture>>,
+    /// Command of the most recent spawn, kept after the process exits
+    pub last_launch: Mutex<Option<LastLaunch>>,
+    /// How the most recent daemon process ended, for a UI that missed "sidecar-terminated"
+    pub last_termination: Mutex<Option<LastTermination>>,
+    /// Recent error lines of the current run, for the error spike alert
+    pub error_rate: Mutex<alerts::ErrorRate>,
+    /// Daemon calls since start_api_recording, None when not recording
+    pub api_recording: Mutex<Option<recording::ApiRecording>>,
+    /// Automatic restart after a crash (see supervisor)
+    pub restart: Mutex<supervisor::RestartState>,
+}
+
+/// Exact command used for a daemon spawn, to reproduce startup crashes
+#[derive(Clone, Serialize)]
+pub struct LastLaunch {
+    pub session_id: u64,
+    /// Seconds since the Unix epoch
+    pub launched_at: u64,
+    pub sidecar: String,
+    /// Trampoline arguments (interpreter, module and daemon flags)
+    pub args: Vec<String>,
+    /// Environment variables set for the sidecar (names only, values may be secrets)
+    pub env_keys: Vec<String>,
+    /// Resolved environment of the daemon, secrets redacted (see resolved_daemon_env)
+    pub env: std::collections::BTreeMap<String, String>,
+    pub sim_mode: bool,
+    pub safe_mode: bool,
+}
+
+/// End of a daemon process, as sent with "sidecar-terminated"
+#[derive(Clone, Serialize)]
+pub struct LastTermination {
+    pub session_id: u64,
+    /// Same string as the "sidecar-terminated" payload
+    pub status: String,
+    pub code: Option<i32>,
+    pub signal: Option<i32>,
+    /// Seconds since the Unix epoch
+    pub terminated_at: u64,
+    /// The app stopped or restarted the daemon, as opposed to a crash
+    pub user_requested: bool,
+    /// EXITED_BEFORE_READY for a status 0 exit before the API came up
+    pub error_code: Option<String>,
+    /// Last output lines, kept with an error code
+    pub output: Vec<String>,
+}
+
+/// Error code of a daemon that exited with status 0 before its API came up
+/// (e.g. an argument made it print its help and quit): neither a crash nor a start
+pub const EXITED_BEFORE_READY: &str = "exited_before_ready";
+/// Output lines kept with such an exit
+const EARLY_EXIT_OUTPUT_LINES: usize = 30;
+
+/// Emitted when a daemon dies before its API came up and won't be retried
+#[derive(Clone, Serialize)]
+pub struct DaemonStartFailure {
+    pub session_id: u64,
+    pub error: String,
+    /// EXITED_BEFORE_READY, None for other failures
+    pub error_code: Option<String>,
+    pub launch: Option<LastLaunch>,
+}
+
+/// Launch parameters of the current daemon run
+#[derive(Clone, Serialize)]
+pub struct DaemonLaunch {
+    pub sim_mode: bool,
+    /// Launched with optional subsystems disabled (see build_daemon_args)
+    pub safe_mode: bool,
+    pub serial_port: Option<String>,
+    pub endpoint: DaemonEndpoint,
+    /// Full trampoline command line (interpreter, module and daemon flags)
+    pub args: Vec<String>,
+    /// Current log level, may differ from the launch flag after set_daemon_log_level
+    pub log_level: crate::settings::DaemonLogLevel,
+    /// Capture devices passed to the daemon, None for its defaults (always None in safe mode)
+    pub camera_device: Option<String>,
+    pub microphone_device: Option<String>,
+    /// Directory the daemon runs from, None for the uv folder
+    pub working_dir: Option<std::path::PathBuf>,
+    /// MUJOCO_GL the sim daemon was spawned with, None for MuJoCo's default
+    pub mujoco_gl: Option<String>,
+}
+
+impl DaemonLaunch {
+    /// Daemon flags as JSON: `--flag value` becomes "flag": "value",
+    /// a lone `--flag` becomes "flag": true
+    pub fn flags_json(&self) -> serde_json::Value {
+        let mut flags = serde_json::Map::new();
+        let mut args = self.args.iter().skip_while(|arg| !arg.starts_with("--")).peekable();
+        
+        while let Some(arg) = args.next() {
+            let key = match arg.strip_prefix("--") {
+                Some(key) => key.replace('-', "_"),
+                None => continue,
+            };
+            let value = match args.peek() {
+                Some(next) if !next.starts_with("--") => serde_json::Value::String(args.next().unwrap().clone()),
+                _ => serde_json::Value::Bool(true),
+            };
+            flags.insert(key, value);
+        }
+        
+        serde_json::Value::Object(flags)
+    }
+}
+
+/// Address the app uses to reach the daemon
+#[derive(Clone, Serialize)]
+pub struct DaemonEndpoint {
+    pub host: String,
+    pub port: u16,
+}
+
+impl Default for DaemonEndpoint {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: DAEMON_PORT,
+        }
+    }
+}
+
+impl DaemonEndpoint {
+    /// Endpoint for a daemon bound to `bind_host` (None = localhost only)
+    /// A daemon listening on all interfaces is still reached through loopback
+    pub fn for_bind_host(bind_host: Option<&str>, port: u16) -> Self {
+        let host = match bind_host {
+            None | Some("0.0.0.0") | Some("::") => "127.0.0.1".to_string(),
+            Some(host) => host.to_string(),
+        };
+        Self { host, port }
+    }
+    
+    /// Endpoint the next daemon will be launched on, from the user settings
+    pub fn from_settings(settings: &crate::settings::AppSettings) -> Self {
+        Self::for_bind_host(settings.daemon_host.as_deref(), settings.daemon_port.unwrap_or(DAEMON_PORT))
+    }
+}
+
+#[derive(Clone, Serialize)]
+pub struct SidecarLine {
+    /// "stdout" or "stderr"
+    pub stream: String,
+    pub line: String,
+}
+
+/// Lines received while the log stream is paused, sent as "sidecar-batch" on resume
+#[derive(Clone, Default, Serialize)]
+pub struct DeferredLines {
+    pub lines: VecDeque<SidecarLine>,
+    /// Older lines that didn't fit (still in the log file)
+    pub dropped: usize,
+}
+
+#[derive(Clone, Serialize)]
+pub struct LogEntry {
+    /// Unix millis
+    pub timestamp: u64,
+    /// Daemon run this entry belongs to
+    pub session_id: u64,
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Format: "TIMESTAMP|MESSAGE" - parsed by frontend
+    pub fn formatted(&self) -> String {
+        format!("{}|{}", self.timestamp, self.message)
+    }
 }
 
 pub const MAX_LOGS: usize = 50;
+pub const MAX_SIDECAR_LINES: usize = 200;
+
+/// Default port of the daemon's HTTP API
+pub const DAEMON_PORT: u16 = 8000;
 
 // ============================================================================
 // LOG MANAGEMENT
@@ -19,22 +231,50 @@ pub const MAX_LOGS: usize = 50;
 pub fn add_log(state: &State<DaemonState>, message: String) {
     use std::time::{SystemTime, UNIX_EPOCH};
     
-    // Add timestamp prefix (Unix millis) for proper chronological sorting
+    // Timestamp (Unix millis) for proper chronological sorting
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_millis())
+        .map(|d| d.as_millis() as u64)
         .unwrap_or(0);
     
-    // Format: "TIMESTAMP|MESSAGE" - will be parsed by frontend
-    let timestamped_message = format!("{}|{}", timestamp, message);
+    let session_id = *state.session_id.lock().unwrap();
+    
+    logfile::append(state, &format!("[app] {}", message));
     
     let mut logs = state.logs.lock().unwrap();
-    logs.push_back(timestamped_message);
+    logs.push_back(LogEntry { timestamp, session_id, message });
     if logs.len() > MAX_LOGS {
         logs.pop_front();
     }
 }
 
+/// Start a new log session and insert a clearly-marked boundary entry
+/// Called at the beginning of each daemon run so restarts stay legible
+pub fn start_log_session(state: &State<DaemonState>, mode: &str) -> u64 {
+    let session_id = {
+        let mut session_lock = state.session_id.lock().unwrap();
+        *session_lock += 1;
+        *session_lock
+    };
+    
+    add_log(state, format!("━━━━━━━━━━ Session #{} started ({}) ━━━━━━━━━━", session_id, mode));
+    session_id
+}
+
+/// Keep a sidecar output line so it can be replayed to a late subscriber
+pub fn record_sidecar_line(app_handle: &tauri::AppHandle, stream: &str, line: String) {
+    use tauri::Manager;
+    
+    let state: State<DaemonState> = app_handle.state();
+    logfile::append(&state, &format!("[{}] {}", stream, line));
+    
+    let mut output = state.sidecar_output.lock().unwrap();
+    output.push_back(SidecarLine { stream: stream.to_string(), line });
+    if output.len() > MAX_SIDECAR_LINES {
+        output.pop_front();
+    }
+}
+
 // ============================================================================
 // DAEMON LIFECYCLE MANAGEMENT
 // ============================================================================
@@ -64,19 +304,27 @@ pub fn kill_processes_on_port(port: u16, signal: Option<&str>) {
     }
 }
 
-/// Clean up all daemon processes running on the system (via port 8000)
-pub fn cleanup_system_daemons() {
+/// Stop whatever listens on the port the daemon was launched on
+/// Try SIGTERM first (graceful shutdown), and give the daemon up to
+/// term_wait_ms to run its shutdown handlers before force-killing it
+#[cfg(not(target_os = "windows"))]
+fn release_port(shutdown: &ShutdownConfig, endpoint: &DaemonEndpoint, pid: Option<u32>) {
+    kill_processes_on_port(endpoint.port, None);
+    let _ = wait_for_daemon_shutdown(endpoint, pid, std::time::Duration::from_millis(shutdown.term_wait_ms));
+    
+    // Force kill if still there
+    kill_processes_on_port(endpoint.port, Some("-9"));
+}
+
+/// Clean up all daemon processes running on the system (via the daemon port)
+/// `pid` is the launched daemon, waited on along with the port
+pub fn cleanup_system_daemons(shutdown: &ShutdownConfig, endpoint: &DaemonEndpoint, pid: Option<u32>) {
     #[cfg(not(target_os = "windows"))]
     {
         use std::process::Command;
         
-        // Method 1: Kill via port 8000 (more reliable)
-        // Try SIGTERM first (graceful shutdown)
-        kill_processes_on_port(8000, None);
-        std::thread::sleep(std::time::Duration::from_millis(500));
-        
-        // Force kill if still there
-        kill_processes_on_port(8000, Some("-9"));
+        // Method 1: Kill via daemon port (more reliable)
+        release_port(shutdown, endpoint, pid);
         
         // Method 2: Kill by process name (fallback)
         let _ = Command::new("pkill")
@@ -85,34 +333,240 @@ pub fn cleanup_system_daemons() {
             .arg("reachy_mini.daemon.app.main")
             .output();
             
-        std::thread::sleep(std::time::Duration::from_millis(300));
+        std::thread::sleep(std::time::Duration::from_millis(shutdown.post_kill_wait_ms));
     }
+    
+    #[cfg(target_os = "windows")]
+    let _ = (shutdown, endpoint, pid);
 }
 
 /// Kill daemon completely (local sidecar process + system)
-pub fn kill_daemon(state: &State<DaemonState>) {
+/// Returns the PID of the launched daemon, to pass to wait_for_daemon_shutdown
+pub fn kill_daemon(state: &State<DaemonState>, shutdown: &ShutdownConfig) -> Option<u32> {
+    // A daemon we only attached to belongs to the developer, just let go of it
+    if std::mem::take(&mut *state.attached.lock().unwrap()) {
+        println!("[tauri] 🛠️  Detached from external daemon");
+        return None;
+    }
+    
+    let pid = crate::process::daemon_pid(state);
+    
     // Clear the stored process reference
     // Note: CommandChild doesn't expose kill() method, so we rely on cleanup_system_daemons()
-    // which kills processes via port 8000 (more reliable)
+    // which kills processes via the daemon port (more reliable)
     let mut process_lock = state.process.lock().unwrap();
     process_lock.take();
     drop(process_lock);
+    state.started_at.lock().unwrap().take();
+    state.readiness.lock().unwrap().take();
+    state.launch.lock().unwrap().take();
+    *state.awake.lock().unwrap() = false;
+    state.sim_viewer.lock().unwrap().take();
+    alerts::reset(state);
+    supervisor::cancel_countdown(state);
+    
+    // Clean up system processes (kills via daemon port and process name)
+    cleanup_system_daemons(shutdown, &cleanup_endpoint(state), pid);
+    pid
+}
+
+/// Endpoint kill_daemon cleans up: the one the daemon was launched with,
+/// not the current settings (the port may have changed since)
+fn cleanup_endpoint(state: &DaemonState) -> DaemonEndpoint {
+    state.endpoint.lock().unwrap().clone()
+}
+
+/// True while the given daemon run is still the current one
+/// (background tasks of a run stop once it is stopped or restarted)
+pub fn is_current_session(app_handle: &tauri::AppHandle, session_id: u64) -> bool {
+    use tauri::Manager;
+    
+    let state: State<DaemonState> = app_handle.state();
+    let is_running = state.process.lock().unwrap().is_some();
+    let current_session = *state.session_id.lock().unwrap();
+    is_running && current_session == session_id
+}
+
+/// Call the running daemon's HTTP API (blocking)
+/// Fails with a clear message when no daemon was launched by the app
+pub fn api_request(
+    app_handle: &tauri::AppHandle,
+    method: &str,
+    path: &str,
+    json_body: Option<&str>,
+    timeout: std::time::Duration,
+) -> Result<http::HttpResponse, String> {
+    use tauri::Manager;
+    
+    let state: State<DaemonState> = app_handle.state();
+    if state.process.lock().unwrap().is_none() && !*state.attached.lock().unwrap() {
+        return Err("Daemon is not running".to_string());
+    }
+    let endpoint = state.endpoint.lock().unwrap().clone();
+    
+    http::request(&endpoint, method, path, json_body, timeout).map_err(|e| e.to_string())
+}
+
+/// Check whether something is still accepting connections on host:port
+pub fn is_port_in_use(host: &str, port: u16) -> bool {
+    use std::net::{TcpStream, ToSocketAddrs};
+    
+    let addrs = match (host, port).to_socket_addrs() {
+        Ok(addrs) => addrs,
+        Err(_) => return false,
+    };
+    
+    addrs
+        .into_iter()
+        .any(|addr| TcpStream::connect_timeout(&addr, std::time::Duration::from_millis(200)).is_ok())
+}
+
+/// Check whether a process is still alive
+#[cfg(not(target_os = "windows"))]
+pub fn is_process_alive(pid: u32) -> bool {
+    use std::process::Command;
+    
+    Command::new("kill")
+        .arg("-0")
+        .arg(pid.to_string())
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(target_os = "windows")]
+pub fn is_process_alive(_pid: u32) -> bool {
+    // No process lookup on Windows, rely on the port check only
+    false
+}
+
+/// Poll until the daemon port is free and the launched daemon process (if known) is gone
+/// Other reachy_mini daemons on the machine are not waited on
+/// Returns an error if the daemon is still alive once the timeout expires
+pub fn wait_for_daemon_shutdown(endpoint: &DaemonEndpoint, pid: Option<u32>, timeout: std::time::Duration) -> Result<(), String> {
+    let start = std::time::Instant::now();
     
-    // Clean up system processes (kills via port 8000 and process name)
-    cleanup_system_daemons();
+    loop {
+        let port_busy = is_port_in_use(&endpoint.host, endpoint.port);
+        let process_alive = pid.is_some_and(is_process_alive);
+        
+        if !port_busy && !process_alive {
+            return Ok(());
+        }
+        
+        if start.elapsed() >= timeout {
+            return Err(format!(
+                "Daemon did not shut down within {}ms (port {} in use: {}, process alive: {})",
+                timeout.as_millis(), endpoint.port, port_busy, process_alive
+            ));
+        }
+        
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
 }
 
 // ============================================================================
 // SIDECAR MANAGEMENT
 // ============================================================================
 
+/// Splits raw sidecar output into lines, buffering incomplete ones
+/// Bytes are decoded per complete line, never per chunk
+#[derive(Default)]
+pub struct LineDecoder {
+    pending: Vec<u8>,
+}
+
+impl LineDecoder {
+    /// Feed a raw output chunk and return the lines it completes
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.pending.extend_from_slice(bytes);
+        
+        let mut lines = Vec::new();
+        while let Some(newline_pos) = self.pending.iter().position(|&b| b == b'\n') {
+            let line_bytes: Vec<u8> = self.pending.drain(..=newline_pos).collect();
+            lines.push(Self::decode(&line_bytes[..newline_pos]));
+        }
+        lines
+    }
+    
+    /// Return the remaining partial line, if any (e.g. when the process exits)
+    pub fn flush(&mut self) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        let line_bytes = std::mem::take(&mut self.pending);
+        Some(Self::decode(&line_bytes))
+    }
+    
+    fn decode(line_bytes: &[u8]) -> String {
+        // Strip Windows line endings
+        let line_bytes = line_bytes.strip_suffix(b"\r").unwrap_or(line_bytes);
+        String::from_utf8_lossy(line_bytes).into_owned()
+    }
+}
+
+/// Forward a decoded sidecar output line to the console, the frontend
+/// ("sidecar-stdout"/"sidecar-stderr" events) and the replay buffer
+pub fn handle_sidecar_line(app_handle: &tauri::AppHandle, prefix: Option<&str>, stream: &str, line: &str) {
+    use tauri::Emitter;
+    
+    let prefixed_line = prefix
+        .map(|p| format!("[{}] {}", p, line))
+        .unwrap_or_else(|| line.to_string());
+    
+    if stream == "stderr" {
+        eprintln!("Sidecar stderr: {}", prefixed_line);
+    } else {
+        println!("Sidecar stdout: {}", prefixed_line);
+    }
+    
+    // While paused, keep reading the pipe but defer the per-line events
+    if !defer_sidecar_line(app_handle, stream, &prefixed_line) {
+        let event = if stream == "stderr" { "sidecar-stderr" } else { "sidecar-stdout" };
+        let _ = app_handle.emit(event, prefixed_line.clone());
+    }
+    
+    // Daemon output only, installs are prefixed
+    if prefix.is_none() {
+        wakeup::observe_line(app_handle, line);
+        viewer::observe_line(app_handle, line);
+        alerts::observe_line(app_handle, line);
+    }
+    
+    record_sidecar_line(app_handle, stream, prefixed_line);
+}
+
+/// Queue a line for the catch-up batch, false when the stream isn't paused
+fn defer_sidecar_line(app_handle: &tauri::AppHandle, stream: &str, line: &str) -> bool {
+    use tauri::Manager;
+    
+    let state: State<DaemonState> = app_handle.state();
+    let mut deferred = state.deferred_lines.lock().unwrap();
+    match deferred.as_mut() {
+        Some(deferred) => {
+            deferred.lines.push_back(SidecarLine { stream: stream.to_string(), line: line.to_string() });
+            if deferred.lines.len() > MAX_SIDECAR_LINES {
+                deferred.lines.pop_front();
+                deferred.dropped += 1;
+            }
+            true
+        }
+        None => false,
+    }
+}
+
 /// Macro helper to spawn sidecar monitoring task
 /// Avoids duplication while working around private Receiver type
+/// An optional closure is called with the exit status once the process terminates
 #[macro_export]
 macro_rules! spawn_sidecar_monitor {
     ($rx:ident, $app_handle:ident, $prefix:expr) => {
+        $crate::spawn_sidecar_monitor!($rx, $app_handle, $prefix, |_| {})
+    };
+    ($rx:ident, $app_handle:ident, $prefix:expr, $on_terminated:expr) => {
         {
             let prefix = $prefix;
+            let mut on_terminated = Some($on_terminated);
             let app_handle_clone = $app_handle.clone();
             tauri::async_runtime::spawn(async move {
                 use tauri::Emitter;
@@ -124,27 +578,32 @@ macro_rules! spawn_sidecar_monitor {
                     println!("[tauri] Starting sidecar output monitoring...");
                 }
                 
+                // Raw output chunks are decoded only once a full line is received,
+                // so multi-byte UTF-8 characters split across chunks stay intact
+                let mut stdout_decoder = $crate::daemon::LineDecoder::default();
+                let mut stderr_decoder = $crate::daemon::LineDecoder::default();
+                
                 while let Some(event) = $rx.recv().await {
                     match event {
-                        CommandEvent::Stdout(line_bytes) => {
-                            let line = String::from_utf8_lossy(&line_bytes);
-                            let prefixed_line = prefix
-                                .as_ref()
-                                .map(|p| format!("[{}] {}", p, line))
-                                .unwrap_or_else(|| line.to_string());
-                            println!("Sidecar stdout: {}", prefixed_line);
-                            let _ = app_handle_clone.emit("sidecar-stdout", prefixed_line.clone());
+                        CommandEvent::Stdout(bytes) => {
+                            for line in stdout_decoder.push(&bytes) {
+                                $crate::daemon::handle_sidecar_line(&app_handle_clone, prefix.as_deref(), "stdout", &line);
+                            }
                         }
-                        CommandEvent::Stderr(line_bytes) => {
-                            let line = String::from_utf8_lossy(&line_bytes);
-                            let prefixed_line = prefix
-                                .as_ref()
-                                .map(|p| format!("[{}] {}", p, line))
-                                .unwrap_or_else(|| line.to_string());
-                            eprintln!("Sidecar stderr: {}", prefixed_line);
-                            let _ = app_handle_clone.emit("sidecar-stderr", prefixed_line.clone());
+                        CommandEvent::Stderr(bytes) => {
+                            for line in stderr_decoder.push(&bytes) {
+                                $crate::daemon::handle_sidecar_line(&app_handle_clone, prefix.as_deref(), "stderr", &line);
+                            }
                         }
                         CommandEvent::Terminated(status) => {
+                            // Flush output that wasn't newline-terminated
+                            if let Some(line) = stdout_decoder.flush() {
+                                $crate::daemon::handle_sidecar_line(&app_handle_clone, prefix.as_deref(), "stdout", &line);
+                            }
+                            if let Some(line) = stderr_decoder.flush() {
+                                $crate::daemon::handle_sidecar_line(&app_handle_clone, prefix.as_deref(), "stderr", &line);
+                            }
+                            
                             if let Some(ref p) = prefix {
                                 println!("[tauri] [{}] Process terminated with status: {:?}", p, status);
                             } else {
@@ -153,6 +612,10 @@ macro_rules! spawn_sidecar_monitor {
                                 let status_str = format!("{:?}", status);
                                 let _ = app_handle_clone.emit("sidecar-terminated", status_str);
                             }
+                            
+                            if let Some(on_terminated) = on_terminated.take() {
+                                on_terminated(status);
+                            }
                         }
                         _ => {}
                     }
@@ -162,16 +625,158 @@ macro_rules! spawn_sidecar_monitor {
     };
 }
 
+/// Check a directory the user wants the daemon to run from
+pub fn validate_working_dir(path: &str) -> Result<std::path::PathBuf, String> {
+    let dir = std::path::Path::new(path);
+    if !dir.is_dir() {
+        return Err(format!("Working directory {} does not exist or is not a directory", path));
+    }
+    std::fs::read_dir(dir).map_err(|e| format!("Working directory {} is not accessible: {}", path, e))?;
+    dir.canonicalize().map_err(|e| format!("Failed to resolve working directory {}: {}", path, e))
+}
+
+/// Launch options of start_daemon, assembled by the frontend in one place
+/// Missing fields take their defaults, so callers keep working as options are added.
+/// Unknown ones are rejected: a misspelled `simMode` would otherwise launch on hardware.
+#[derive(Clone, Default, Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DaemonLaunchConfig {
+    pub sim_mode: bool,
+    /// Minimal daemon (no wake-up, media or datasets, mockup instead of MuJoCo)
+    /// to find out which subsystem prevents a normal start
+    pub safe_mode: bool,
+    /// Serial number or port of the robot to use when several are connected (hardware only)
+    pub robot: Option<String>,
+    /// Directory the daemon runs from, so relative paths (datasets, app assets)
+    /// resolve there instead of in the uv folder
+    pub working_dir: Option<String>,
+}
+
+impl DaemonLaunchConfig {
+    /// Check every field up front, failing with all the invalid ones at once
+    /// Returns the resolved working directory
+    pub fn validate(&self) -> Result<Option<std::path::PathBuf>, String> {
+        let mut errors = Vec::new();
+        
+        if let Some(ref robot) = self.robot {
+            if robot.trim().is_empty() {
+                errors.push("robot: can't be empty".to_string());
+            } else if self.sim_mode {
+                errors.push("robot: only applies to hardware mode".to_string());
+            }
+        }
+        
+        let working_dir = match self.working_dir.as_deref().filter(|dir| !dir.is_empty()) {
+            Some(dir) => validate_working_dir(dir).map_err(|e| errors.push(format!("working_dir: {}", e))).ok(),
+            None => None,
+        };
+        
+        if errors.is_empty() {
+            Ok(working_dir)
+        } else {
+            Err(format!("Invalid launch config: {}", errors.join("; ")))
+        }
+    }
+}
+
+/// Sidecar binary wrapping the daemon's Python interpreter
+pub const DAEMON_SIDECAR: &str = "uv-trampoline";
+
+/// Makes Python print every thread's traceback on fatal signals (SIGABRT
+/// included), which dump_daemon_stack relies on
+pub const FAULTHANDLER_ENV: &str = "PYTHONFAULTHANDLER";
+
+/// Rendering backend of MuJoCo (glfw, egl or osmesa)
+pub const MUJOCO_GL_ENV: &str = "MUJOCO_GL";
+
+/// Keeps the working directory off sys.path (python -P)
+pub const SAFE_PATH_ENV: &str = "PYTHONSAFEPATH";
+
+/// Environment variables the daemon sidecar is spawned with
+pub fn daemon_env(
+    settings: &crate::settings::AppSettings,
+    sim_mode: bool,
+    working_dir: Option<&std::path::Path>,
+) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        (uv_wrapper::SKIP_SIGNING_ENV, if settings.developer_mode { "1" } else { "0" }.to_string()),
+        (FAULTHANDLER_ENV, "1".to_string()),
+    ];
+    env.extend(crate::python::trampoline_env());
+    
+    // Gated datasets and app-store content need the user's Hugging Face token
+    if let Some(token) = crate::secrets::hf_token() {
+        env.push((crate::secrets::HF_TOKEN_ENV, token));
+    }
+    
+    if let Some(ref location) = settings.dataset_location {
+        env.push((crate::system::HUB_CACHE_ENV, location.display().to_string()));
+    }
+    
+    if let Some(backend) = settings.mujoco_rendering.gl_backend().filter(|_| sim_mode) {
+        env.push((MUJOCO_GL_ENV, backend.to_string()));
+    }
+    
+    if let Some(dir) = working_dir {
+        env.push((uv_wrapper::DAEMON_CWD_ENV, dir.display().to_string()));
+        // `python -m` puts the cwd first on sys.path: modules of the user's
+        // project would shadow the venv's packages
+        env.push((SAFE_PATH_ENV, "1".to_string()));
+    }
+    
+    env
+}
+
+/// Inherited variables that change which Python, packages or caches the daemon uses
+const DAEMON_ENV_PREFIXES: &[&str] = &["PYTHON", "UV_", "HF_", "REACHY_MINI_", "MUJOCO_", "GIT_LFS_", "GST_"];
+const DAEMON_ENV_NAMES: &[&str] = &["PATH", "VIRTUAL_ENV", "CONDA_PREFIX", "LD_LIBRARY_PATH", "DYLD_LIBRARY_PATH", "XDG_CACHE_HOME"];
+const REDACTED: &str = "<redacted>";
+
+fn is_secret_env(key: &str) -> bool {
+    let key = key.to_uppercase();
+    ["TOKEN", "SECRET", "PASSWORD", "CREDENTIAL", "API_KEY"].iter().any(|word| key.contains(word))
+}
+
+/// Environment the daemon runs with: the relevant variables inherited from
+/// the app,
+/// the ones set for the spawn, and those uv-trampoline adds. Secret values are redacted.
+pub fn resolved_daemon_env(spawn_env: &[(&'static str, String)]) -> std::collections::BTreeMap<String, String> {
+    let mut env: std::collections::BTreeMap<String, String> = std::env::vars()
+        .filter(|(key, _)| DAEMON_ENV_NAMES.contains(&key.as_str()) || DAEMON_ENV_PREFIXES.iter().any(|prefix| key.starts_with(prefix)))
+        .collect();
+    env.extend(spawn_env.iter().map(|(key, value)| (key.to_string(), value.clone())));
+    
+    // Set by uv-trampoline before running the daemon's interpreter
+    if let Ok(uv_folder) = crate::python::find_uv_folder() {
+        env.insert("UV_WORKING_DIR".to_string(), uv_folder.display().to_string());
+        env.insert("UV_PYTHON_INSTALL_DIR".to_string(), uv_folder.display().to_string());
+    }
+    
+    for (key, value) in env.iter_mut() {
+        if is_secret_env(key) {
+            *value = REDACTED.to_string();
+        }
+    }
+    env
+}
+
 /// Spawn and monitor the embedded daemon sidecar
 /// 
 /// # Arguments
 /// * `app_handle` - Tauri app handle
 /// * `state` - Daemon state
 /// * `sim_mode` - If true, launch daemon in simulation mode (MuJoCo) with --sim flag
+/// * `serial_port` - Serial port of the robot to bind to (None lets the daemon pick)
+/// * `retries_left` - Relaunches allowed if the daemon exits before becoming ready
+/// * `working_dir` - Directory the daemon runs from (None for the uv folder)
 pub fn spawn_and_monitor_sidecar(
     app_handle: tauri::AppHandle,
     state: &State<DaemonState>,
     sim_mode: bool,
+    safe_mode: bool,
+    serial_port: Option<String>,
+    retries_left: u32,
+    working_dir: Option<std::path::PathBuf>,
 ) -> Result<(), String> {
     use crate::python::build_daemon_args;
     use tauri_plugin_shell::ShellExt;
@@ -185,12 +790,21 @@ pub fn spawn_and_monitor_sidecar(
     drop(process_lock);
     
     // Build daemon arguments dynamically
-    let daemon_args = build_daemon_args(sim_mode)?;
+    let settings = crate::settings::current(&app_handle);
+    let bind_host = settings.daemon_host.as_deref();
+    let endpoint = DaemonEndpoint::from_settings(&settings);
+    let daemon_args = build_daemon_args(sim_mode, safe_mode, serial_port.as_deref(), &settings)?;
+    
+    if let Some(host) = bind_host {
+        println!("[tauri] ⚠️  Daemon API exposed on the network ({}), anyone on this network can control the robot", host);
+    }
     
     // Note: libpython3.12.dylib signing is now handled by uv-trampoline
     // which runs in the correct working directory context
     
-    if sim_mode {
+    if safe_mode {
+        println!("[tauri] 🩺 Launching daemon in safe mode (wake-up, media and datasets disabled)");
+    } else if sim_mode {
         #[cfg(target_os = "macos")]
         {
             println!("[tauri] 🎭 Launching daemon in simulation mode (MuJoCo) with mjpython");
@@ -204,22 +818,228 @@ pub fn spawn_and_monitor_sidecar(
     // Convert Vec<String> to Vec<&str> for args()
     let daemon_args_refs: Vec<&str> = daemon_args.iter().map(|s| s.as_str()).collect();
     
+    if let Err(e) = crate::bundle::ensure_sidecar(DAEMON_SIDECAR) {
+        report_start_failure(&app_handle, *state.session_id.lock().unwrap(), e.clone(), None);
+        return Err(e);
+    }
+    
+    // Inherited by the daemon, which holds many cameras, sockets and dataset files
+    crate::system::ensure_daemon_fd_limit();
+    
+    let env = daemon_env(&settings, sim_mode, working_dir.as_deref());
+    let mujoco_gl = env.iter().find(|(key, _)| *key == MUJOCO_GL_ENV).map(|(_, value)| value.clone());
+    if sim_mode {
+        println!("[tauri] 🎨 MuJoCo rendering: MUJOCO_GL={}", mujoco_gl.as_deref().unwrap_or("(default)"));
+    }
+    let env_keys = env.iter().map(|(key, _)| key.to_string()).collect();
+    let resolved_env = resolved_daemon_env(&env);
     let sidecar_command = app_handle
         .shell()
-        .sidecar("uv-trampoline")
+        .sidecar(DAEMON_SIDECAR)
         .map_err(|e| e.to_string())?
-        .args(daemon_args_refs);
+        .args(daemon_args_refs)
+        .envs(env)
+        .set_raw_out(true);
+    
+    // Recorded before spawning so a launch that fails outright is captured too
+    let session_id = *state.session_id.lock().unwrap();
+    *state.last_launch.lock().unwrap() = Some(LastLaunch {
+        session_id,
+        launched_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        sidecar: DAEMON_SIDECAR.to_string(),
+        args: daemon_args.clone(),
+        env_keys,
+        env: resolved_env,
+        sim_mode,
+        safe_mode,
+    });
     
-    let (mut rx, child) = sidecar_command.spawn().map_err(|e| e.to_string())?;
+    let (mut rx, child) = match sidecar_command.spawn() {
+        Ok(spawned) => spawned,
+        Err(e) => {
+            let error = format!("Failed to spawn daemon: {}", e);
+            report_start_failure(&app_handle, session_id, error.clone(), None);
+            return Err(error);
+        }
+    };
 
     // Store the child process in DaemonState
     let mut process_lock = state.process.lock().unwrap();
     *process_lock = Some(child);
     drop(process_lock);
+    *state.started_at.lock().unwrap() = Some(std::time::Instant::now());
+    *state.endpoint.lock().unwrap() = endpoint.clone();
+    *state.readiness.lock().unwrap() = Some(readiness::ReadinessStage::NotListening);
+    *state.launch.lock().unwrap() = Some(DaemonLaunch {
+        sim_mode,
+        safe_mode,
+        serial_port: serial_port.clone(),
+        endpoint: endpoint.clone(),
+        args: daemon_args.clone(),
+        log_level: settings.daemon_log_level,
+        camera_device: settings.camera_device.clone().filter(|_| !safe_mode),
+        microphone_device: settings.microphone_device.clone().filter(|_| !safe_mode),
+        working_dir: working_dir.clone(),
+        mujoco_gl,
+    });
+    
+    readiness::spawn_readiness_probe(app_handle.clone(), session_id, endpoint.clone(), settings.readiness, sim_mode);
+    if !sim_mode {
+        link::spawn_robot_link_monitor(app_handle.clone(), session_id, endpoint, serial_port.clone());
+    }
 
-    // Spawn async task to monitor sidecar output
-    crate::spawn_sidecar_monitor!(rx, app_handle, None::<String>);
+    // Spawn async task to monitor sidecar output, relaunching on an early exit
+    // or, with auto-restart, on a crash of a daemon that had come up
+    let retry_app_handle = app_handle.clone();
+    crate::spawn_sidecar_monitor!(rx, app_handle, None::<String>, move |status| {
+        if record_termination(&retry_app_handle, session_id, &status) {
+            // Relaunching with the same arguments would only exit the same way
+            report_start_failure(
+                &retry_app_handle,
+                session_id,
+                "Daemon exited with status 0 before its API came up (it may have printed its usage and quit)".to_string(),
+                Some(EXITED_BEFORE_READY),
+            );
+        } else if exited_during_startup(&retry_app_handle, session_id) {
+            if retries_left > 0 {
+                retry_failed_start(retry_app_handle, session_id, sim_mode, safe_mode, serial_port, retries_left, working_dir);
+            } else {
+                report_start_failure(&retry_app_handle, session_id, format!("Daemon exited during startup: {:?}", status), None);
+            }
+        } else if is_current_session(&retry_app_handle, session_id) && status.code != Some(0) {
+            supervisor::schedule_restart(retry_app_handle, session_id);
+        }
+    });
 
     Ok(())
 }
 
+/// Keep how the run's process ended (a user stop releases the process first)
+/// Returns true for a status 0 exit before the API came up, which also ends
+/// the readiness probe of the run with its own stage
+fn record_termination(app_handle: &tauri::AppHandle, session_id: u64, status: &tauri_plugin_shell::process::TerminatedPayload) -> bool {
+    use tauri::Manager;
+    
+    let user_requested = !is_current_session(app_handle, session_id);
+    let exited_before_ready = status.code == Some(0) && exited_during_startup(app_handle, session_id);
+    let state: State<DaemonState> = app_handle.state();
+    
+    let output = if exited_before_ready {
+        *state.readiness.lock().unwrap() = Some(readiness::ReadinessStage::ExitedBeforeReady);
+        let output = state.sidecar_output.lock().unwrap();
+        output.iter().skip(output.len().saturating_sub(EARLY_EXIT_OUTPUT_LINES)).map(|line| line.line.clone()).collect()
+    } else {
+        Vec::new()
+    };
+    
+    *state.last_termination.lock().unwrap() = Some(LastTermination {
+        session_id,
+        status: format!("{:?}", status),
+        code: status.code,
+        signal: status.signal,
+        terminated_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        user_requested,
+        error_code: exited_before_ready.then(|| EXITED_BEFORE_READY.to_string()),
+        output,
+    });
+    exited_before_ready
+}
+
+/// True when the run's process ended on its own before the API became ready
+/// (a user stop releases the process first)
+fn exited_during_startup(app_handle: &tauri::AppHandle, session_id: u64) -> bool {
+    use tauri::Manager;
+    
+    let state: State<DaemonState> = app_handle.state();
+    is_current_session(app_handle, session_id)
+        && *state.readiness.lock().unwrap() != Some(readiness::ReadinessStage::Ready)
+}
+
+/// Log a failed start and tell the frontend how the daemon was launched
+fn report_start_failure(app_handle: &tauri::AppHandle, session_id: u64, error: String, error_code: Option<&str>) {
+    use tauri::{Emitter, Manager};
+    
+    let state: State<DaemonState> = app_handle.state();
+    let launch = state.last_launch.lock().unwrap().clone();
+    if let Some(ref launch) = launch {
+        add_log(&state, format!("❌ {} (launched with: {} {})", error, launch.sidecar, launch.args.join(" ")));
+    } else {
+        add_log(&state, format!("❌ {}", error));
+    }
+    let _ = app_handle.emit("daemon-start-failed", DaemonStartFailure {
+        session_id,
+        error,
+        error_code: error_code.map(str::to_string),
+        launch,
+    });
+}
+
+/// Relaunch a daemon that exited on its own before its API came up
+///
+/// Does nothing when the run was stopped by the user (process already
+/// released) or became ready. Runs a full cleanup first so nothing from
+/// the failed attempt holds the port.
+fn retry_failed_start(
+    app_handle: tauri::AppHandle,
+    session_id: u64,
+    sim_mode: bool,
+    safe_mode: bool,
+    serial_port: Option<String>,
+    retries_left: u32,
+    working_dir: Option<std::path::PathBuf>,
+) {
+    use tauri::Manager;
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        if !exited_during_startup(&app_handle, session_id) {
+            return;
+        }
+        let state: State<DaemonState> = app_handle.state();
+        
+        add_log(&state, format!("⚠️ Daemon exited during startup, retrying ({} retries left)", retries_left - 1));
+        let settings = crate::settings::current(&app_handle);
+        kill_daemon(&state, &settings.shutdown);
+        start_log_session(&state, "retry");
+        
+        if let Err(e) = spawn_and_monitor_sidecar(app_handle.clone(), &state, sim_mode, safe_mode, serial_port, retries_left - 1, working_dir) {
+            add_log(&state, format!("❌ Daemon retry failed: {}", e));
+        }
+    });
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{DaemonEndpoint, DaemonState, LineDecoder};
+    use crate::settings::AppSettings;
+
+    #[test]
+    fn line_decoder_joins_utf8_split_across_chunks() {
+        let line = "🤖 Daemon ready — tête OK\n".as_bytes();
+        // Split inside the 4-byte robot emoji
+        let (first, second) = line.split_at(2);
+
+        let mut decoder = LineDecoder::default();
+        assert!(decoder.push(first).is_empty());
+        assert_eq!(decoder.push(second), vec!["🤖 Daemon ready — tête OK".to_string()]);
+        assert_eq!(decoder.flush(), None);
+    }
+
+    #[test]
+    fn cleanup_targets_the_launched_endpoint() {
+        let state = DaemonState::default();
+        *state.endpoint.lock().unwrap() = DaemonEndpoint::for_bind_host(Some("0.0.0.0"), 8123);
+        // The port setting changed while the daemon was running
+        let settings = AppSettings { daemon_port: Some(9000), ..Default::default() };
+
+        let endpoint = super::cleanup_endpoint(&state);
+        assert_eq!((endpoint.host.as_str(), endpoint.port), ("127.0.0.1", 8123));
+        assert_ne!(endpoint.port, DaemonEndpoint::from_settings(&settings).port);
+    }
+}
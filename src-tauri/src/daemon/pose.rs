@@ -0,0 +1,222 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+use super::{api_request, DaemonState};
+
+const POSES_FILE: &str = "robot_poses.json";
+const STATE_PATH: &str = "/api/state/full?with_head_pose=true&use_pose_matrix=false&with_antenna_positions=true&with_body_yaw=true";
+const STATUS_PATH: &str = "/api/daemon/status";
+const GOTO_PATH: &str = "/api/move/goto";
+const RUNNING_MOVES_PATH: &str = "/api/move/running";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
+/// Duration of the move to a saved pose
+const MOVE_DURATION_SECS: f64 = 2.0;
+/// Give up waiting for the move after this long
+const MOVE_TIMEOUT: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Total time parking may hold up the exit
+const PARK_DEADLINE: Duration = Duration::from_secs(5);
+
+/// Robot pose as reported by the daemon's full state
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RobotPose {
+    /// Head pose as x/y/z/roll/pitch/yaw
+    pub head_pose: serde_json::Value,
+    /// [left, right]
+    pub antennas: Vec<f64>,
+    pub body_yaw: f64,
+    /// Seconds since the Unix epoch
+    pub saved_at: u64,
+}
+
+fn poses_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    app_handle
+        .path()
+        .app_data_dir()
+        .map(|dir| dir.join(POSES_FILE))
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))
+}
+
+fn load_poses(app_handle: &AppHandle) -> BTreeMap<String, RobotPose> {
+    poses_path(app_handle)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn store_poses(app_handle: &AppHandle, poses: &BTreeMap<String, RobotPose>) -> Result<(), String> {
+    let path = poses_path(app_handle)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    let content = serde_json::to_string_pretty(poses).map_err(|e| format!("Failed to serialize poses: {}", e))?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Saved pose by name, for settings validation
+pub fn find_pose(app_handle: &AppHandle, name: &str) -> Option<RobotPose> {
+    load_poses(app_handle).remove(name)
+}
+
+/// Fail unless the daemon drives the physical robot and its backend is up
+fn ensure_hardware(app_handle: &AppHandle) -> Result<(), String> {
+    {
+        let state: State<DaemonState> = app_handle.state();
+        let attached = *state.attached.lock().unwrap();
+        let sim_mode = state.launch.lock().unwrap().as_ref().map(|launch| launch.sim_mode);
+        match sim_mode {
+            Some(true) => return Err("Poses only apply to the physical robot, the daemon runs in simulation".to_string()),
+            None if !attached => return Err("Daemon is not running".to_string()),
+            _ => {}
+        }
+    }
+
+    let response = api_request(app_handle, "GET", STATUS_PATH, None, REQUEST_TIMEOUT)?;
+    let running = response.is_success()
+        && serde_json::from_str::<serde_json::Value>(&response.body)
+            .ok()
+            .and_then(|status| status.get("state").and_then(|s| s.as_str()).map(|s| s == "running"))
+            .unwrap_or(false);
+    if !running {
+        return Err("Daemon is not connected to the robot".to_string());
+    }
+    Ok(())
+}
+
+/// Read the current pose from the daemon
+fn read_pose(app_handle: &AppHandle) -> Result<RobotPose, String> {
+    let response = api_request(app_handle, "GET", STATE_PATH, None, REQUEST_TIMEOUT)?;
+    if !response.is_success() {
+        return Err(format!("Reading the robot state failed: HTTP {} {}", response.status, response.body.trim()));
+    }
+
+    let state: serde_json::Value = serde_json::from_str(&response.body)
+        .map_err(|e| format!("Unexpected robot state response: {}", e))?;
+    let head_pose = state
+        .get("head_pose")
+        .filter(|pose| pose.is_object())
+        .cloned()
+        .ok_or("The daemon didn't report the head pose")?;
+    let antennas = state
+        .get("antennas_position")
+        .and_then(|antennas| serde_json::from_value::<Vec<f64>>(antennas.clone()).ok())
+        .ok_or("The daemon didn't report the antenna positions")?;
+    let body_yaw = state.get("body_yaw").and_then(|yaw| yaw.as_f64()).unwrap_or(0.0);
+
+    Ok(RobotPose {
+        head_pose,
+        antennas,
+        body_yaw,
+        saved_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    })
+}
+
+/// Move the robot to a pose and wait for the move to end
+fn move_to_pose(app_handle: &AppHandle, pose: &RobotPose) -> Result<(), String> {
+    let body = serde_json::json!({
+        "head_pose": pose.head_pose,
+        "antennas": pose.antennas,
+        "body_yaw": pose.body_yaw,
+        "duration": MOVE_DURATION_SECS,
+    })
+    .to_string();
+    let response = api_request(app_handle, "POST", GOTO_PATH, Some(&body), REQUEST_TIMEOUT)?;
+    if !response.is_success() {
+        return Err(format!("Moving to the pose failed: HTTP {} {}", response.status, response.body.trim()));
+    }
+
+    let move_id = serde_json::from_str::<serde_json::Value>(&response.body)
+        .ok()
+        .and_then(|body| body.get("uuid").and_then(|id| id.as_str()).map(str::to_string));
+    if let Some(move_id) = move_id {
+        let start = Instant::now();
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let running = api_request(app_handle, "GET", RUNNING_MOVES_PATH, None, REQUEST_TIMEOUT)?;
+            if !running.body.contains(&move_id) {
+                break;
+            }
+            if start.elapsed() >= MOVE_TIMEOUT {
+                return Err(format!("Move still running after {}s", MOVE_TIMEOUT.as_secs()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Park the robot in the pose chosen in the settings before the daemon is stopped
+///
+/// Best effort and blocking for at most PARK_DEADLINE: a move still running
+/// then is cut short by the daemon shutdown. Call it off the window event thread.
+pub fn park_before_exit(app_handle: &AppHandle) {
+    let Some(name) = crate::settings::current(app_handle).rest_pose_on_exit else {
+        return;
+    };
+    let Some(pose) = find_pose(app_handle, &name) else {
+        eprintln!("[tauri] ⚠️  Rest pose '{}' no longer exists", name);
+        return;
+    };
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let handle = app_handle.clone();
+    std::thread::spawn(move || {
+        let _ = sender.send(ensure_hardware(&handle).and_then(|()| move_to_pose(&handle, &pose)));
+    });
+
+    match receiver.recv_timeout(PARK_DEADLINE) {
+        Ok(Ok(())) => println!("[tauri] 🛏️  Robot parked in '{}' before exit", name),
+        Ok(Err(e)) => eprintln!("[tauri] ⚠️  Failed to park the robot in '{}': {}", name, e),
+        Err(_) => eprintln!("[tauri] ⚠️  Parking the robot in '{}' took over {}s, exiting anyway", name, PARK_DEADLINE.as_secs()),
+    }
+}
+
+/// Save the robot's current pose under `name` (replacing a pose of the same name)
+#[tauri::command]
+pub async fn save_robot_pose(app_handle: AppHandle, name: String) -> Result<RobotPose, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Pose name can't be empty".to_string());
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        ensure_hardware(&app_handle)?;
+        let pose = read_pose(&app_handle)?;
+
+        let mut poses = load_poses(&app_handle);
+        poses.insert(name.clone(), pose.clone());
+        store_poses(&app_handle, &poses)?;
+
+        println!("[tauri] 📌 Saved robot pose '{}'", name);
+        Ok(pose)
+    })
+    .await
+    .map_err(|e| format!("Failed to execute save pose task: {}", e))?
+}
+
+/// Move the robot back to a saved pose, returns once the move has ended
+#[tauri::command]
+pub async fn restore_robot_pose(app_handle: AppHandle, name: String) -> Result<(), String> {
+    let pose = find_pose(&app_handle, &name).ok_or_else(|| format!("No saved pose named '{}'", name))?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        ensure_hardware(&app_handle)?;
+        move_to_pose(&app_handle, &pose)?;
+
+        println!("[tauri] 📌 Restored robot pose '{}'", name);
+        Ok(())
+    })
+    .await
+    .map_err(|e| format!("Failed to execute restore pose task: {}", e))?
+}
+
+/// Saved poses by name
+#[tauri::command]
+pub fn list_robot_poses(app_handle: AppHandle) -> BTreeMap<String, RobotPose> {
+    load_poses(&app_handle)
+}
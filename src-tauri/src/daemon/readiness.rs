@@ -0,0 +1,145 @@
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+use super::http::{self, HttpError};
+use super::{is_current_session, DaemonEndpoint, DaemonState};
+use crate::settings::ReadinessConfig;
+
+/// Daemon API route used as health check
+const HEALTH_PATH: &str = "/api/daemon/status";
+const PROBE_REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+const BACKOFF_FACTOR: f64 = 1.5;
+
+#[derive(Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessStage {
+    /// Port not accepting connections yet (Python still importing, datasets loading)
+    NotListening,
+    /// API reachable but not reporting healthy yet
+    Unhealthy,
+    Ready,
+    /// Gave up waiting, the daemon may still come up later
+    TimedOut,
+    /// The process exited with status 0 before the API came up (see EXITED_BEFORE_READY)
+    ExitedBeforeReady,
+}
+
+/// Payload of the "daemon-readiness" event
+#[derive(Clone, Serialize)]
+pub struct ReadinessUpdate {
+    pub session_id: u64,
+    pub stage: ReadinessStage,
+    pub attempt: u32,
+    pub elapsed_ms: u64,
+    pub detail: Option<String>,
+}
+
+/// Single health check against the daemon API
+fn probe(endpoint: &DaemonEndpoint) -> (ReadinessStage, Option<String>) {
+    match http::get(endpoint, HEALTH_PATH, PROBE_REQUEST_TIMEOUT) {
+        Ok(response) if response.is_success() => (ReadinessStage::Ready, None),
+        Ok(response) => {
+            let body: String = response.body.trim().chars().take(200).collect();
+            (ReadinessStage::Unhealthy, Some(format!("HTTP {}: {}", response.status, body)))
+        }
+        Err(HttpError::NotListening(e)) => (ReadinessStage::NotListening, Some(e)),
+        Err(e @ HttpError::Failed(_)) => (ReadinessStage::Unhealthy, Some(e.to_string())),
+    }
+}
+
+/// Poll the daemon API until it reports healthy, with exponential backoff
+///
+/// Emits "daemon-readiness" whenever the stage changes, then a final update
+/// (ready or timed_out). Stops silently if the daemon is stopped or restarted.
+pub fn spawn_readiness_probe(
+    app_handle: AppHandle,
+    session_id: u64,
+    endpoint: DaemonEndpoint,
+    config: ReadinessConfig,
+    sim_mode: bool,
+) {
+    tauri::async_runtime::spawn_blocking(move || {
+        let timeout = Duration::from_millis(if sim_mode { config.sim_timeout_ms } else { config.timeout_ms });
+        let max_interval = Duration::from_millis(config.max_interval_ms);
+        let mut interval = Duration::from_millis(config.initial_interval_ms);
+        let start = Instant::now();
+        let mut attempt = 0;
+        let mut last_stage = None;
+
+        println!(
+            "[tauri] ⏳ Waiting for daemon API on {}:{} (timeout {}s)",
+            endpoint.host, endpoint.port, timeout.as_secs()
+        );
+
+        loop {
+            if !is_current_session(&app_handle, session_id) {
+                println!("[tauri] Readiness probe for session #{} cancelled", session_id);
+                return;
+            }
+
+            if *app_handle.state::<DaemonState>().readiness.lock().unwrap() == Some(ReadinessStage::ExitedBeforeReady) {
+                println!("[tauri] ⚠️  Daemon exited with status 0 before its API came up");
+                let _ = app_handle.emit("daemon-readiness", ReadinessUpdate {
+                    session_id,
+                    stage: ReadinessStage::ExitedBeforeReady,
+                    attempt,
+                    elapsed_ms: start.elapsed().as_millis() as u64,
+                    detail: Some(super::EXITED_BEFORE_READY.to_string()),
+                });
+                return;
+            }
+
+            attempt += 1;
+            let (stage, detail) = probe(&endpoint);
+            let elapsed = start.elapsed();
+
+            println!(
+                "[tauri] [debug] Readiness probe #{} ({}ms): {}",
+                attempt,
+                elapsed.as_millis(),
+                detail.as_deref().unwrap_or("ok")
+            );
+
+            let final_stage = match stage {
+                ReadinessStage::Ready => Some(ReadinessStage::Ready),
+                _ if elapsed >= timeout => Some(ReadinessStage::TimedOut),
+                _ => None,
+            };
+            let reported_stage = final_stage.unwrap_or(stage);
+
+            if last_stage != Some(reported_stage) {
+                last_stage = Some(reported_stage);
+                if is_current_session(&app_handle, session_id) {
+                    let state: State<DaemonState> = app_handle.state();
+                    let mut readiness = state.readiness.lock().unwrap();
+                    // An exit recorded meanwhile stays, the next iteration reports it
+                    if *readiness != Some(ReadinessStage::ExitedBeforeReady) {
+                        *readiness = Some(reported_stage);
+                    }
+                }
+                let _ = app_handle.emit("daemon-readiness", ReadinessUpdate {
+                    session_id,
+                    stage: reported_stage,
+                    attempt,
+                    elapsed_ms: elapsed.as_millis() as u64,
+                    detail,
+                });
+            }
+
+            match final_stage {
+                Some(ReadinessStage::Ready) => {
+                    println!("[tauri] ✅ Daemon API ready after {:.1}s ({} probes)", elapsed.as_secs_f64(), attempt);
+                    return;
+                }
+                Some(_) => {
+                    println!("[tauri] ⚠️  Daemon API not ready after {}s, giving up", elapsed.as_secs());
+                    return;
+                }
+                None => {}
+            }
+
+            std::thread::sleep(interval);
+            interval = interval.mul_f64(BACKOFF_FACTOR).min(max_interval);
+        }
+    });
+}
@@ -0,0 +1,237 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+use super::{api_request, DaemonState};
+
+/// Subdirectory of the app data dir holding saved recordings
+const RECORDINGS_DIR: &str = "api-recordings";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const METHODS: &[&str] = &["GET", "POST", "PUT", "PATCH", "DELETE"];
+
+/// One proxied daemon call
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RecordedCall {
+    /// Milliseconds since the recording started
+    pub offset_ms: u64,
+    pub method: String,
+    pub path: String,
+    pub body: Option<serde_json::Value>,
+    /// None when the daemon couldn't be reached
+    pub status: Option<u16>,
+    pub duration_ms: u64,
+}
+
+/// File format of a saved recording
+#[derive(Serialize, Deserialize)]
+pub struct ApiRecordingFile {
+    pub name: String,
+    /// Seconds since the Unix epoch
+    pub started_at: u64,
+    pub calls: Vec<RecordedCall>,
+}
+
+/// Calls proxied or reported since start_api_recording
+pub struct ApiRecording {
+    pub name: String,
+    pub started_at: u64,
+    pub started: Instant,
+    pub calls: Vec<RecordedCall>,
+}
+
+#[derive(Serialize)]
+pub struct DaemonResponse {
+    pub status: u16,
+    /// Parsed JSON, or the raw text when the body isn't JSON
+    pub body: serde_json::Value,
+}
+
+#[derive(Serialize)]
+pub struct RecordingFile {
+    pub path: String,
+    pub calls: usize,
+}
+
+#[derive(Serialize)]
+pub struct ReplayedCall {
+    pub method: String,
+    pub path: String,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ApiReplay {
+    pub calls: Vec<ReplayedCall>,
+    /// False when the replay stopped early because the daemon became unreachable
+    pub completed: bool,
+}
+
+/// Validate and send a proxied call
+fn send(app_handle: &AppHandle, method: &str, path: &str, body: Option<&serde_json::Value>) -> Result<DaemonResponse, String> {
+    if !METHODS.contains(&method) {
+        return Err(format!("Unsupported method {}", method));
+    }
+    if !path.starts_with("/api/") {
+        return Err(format!("Only daemon API paths (/api/...) can be requested, got {}", path));
+    }
+    // The path goes verbatim into the HTTP request line
+    if path.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(format!("Invalid characters in daemon API path {:?}", path));
+    }
+
+    let body = body.map(|body| body.to_string());
+    let response = api_request(app_handle, method, path, body.as_deref(), REQUEST_TIMEOUT)?;
+    let body = serde_json::from_str(&response.body).unwrap_or(serde_json::Value::String(response.body));
+    Ok(DaemonResponse { status: response.status, body })
+}
+
+/// Append a call to the running recording, if any
+fn record_call(
+    state: &State<DaemonState>,
+    sent: Instant,
+    method: String,
+    path: String,
+    body: Option<serde_json::Value>,
+    status: Option<u16>,
+) {
+    if let Some(recording) = state.api_recording.lock().unwrap().as_mut() {
+        recording.calls.push(RecordedCall {
+            offset_ms: sent.saturating_duration_since(recording.started).as_millis() as u64,
+            method,
+            path,
+            body,
+            status,
+            duration_ms: sent.elapsed().as_millis() as u64,
+        });
+    }
+}
+
+/// Call the daemon API on behalf of the frontend, recorded while a recording runs
+#[tauri::command]
+pub async fn daemon_request(
+    app_handle: AppHandle,
+    method: String,
+    path: String,
+    body: Option<serde_json::Value>,
+) -> Result<DaemonResponse, String> {
+    let method = method.to_uppercase();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let sent = Instant::now();
+        let result = send(&app_handle, &method, &path, body.as_ref());
+
+        let state: State<DaemonState> = app_handle.state();
+        let status = result.as_ref().ok().map(|response| response.status);
+        record_call(&state, sent, method, path, body, status);
+        result
+    })
+    .await
+    .map_err(|e| format!("Failed to execute daemon request task: {}", e))?
+}
+
+/// Record a daemon call the frontend made itself (fetchWithTimeout), no-op
+/// when no recording runs
+#[tauri::command]
+pub fn record_daemon_call(
+    state: State<DaemonState>,
+    method: String,
+    path: String,
+    body: Option<serde_json::Value>,
+    status: Option<u16>,
+    duration_ms: u64,
+) {
+    let Some(sent) = Instant::now().checked_sub(Duration::from_millis(duration_ms)) else {
+        return;
+    };
+    record_call(&state, sent, method.to_uppercase(), path, body, status);
+}
+
+/// Start recording daemon calls under a name, emits "api-recording-active"
+#[tauri::command]
+pub fn start_api_recording(app_handle: AppHandle, state: State<DaemonState>, name: String) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err("Recording names may only contain letters, digits, '-' and '_'".to_string());
+    }
+
+    let mut recording = state.api_recording.lock().unwrap();
+    if let Some(ref active) = *recording {
+        return Err(format!("Recording '{}' is already running", active.name));
+    }
+
+    let started_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    println!("[tauri] ⏺️  API recording '{}' started", name);
+    *recording = Some(ApiRecording { name, started_at, started: Instant::now(), calls: Vec::new() });
+    let _ = app_handle.emit("api-recording-active", true);
+
+    Ok(())
+}
+
+/// Stop the recording and write it to api-recordings/<name>-<start time>.json in the app data dir
+#[tauri::command]
+pub fn stop_api_recording(app_handle: AppHandle, state: State<DaemonState>) -> Result<RecordingFile, String> {
+    let dir: PathBuf = app_handle
+        .path()
+        .app_data_dir()
+        .map(|dir| dir.join(RECORDINGS_DIR))
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?;
+    let recording = state.api_recording.lock().unwrap().take().ok_or("No API recording is running")?;
+    let _ = app_handle.emit("api-recording-active", false);
+
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let path = dir.join(format!("{}-{}.json", recording.name, recording.started_at));
+    let file = ApiRecordingFile { name: recording.name, started_at: recording.started_at, calls: recording.calls };
+    let content = serde_json::to_string_pretty(&file).map_err(|e| format!("Failed to serialize recording: {}", e))?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    println!("[tauri] ⏺️  API recording '{}' saved to {} ({} calls)", file.name, path.display(), file.calls.len());
+
+    Ok(RecordingFile {
+        path: path.display().to_string(),
+        calls: file.calls.len(),
+    })
+}
+
+/// Re-issue the calls of a recording in order, with their original relative timing
+///
+/// `confirm` must be true: the calls move the real robot.
+#[tauri::command]
+pub async fn replay_api_recording(app_handle: AppHandle, path: String, confirm: bool) -> Result<ApiReplay, String> {
+    if !confirm {
+        return Err("Replaying a recording moves the robot, confirm to proceed".to_string());
+    }
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let recording: ApiRecordingFile = serde_json::from_str(&content).map_err(|e| format!("Invalid recording {}: {}", path, e))?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        println!("[tauri] ▶️  Replaying API recording '{}' ({} calls)", recording.name, recording.calls.len());
+        let start = Instant::now();
+        let mut calls = Vec::new();
+
+        for call in recording.calls {
+            let due = Duration::from_millis(call.offset_ms);
+            if let Some(wait) = due.checked_sub(start.elapsed()) {
+                std::thread::sleep(wait);
+            }
+
+            match send(&app_handle, &call.method, &call.path, call.body.as_ref()) {
+                Ok(response) => calls.push(ReplayedCall { method: call.method, path: call.path, status: Some(response.status), error: None }),
+                Err(e) => {
+                    // Later calls assume the earlier ones ran, don't go on without the daemon
+                    eprintln!("[tauri] ❌ Replay stopped at {} {}: {}", call.method, call.path, e);
+                    calls.push(ReplayedCall { method: call.method, path: call.path, status: None, error: Some(e) });
+                    return Ok(ApiReplay { calls, completed: false });
+                }
+            }
+        }
+
+        println!("[tauri] ✅ Replayed {} calls", calls.len());
+        Ok(ApiReplay { calls, completed: true })
+    })
+    .await
+    .map_err(|e| format!("Failed to execute replay task: {}", e))?
+}
@@ -0,0 +1,165 @@
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+use super::{add_log, is_current_session, kill_daemon, spawn_and_monitor_sidecar, start_log_session, DaemonState};
+
+/// A run that stayed up this long was healthy, the attempt count starts over
+const STABLE_RUN: Duration = Duration::from_secs(60);
+const COUNTDOWN_STEP: Duration = Duration::from_millis(250);
+
+/// Automatic restart after a crash, also returned by get_restart_state
+#[derive(Clone, Default, Serialize)]
+pub struct RestartState {
+    /// A restart is counting down
+    pub pending: bool,
+    /// Restarts since the daemon last ran for a while
+    pub attempt: u32,
+    /// Unix millis of the pending restart
+    pub next_attempt_at: Option<u64>,
+    /// Gave up after max_attempts crashes in a row
+    pub exhausted: bool,
+    /// Bumped by cancel_countdown so the countdown knows it was cancelled
+    #[serde(skip)]
+    generation: u64,
+}
+
+/// Payload of the "daemon-restart-scheduled" event
+#[derive(Clone, Serialize)]
+pub struct RestartScheduled {
+    pub session_id: u64,
+    pub attempt: u32,
+    pub max_attempts: u32,
+    pub delay_ms: u64,
+    /// Unix millis
+    pub restart_at: u64,
+}
+
+fn unix_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Cooldown before an attempt: doubled after each crash, capped
+fn restart_delay(config: &crate::settings::AutoRestartConfig, attempt: u32) -> Duration {
+    let factor = 2u64.saturating_pow(attempt.saturating_sub(1));
+    Duration::from_millis(config.initial_delay_ms.saturating_mul(factor).min(config.max_delay_ms))
+}
+
+/// Relaunch a daemon that crashed after coming up, when auto-restart is enabled
+///
+/// Emits "daemon-restart-scheduled", waits for the cooldown, then relaunches
+/// with the same options unless the restart was cancelled or the user started
+/// or stopped the daemon in the meantime.
+pub fn schedule_restart(app_handle: AppHandle, session_id: u64) {
+    let config = crate::settings::current(&app_handle).auto_restart;
+    if !config.enabled {
+        return;
+    }
+    let state: State<DaemonState> = app_handle.state();
+    let Some(launch) = state.launch.lock().unwrap().clone() else {
+        return;
+    };
+    let stable = state.started_at.lock().unwrap().is_some_and(|started| started.elapsed() >= STABLE_RUN);
+
+    let (attempt, generation, delay, restart_at) = {
+        let mut restart = state.restart.lock().unwrap();
+        if stable {
+            restart.attempt = 0;
+        }
+        if restart.attempt >= config.max_attempts {
+            restart.pending = false;
+            restart.next_attempt_at = None;
+            restart.exhausted = true;
+            drop(restart);
+            add_log(&state, format!("❌ Daemon crashed {} times in a row, auto-restart stopped", config.max_attempts));
+            return;
+        }
+        restart.attempt += 1;
+        let delay = restart_delay(&config, restart.attempt);
+        restart.pending = true;
+        restart.exhausted = false;
+        let restart_at = unix_millis() + delay.as_millis() as u64;
+        restart.next_attempt_at = Some(restart_at);
+        (restart.attempt, restart.generation, delay, restart_at)
+    };
+
+    add_log(&state, format!("🔁 Daemon crashed, restarting in {}s (attempt {}/{})", delay.as_secs(), attempt, config.max_attempts));
+    let _ = app_handle.emit("daemon-restart-scheduled", RestartScheduled {
+        session_id,
+        attempt,
+        max_attempts: config.max_attempts,
+        delay_ms: delay.as_millis() as u64,
+        restart_at,
+    });
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let state: State<DaemonState> = app_handle.state();
+        let deadline = Instant::now() + delay;
+        while Instant::now() < deadline {
+            std::thread::sleep(COUNTDOWN_STEP);
+            if state.restart.lock().unwrap().generation != generation {
+                return;
+            }
+        }
+
+        {
+            let mut restart = state.restart.lock().unwrap();
+            if restart.generation != generation {
+                return;
+            }
+            restart.pending = false;
+            restart.next_attempt_at = None;
+        }
+        // Stopped or started by the user meanwhile
+        if !is_current_session(&app_handle, session_id) {
+            return;
+        }
+
+        let settings = crate::settings::current(&app_handle);
+        kill_daemon(&state, &settings.shutdown);
+        start_log_session(&state, "auto-restart");
+        if let Err(e) = spawn_and_monitor_sidecar(
+            app_handle.clone(),
+            &state,
+            launch.sim_mode,
+            launch.safe_mode,
+            launch.serial_port,
+            settings.readiness.start_retries,
+            launch.working_dir,
+        ) {
+            add_log(&state, format!("❌ Daemon auto-restart failed: {}", e));
+        }
+    });
+}
+
+/// Stop a pending countdown, false when none was pending
+/// (stopping or starting the daemon supersedes an automatic restart)
+pub fn cancel_countdown(state: &State<DaemonState>) -> bool {
+    let mut restart = state.restart.lock().unwrap();
+    if !restart.pending {
+        return false;
+    }
+    restart.generation += 1;
+    restart.pending = false;
+    restart.next_attempt_at = None;
+    restart.attempt = 0;
+    true
+}
+
+/// Whether a restart is counting down, the attempt count and when it runs
+#[tauri::command]
+pub fn get_restart_state(state: State<DaemonState>) -> RestartState {
+    state.restart.lock().unwrap().clone()
+}
+
+/// Cancel the pending automatic restart, the daemon stays down
+#[tauri::command]
+pub fn cancel_pending_restart(state: State<DaemonState>) -> Result<RestartState, String> {
+    if !cancel_countdown(&state) {
+        return Err("No restart is pending".to_string());
+    }
+    add_log(&state, "⏹️ Pending daemon restart cancelled".to_string());
+    Ok(state.restart.lock().unwrap().clone())
+}
@@ -0,0 +1,106 @@
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+use super::DaemonState;
+
+/// Daemon output reporting a viewer failure (GLFW/OpenGL/display errors)
+const VIEWER_FAILURE_MARKERS: &[&str] = &[
+    "glfw error",
+    "failed to initialize glfw",
+    "could not create window",
+    "could not initialize opengl",
+    "failed to load opengl",
+    "glxbadfbconfig",
+    "cannot connect to x server",
+    "cannot open display",
+    "no display",
+];
+
+/// State of the MuJoCo viewer window in the current run, also the payload of
+/// the "sim-viewer-opened" and "sim-viewer-failed" events
+#[derive(Clone, Serialize)]
+pub struct SimViewer {
+    pub session_id: u64,
+    pub opened: bool,
+    /// Daemon line reporting the failure
+    pub error: Option<String>,
+    /// Likely cause from the graphics capability check (headless, software GL)
+    pub suggestion: Option<String>,
+}
+
+fn is_viewer_failure(line: &str) -> bool {
+    let line = line.to_lowercase();
+    VIEWER_FAILURE_MARKERS.iter().any(|marker| line.contains(marker))
+}
+
+fn is_viewer_opened(line: &str) -> bool {
+    let line = line.to_lowercase();
+    line.contains("viewer") && ["launched", "opened", "started"].iter().any(|word| line.contains(word))
+}
+
+/// Only the MuJoCo simulation opens a viewer (the safe mode mockup doesn't)
+fn is_mujoco_run(state: &State<DaemonState>) -> bool {
+    state
+        .launch
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|launch| launch.sim_mode && !launch.safe_mode)
+}
+
+/// Record the viewer outcome of the current run and notify the UI (once per run)
+fn report(app_handle: &AppHandle, opened: bool, error: Option<String>) {
+    let state: State<DaemonState> = app_handle.state();
+    let session_id = *state.session_id.lock().unwrap();
+    let viewer = SimViewer { session_id, opened, error: error.clone(), suggestion: None };
+    {
+        let mut current = state.sim_viewer.lock().unwrap();
+        if current.is_some() {
+            return;
+        }
+        *current = Some(viewer.clone());
+    }
+
+    if opened {
+        println!("[tauri] 🪟 MuJoCo viewer opened");
+        let _ = app_handle.emit("sim-viewer-opened", viewer);
+        return;
+    }
+
+    // Probing the graphics stack shells out, keep it off the output monitor
+    let app_handle = app_handle.clone();
+    std::thread::spawn(move || {
+        let suggestion = crate::system::check_graphics_capability().warning;
+        println!("[tauri] ⚠️  MuJoCo viewer failed to open: {}", error.as_deref().unwrap_or("unknown error"));
+
+        let state: State<DaemonState> = app_handle.state();
+        let viewer = {
+            let mut viewer = state.sim_viewer.lock().unwrap();
+            match viewer.as_mut() {
+                Some(viewer) if viewer.session_id == session_id => {
+                    viewer.suggestion = suggestion;
+                    viewer.clone()
+                }
+                _ => return,
+            }
+        };
+        let _ = app_handle.emit("sim-viewer-failed", viewer);
+    });
+}
+
+/// Watch the daemon output for the viewer opening or failing in sim mode
+pub fn observe_line(app_handle: &AppHandle, line: &str) {
+    let failed = is_viewer_failure(line);
+    if !failed && !is_viewer_opened(line) {
+        return;
+    }
+    if !is_mujoco_run(&app_handle.state()) {
+        return;
+    }
+    report(app_handle, !failed, failed.then(|| line.trim().to_string()));
+}
+
+/// Viewer outcome of the current run, None until the daemon reported it
+#[tauri::command]
+pub fn get_sim_viewer_status(state: State<DaemonState>) -> Option<SimViewer> {
+    state.sim_viewer.lock().unwrap().clone()
+}
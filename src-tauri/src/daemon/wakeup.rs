@@ -0,0 +1,88 @@
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+use super::{api_request, DaemonState};
+use super::readiness::ReadinessStage;
+
+const WAKE_UP_PATH: &str = "/api/move/play/wake_up";
+const RUNNING_MOVES_PATH: &str = "/api/move/running";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
+/// The wake-up motion takes a few seconds, give up waiting after this
+const WAKE_UP_TIMEOUT: Duration = Duration::from_secs(15);
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Payload of the "robot-awake" event
+#[derive(Clone, Serialize)]
+pub struct RobotAwake {
+    pub session_id: u64,
+    /// "log" when seen in the daemon output (wake-up on start), "command" after wake_up_robot
+    pub source: String,
+}
+
+/// Daemon log lines reporting the end of the wake-up motion
+fn is_wake_up_complete(line: &str) -> bool {
+    let line = line.to_lowercase();
+    let mentions_wake_up = line.contains("wake up") || line.contains("wake_up") || line.contains("wakeup");
+    mentions_wake_up && ["complete", "done", "finished"].iter().any(|word| line.contains(word))
+}
+
+/// Record the robot as awake for the current run and notify the UI (once per run)
+fn mark_awake(app_handle: &AppHandle, source: &str) {
+    let state: State<DaemonState> = app_handle.state();
+    if std::mem::replace(&mut *state.awake.lock().unwrap(), true) {
+        return;
+    }
+    let session_id = *state.session_id.lock().unwrap();
+
+    println!("[tauri] 🌅 Robot is awake ({})", source);
+    let _ = app_handle.emit("robot-awake", RobotAwake { session_id, source: source.to_string() });
+}
+
+/// Watch the daemon output for the end of the wake-up on start
+pub fn observe_line(app_handle: &AppHandle, line: &str) {
+    if is_wake_up_complete(line) {
+        mark_awake(app_handle, "log");
+    }
+}
+
+/// Play the wake-up motion (e.g. when the daemon was started with wake-up
+/// disabled) and wait for it to finish; "robot-awake" is emitted once it has
+#[tauri::command]
+pub async fn wake_up_robot(app_handle: AppHandle) -> Result<(), String> {
+    {
+        let state: State<DaemonState> = app_handle.state();
+        if *state.readiness.lock().unwrap() != Some(ReadinessStage::Ready) && !*state.attached.lock().unwrap() {
+            return Err("Daemon is not ready yet".to_string());
+        }
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let response = api_request(&app_handle, "POST", WAKE_UP_PATH, None, REQUEST_TIMEOUT)?;
+        if !response.is_success() {
+            return Err(format!("Wake-up failed: HTTP {} {}", response.status, response.body.trim()));
+        }
+
+        // The daemon answers with the id of the move it started, wait for it to end
+        let move_id = serde_json::from_str::<serde_json::Value>(&response.body)
+            .ok()
+            .and_then(|body| body.get("uuid").and_then(|id| id.as_str()).map(str::to_string));
+        if let Some(move_id) = move_id {
+            let start = Instant::now();
+            loop {
+                std::thread::sleep(POLL_INTERVAL);
+                let running = api_request(&app_handle, "GET", RUNNING_MOVES_PATH, None, REQUEST_TIMEOUT)?;
+                if !running.body.contains(&move_id) {
+                    break;
+                }
+                if start.elapsed() >= WAKE_UP_TIMEOUT {
+                    return Err(format!("Wake-up still running after {}s", WAKE_UP_TIMEOUT.as_secs()));
+                }
+            }
+        }
+
+        mark_awake(&app_handle, "command");
+        Ok(())
+    })
+    .await
+    .map_err(|e| format!("Failed to execute wake-up task: {}", e))?
+}
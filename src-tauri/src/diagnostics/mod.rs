@@ -0,0 +1,115 @@
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+use crate::daemon::{DaemonState, SidecarLine};
+
+const INSTALL_ID_FILE: &str = "install_id";
+
+// ============================================================================
+// INSTALL ID
+// ============================================================================
+
+/// Anonymous identifier of this installation, created on first use
+/// Contains no machine or user information, it only correlates support reports
+pub fn install_id(app_handle: &AppHandle) -> Result<String, String> {
+    let data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?;
+    let path = data_dir.join(INSTALL_ID_FILE);
+
+    if let Ok(id) = std::fs::read_to_string(&path) {
+        let id = id.trim();
+        if !id.is_empty() {
+            return Ok(id.to_string());
+        }
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    std::fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create app data dir {}: {}", data_dir.display(), e))?;
+    std::fs::write(&path, &id)
+        .map_err(|e| format!("Failed to write install id {}: {}", path.display(), e))?;
+
+    println!("[tauri] 🆔 Generated install id");
+    Ok(id)
+}
+
+#[tauri::command]
+pub fn get_install_id(app_handle: AppHandle) -> Result<String, String> {
+    install_id(&app_handle)
+}
+
+// ============================================================================
+// DIAGNOSTICS BUNDLE
+// ============================================================================
+
+/// Everything support needs to investigate a report, without personal data
+#[derive(Serialize)]
+pub struct DiagnosticsBundle {
+    pub install_id: Option<String>,
+    pub app_version: String,
+    pub update_history: Vec<crate::updates::UpdateEntry>,
+    pub setup_timings: Option<crate::install::SetupTimings>,
+    pub os: String,
+    pub runtime_arch: crate::system::RuntimeArch,
+    pub reachy_mini: Option<crate::python::VersionCompatibility>,
+    pub disk: Option<crate::system::DiskStatus>,
+    pub usb: crate::usb::UsbTree,
+    pub trampoline_layout: Option<crate::python::TrampolineLayout>,
+    pub bundle_integrity: crate::bundle::BundleIntegrity,
+    pub settings: crate::settings::AppSettings,
+    pub daemon_running: bool,
+    /// Most recent daemon spawn command and environment (secrets redacted), even if it crashed
+    pub last_launch: Option<crate::daemon::LastLaunch>,
+    pub logs: Vec<String>,
+    pub sidecar_output: Vec<SidecarLine>,
+    /// Sections that could not be collected, with the reason
+    pub errors: Vec<String>,
+}
+
+/// Keep a section's value, or record why it couldn't be collected
+fn section<T>(errors: &mut Vec<String>, name: &str, result: Result<T, String>) -> Option<T> {
+    result.map_err(|e| errors.push(format!("{}: {}", name, e))).ok()
+}
+
+pub fn collect(app_handle: &AppHandle) -> DiagnosticsBundle {
+    let mut errors = Vec::new();
+    let install_id = section(&mut errors, "install_id", install_id(app_handle));
+    let reachy_mini = section(&mut errors, "reachy_mini", crate::python::check_version_compatibility());
+    let disk = section(&mut errors, "disk", crate::system::install_disk_status());
+    let trampoline_layout = section(&mut errors, "trampoline_layout", crate::python::last_trampoline_layout());
+
+    let state: State<DaemonState> = app_handle.state();
+    let daemon_running = state.process.lock().unwrap().is_some();
+    let last_launch = state.last_launch.lock().unwrap().clone();
+    let logs = state.logs.lock().unwrap().iter().map(|entry| entry.formatted()).collect();
+    let sidecar_output = state.sidecar_output.lock().unwrap().iter().cloned().collect();
+
+    DiagnosticsBundle {
+        install_id,
+        app_version: app_handle.package_info().version.to_string(),
+        update_history: crate::updates::history(app_handle),
+        setup_timings: crate::install::last_setup_timings(app_handle),
+        os: std::env::consts::OS.to_string(),
+        runtime_arch: crate::system::get_runtime_arch(),
+        reachy_mini,
+        disk,
+        usb: crate::usb::usb_tree(),
+        trampoline_layout,
+        bundle_integrity: crate::bundle::check_bundle_integrity(),
+        settings: crate::settings::current(app_handle),
+        daemon_running,
+        last_launch,
+        logs,
+        sidecar_output,
+        errors,
+    }
+}
+
+/// Collect a diagnostics bundle to attach to a support report
+#[tauri::command]
+pub async fn collect_diagnostics(app_handle: AppHandle) -> Result<DiagnosticsBundle, String> {
+    tauri::async_runtime::spawn_blocking(move || collect(&app_handle))
+        .await
+        .map_err(|e| format!("Failed to collect diagnostics: {}", e))
+}
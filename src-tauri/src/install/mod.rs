@@ -0,0 +1,1023 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_shell::process::{CommandChild, TerminatedPayload};
+use tauri_plugin_shell::ShellExt;
+use crate::settings::KinematicsEngine;
+
+const WATCHDOG_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Set while an install (single command or step sequence) runs, one at a time
+static INSTALL_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Holds INSTALL_RUNNING until dropped, moved into the completion callback
+/// of the install it guards
+struct InstallLock;
+
+impl InstallLock {
+    /// Fails while another install runs (MuJoCo, kinematics extras, version changes, venv rebuilds)
+    fn acquire() -> Result<Self, String> {
+        // Two installs would modify the venv at the same time
+        if INSTALL_RUNNING.swap(true, Ordering::SeqCst) {
+            return Err("Another install is already running, wait for it to finish".to_string());
+        }
+        Ok(InstallLock)
+    }
+}
+
+impl Drop for InstallLock {
+    fn drop(&mut self) {
+        INSTALL_RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Payload of the "install-slow" and "install-timeout" events
+#[derive(Clone, Serialize)]
+pub struct InstallProgress {
+    /// Install name, also used as log prefix (e.g. "mujoco-install")
+    pub name: String,
+    pub elapsed_secs: u64,
+}
+
+// ============================================================================
+// INSTALL WATCHDOG
+// ============================================================================
+
+/// Watch a running install
+///
+/// Past the soft threshold an "install-slow" event reassures the user the
+/// install is still running; past the hard threshold the process is killed
+/// and "install-timeout" is emitted. Returns the flag the install monitor
+/// must set once the process has terminated.
+pub fn watch_install(app_handle: AppHandle, name: &str, child: CommandChild) -> Arc<AtomicBool> {
+    let finished = Arc::new(AtomicBool::new(false));
+    let finished_clone = finished.clone();
+    let name = name.to_string();
+    let timeouts = crate::settings::current(&app_handle).install_timeouts;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let slow_after = Duration::from_secs(timeouts.slow_after_secs);
+        let cancel_after = Duration::from_secs(timeouts.cancel_after_secs);
+        let start = Instant::now();
+        let mut warned = false;
+
+        while !finished_clone.load(Ordering::SeqCst) {
+            std::thread::sleep(WATCHDOG_INTERVAL);
+            let elapsed = start.elapsed();
+            let progress = InstallProgress {
+                name: name.clone(),
+                elapsed_secs: elapsed.as_secs(),
+            };
+
+            if elapsed >= cancel_after {
+                println!("[tauri] ❌ [{}] Install still running after {}s, cancelling", name, elapsed.as_secs());
+                if let Err(e) = child.kill() {
+                    eprintln!("[tauri] ⚠️  [{}] Failed to kill install: {}", name, e);
+                }
+                let _ = app_handle.emit("install-timeout", progress);
+                return;
+            }
+
+            if !warned && elapsed >= slow_after {
+                warned = true;
+                println!("[tauri] ⏳ [{}] Install still running after {}s", name, elapsed.as_secs());
+                let _ = app_handle.emit("install-slow", progress);
+            }
+        }
+    });
+
+    finished
+}
+
+// ============================================================================
+// UV INSTALLS
+// ============================================================================
+
+/// Set once the bundled uv ran successfully, so the check runs once per app run
+static UV_VERIFIED: AtomicBool = AtomicBool::new(false);
+
+/// Outcome of running the bundled `uv --version`
+#[derive(Clone, Serialize)]
+pub struct UvCheck {
+    pub ok: bool,
+    pub path: String,
+    pub version: Option<String>,
+    /// "missing", "not_executable", "wrong_architecture", "crashed" or "unexpected_output"
+    pub failure: Option<String>,
+    pub error: Option<String>,
+}
+
+fn run_uv_check() -> Result<UvCheck, String> {
+    let uv_path = crate::python::find_uv_folder()?.join(uv_wrapper::UV_EXE);
+    let check = match uv_wrapper::check_uv(&uv_path) {
+        Ok(version) => {
+            UV_VERIFIED.store(true, Ordering::SeqCst);
+            UvCheck { ok: true, path: uv_path.display().to_string(), version: Some(version), failure: None, error: None }
+        }
+        Err((failure, error)) => UvCheck {
+            ok: false,
+            path: uv_path.display().to_string(),
+            version: None,
+            failure: Some(failure.as_str().to_string()),
+            error: Some(error),
+        },
+    };
+    Ok(check)
+}
+
+/// Fail with the reason the bundled uv can't run, before it fails deep in an install
+/// A broken or missing uv is first replaced by the cached copy, if there is one
+fn ensure_uv_runnable(app_handle: &AppHandle) -> Result<(), String> {
+    if UV_VERIFIED.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+    let mut check = run_uv_check()?;
+    if let Some(ref error) = check.error {
+        eprintln!("[tauri] ❌ Bundled uv is unusable: {}", error);
+        if restore_cached_uv(app_handle, std::path::Path::new(&check.path)) {
+            check = run_uv_check()?;
+        }
+    }
+    match check.error {
+        None => {
+            println!("[tauri] ✅ Bundled {}", check.version.unwrap_or_default());
+            // A uv known to run is kept for later repairs without network
+            if let Err(e) = cache_uv_binary(app_handle) {
+                eprintln!("[tauri] ⚠️  Failed to cache uv: {}", e);
+            }
+            Ok(())
+        }
+        Some(error) => Err(format!(
+            "The bundled uv can't run ({}): {}. Please reinstall Reachy Mini Control.",
+            check.failure.unwrap_or_default(),
+            error
+        )),
+    }
+}
+
+// ============================================================================
+// UV CACHE
+// ============================================================================
+
+/// Copy of a working uv in the app data dir, for repairs without network
+const UV_CACHE_DIR: &str = "uv-cache";
+
+#[derive(Clone, Serialize)]
+pub struct CachedUv {
+    pub present: bool,
+    pub path: String,
+    /// None when missing or not runnable
+    pub version: Option<String>,
+    pub error: Option<String>,
+}
+
+fn cached_uv_path(app_handle: &AppHandle) -> Result<std::path::PathBuf, String> {
+    use tauri::Manager;
+
+    app_handle
+        .path()
+        .app_data_dir()
+        .map(|dir| dir.join(UV_CACHE_DIR).join(uv_wrapper::UV_EXE))
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))
+}
+
+/// Keep a copy of the bundled uv after a successful setup (replacing an older copy)
+fn cache_uv_binary(app_handle: &AppHandle) -> Result<(), String> {
+    let source = crate::python::find_uv_folder()?.join(uv_wrapper::UV_EXE);
+    let version = uv_wrapper::check_uv(&source).map_err(|(_, e)| e)?;
+    let cached = cached_uv_path(app_handle)?;
+    if uv_wrapper::check_uv(&cached).is_ok_and(|cached_version| cached_version == version) {
+        return Ok(());
+    }
+
+    if let Some(dir) = cached.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    std::fs::copy(&source, &cached).map_err(|e| format!("Failed to copy uv to {}: {}", cached.display(), e))?;
+    println!("[tauri] 📦 Cached {} in {}", version, cached.display());
+    Ok(())
+}
+
+/// Replace an unusable bundled uv with the cached copy, true if it was restored
+fn restore_cached_uv(app_handle: &AppHandle, target: &std::path::Path) -> bool {
+    let Ok(cached) = cached_uv_path(app_handle) else {
+        return false;
+    };
+    let Ok(version) = uv_wrapper::check_uv(&cached) else {
+        return false;
+    };
+
+    // Remove first: a damaged binary may be read-only
+    let _ = std::fs::remove_file(target);
+    match std::fs::copy(&cached, target) {
+        Ok(_) => {
+            println!("[tauri] 📦 Restored {} from the cache", version);
+            true
+        }
+        Err(e) => {
+            eprintln!("[tauri] ⚠️  Failed to restore the cached uv to {}: {}", target.display(), e);
+            false
+        }
+    }
+}
+
+/// Whether a copy of uv is cached for offline repairs, and its version
+#[tauri::command]
+pub fn get_cached_uv(app_handle: AppHandle) -> Result<CachedUv, String> {
+    let path = cached_uv_path(&app_handle)?;
+    let present = path.is_file();
+    let (version, error) = match uv_wrapper::check_uv(&path) {
+        Ok(version) => (Some(version), None),
+        Err(_) if !present => (None, None),
+        Err((_, e)) => (None, Some(e)),
+    };
+    Ok(CachedUv { present, path: path.display().to_string(), version, error })
+}
+
+/// Check that the bundled uv runs on this machine (right arch, executable, intact)
+#[tauri::command]
+pub async fn check_uv_binary() -> Result<UvCheck, String> {
+    tauri::async_runtime::spawn_blocking(run_uv_check)
+        .await
+        .map_err(|e| format!("Failed to execute uv check task: {}", e))?
+}
+
+/// Run a uv command (e.g. `pip install ...`) through uv-trampoline in the background
+///
+/// Output is forwarded with `name` as prefix, the install is watched for
+/// timeouts, and `on_finished` is called with true if it exited successfully.
+/// Fails while another install runs (MuJoCo, kinematics extras, version changes).
+pub fn spawn_uv_install<F>(app_handle: &AppHandle, name: &str, args: &[&str], on_finished: F) -> Result<(), String>
+where
+    F: FnOnce(bool) + Send + 'static,
+{
+    let lock = InstallLock::acquire()?;
+    spawn_uv_command(app_handle, name, args, move |success| {
+        drop(lock);
+        on_finished(success);
+    })
+}
+
+/// spawn_uv_install without taking the install lock, for callers already holding it
+/// (dropping `on_finished` unused, on failure, releases what it holds)
+fn spawn_uv_command<F>(app_handle: &AppHandle, name: &str, args: &[&str], on_finished: F) -> Result<(), String>
+where
+    F: FnOnce(bool) + Send + 'static,
+{
+    // Fail early with a clear message rather than a "no space" or
+    // "permission denied" error mid-install
+    crate::system::ensure_disk_space_for_install()?;
+    crate::system::ensure_venv_writable()?;
+    crate::bundle::ensure_sidecar("uv-trampoline")?;
+    ensure_uv_runnable(app_handle)?;
+
+    let settings = crate::settings::current(app_handle);
+    println!("[tauri] ⚙️  {}: uv concurrency {}", name, settings.uv_concurrency.describe());
+    let spawned = app_handle
+        .shell()
+        .sidecar("uv-trampoline")
+        .map_err(|e| format!("Failed to find uv-trampoline: {}", e))
+        .and_then(|command| {
+            command
+                .args(args)
+                .env(uv_wrapper::VERBOSE_ENV, if settings.verbose_installs { "1" } else { "0" })
+                .env(uv_wrapper::SKIP_SIGNING_ENV, if settings.developer_mode { "1" } else { "0" })
+                .envs(settings.uv_concurrency.env())
+                .envs(crate::python::trampoline_env())
+                .set_raw_out(true)
+                .spawn()
+                .map_err(|e| format!("Failed to spawn uv-trampoline: {}", e))
+        });
+    let (mut rx, child) = spawned?;
+
+    // Warn when the install is slow, cancel it when it exceeds the hard timeout
+    let finished = watch_install(app_handle.clone(), name, child);
+
+    let app_handle = app_handle.clone();
+    crate::spawn_sidecar_monitor!(rx, app_handle, Some(name.to_string()), move |status: TerminatedPayload| {
+        finished.store(true, Ordering::SeqCst);
+        on_finished(status.code == Some(0));
+    });
+
+    Ok(())
+}
+
+// ============================================================================
+// KINEMATICS ENGINE
+// ============================================================================
+
+/// Payload of the "kinematics-engine-changed" event
+#[derive(Clone, Serialize)]
+pub struct KinematicsEngineChange {
+    pub engine: KinematicsEngine,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Select the daemon's kinematics engine, installing the matching reachy-mini
+/// extra first so the engine doesn't fail at import
+///
+/// The setting only changes once the install succeeded ("kinematics-engine-changed").
+#[tauri::command]
+pub fn set_kinematics_engine(app_handle: AppHandle, engine: KinematicsEngine) -> Result<String, String> {
+    let extra = match engine.extra() {
+        Some(extra) => extra,
+        None => {
+            crate::settings::update(&app_handle, |settings| settings.kinematics_engine = engine)?;
+            let _ = app_handle.emit("kinematics-engine-changed", KinematicsEngineChange { engine, success: true, error: None });
+            return Ok(format!("Kinematics engine set to {}", engine.flag()));
+        }
+    };
+
+    // Keep the installed version, only add the extra's dependencies
+    let installed_version = crate::python::find_venv_dir()
+        .ok()
+        .and_then(|venv| crate::python::installed_package_version(&venv, "reachy-mini"));
+    let requirement = match installed_version {
+        Some(version) => format!("reachy-mini[{}]=={}", extra, version),
+        None => format!("reachy-mini[{}]", extra),
+    };
+
+    println!("[tauri] 🦾 Installing {} for the {} kinematics engine", requirement, engine.flag());
+
+    let app_handle_clone = app_handle.clone();
+    let failure = format!("Failed to install {}", requirement);
+    spawn_uv_install(&app_handle, "kinematics-install", &["pip", "install", &requirement], move |success| {
+        let error = if success {
+            crate::settings::update(&app_handle_clone, |settings| settings.kinematics_engine = engine).err()
+        } else {
+            Some(failure)
+        };
+
+        // New native libraries must be re-signed before the daemon loads them
+        #[cfg(target_os = "macos")]
+        if success {
+            tauri::async_runtime::spawn(crate::signing::sign_python_binaries(app_handle_clone.clone()));
+        }
+
+        let _ = app_handle_clone.emit("kinematics-engine-changed", KinematicsEngineChange {
+            engine,
+            success: error.is_none(),
+            error,
+        });
+    })?;
+
+    Ok(format!("Installing dependencies for the {} kinematics engine", engine.flag()))
+}
+
+// ============================================================================
+// VENV ARCHITECTURE
+// ============================================================================
+
+/// Payload of the "venv-arch-changed" event
+#[derive(Clone, Serialize)]
+pub struct VenvArchChange {
+    pub arch: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Run uv commands one after the other, stopping at the first failure
+///
+/// The install lock is held from the first step to the last, so no other
+/// install slips in between. Fails while another install runs.
+fn spawn_uv_steps<F>(app_handle: &AppHandle, name: &str, steps: VecDeque<Vec<String>>, on_finished: F) -> Result<(), String>
+where
+    F: FnOnce(Result<(), String>) + Send + 'static,
+{
+    spawn_timed_uv_steps(app_handle, name, steps, move |result, _| on_finished(result))
+}
+
+/// Same as spawn_uv_steps, also reporting how long each finished step took
+fn spawn_timed_uv_steps<F>(app_handle: &AppHandle, name: &str, steps: VecDeque<Vec<String>>, on_finished: F) -> Result<(), String>
+where
+    F: FnOnce(Result<(), String>, Vec<PhaseTiming>) + Send + 'static,
+{
+    let lock = InstallLock::acquire()?;
+    run_uv_steps(app_handle, name, steps, Vec::new(), move |result, timings| {
+        drop(lock);
+        on_finished(result, timings);
+    });
+    Ok(())
+}
+
+/// Run the next step, under the install lock taken by spawn_timed_uv_steps
+fn run_uv_steps<F>(
+    app_handle: &AppHandle,
+    name: &str,
+    mut steps: VecDeque<Vec<String>>,
+    timings: Vec<PhaseTiming>,
+    on_finished: F,
+)
+where
+    F: FnOnce(Result<(), String>, Vec<PhaseTiming>) + Send + 'static,
+{
+    let step = match steps.pop_front() {
+        Some(step) => step,
+        None => return on_finished(Ok(()), timings),
+    };
+    let started = Instant::now();
+    let phase = phase_name(&step);
+
+    // on_finished is called either by the completion callback or on spawn failure
+    let on_finished = Arc::new(Mutex::new(Some(on_finished)));
+    let on_finished_clone = on_finished.clone();
+    let app_handle_clone = app_handle.clone();
+    let name_owned = name.to_string();
+    let failure = format!("uv {} failed", step.join(" "));
+    let args: Vec<&str> = step.iter().map(String::as_str).collect();
+
+    let timings_clone = Arc::new(Mutex::new(Some(timings)));
+    let timings_for_error = timings_clone.clone();
+
+    let spawned = spawn_uv_command(app_handle, name, &args, move |success| {
+        let on_finished = match on_finished_clone.lock().unwrap().take() {
+            Some(on_finished) => on_finished,
+            None => return,
+        };
+        let mut timings = timings_clone.lock().unwrap().take().unwrap_or_default();
+        timings.push(PhaseTiming { phase, duration_ms: started.elapsed().as_millis() as u64 });
+        if success {
+            run_uv_steps(&app_handle_clone, &name_owned, steps, timings, on_finished);
+        } else {
+            on_finished(Err(failure), timings);
+        }
+    });
+
+    if let Err(e) = spawned {
+        if let Some(on_finished) = on_finished.lock().unwrap().take() {
+            on_finished(Err(e), timings_for_error.lock().unwrap().take().unwrap_or_default());
+        }
+    }
+}
+
+/// reachy-mini extras the venv currently relies on (kinematics engine, MuJoCo),
+/// as a requirement suffix such as "[mujoco]", empty when there are none
+fn installed_extras(app_handle: &AppHandle, venv_dir: &std::path::Path) -> String {
+    let mut extras: Vec<&str> = crate::settings::current(app_handle)
+        .kinematics_engine
+        .extra()
+        .into_iter()
+        .collect();
+    if crate::python::installed_package_version(venv_dir, "mujoco").is_some() {
+        extras.push("mujoco");
+    }
+    if extras.is_empty() { String::new() } else { format!("[{}]", extras.join(",")) }
+}
+
+/// Requirement reinstalling the current reachy-mini version and extras in a new venv
+fn reinstall_requirement(app_handle: &AppHandle, venv_dir: &std::path::Path) -> String {
+    let extras = installed_extras(app_handle, venv_dir);
+    match crate::python::installed_package_version(venv_dir, "reachy-mini") {
+        Some(version) => format!("reachy-mini{}=={}", extras, version),
+        None => format!("reachy-mini{}", extras),
+    }
+}
+
+/// uv commands creating a venv from scratch: fetch the interpreter, create
+/// the venv (clearing any previous one) and install the daemon
+fn venv_creation_steps(python_request: &str, venv_path: &str, requirement: &str) -> VecDeque<Vec<String>> {
+    [
+        vec!["python", "install", python_request],
+        vec!["venv", "--clear", "--python", python_request, venv_path],
+        vec!["pip", "install", requirement],
+    ]
+    .into_iter()
+    .map(|step| step.into_iter().map(String::from).collect())
+    .collect()
+}
+
+/// Rebuild the venv with an interpreter of the given architecture
+/// ("x86_64" or "aarch64"), e.g. an x86_64 venv on Apple Silicon for a
+/// dependency only published as x86_64 wheels
+///
+/// Keeps the installed reachy-mini version and extras; other packages
+/// (installed apps) have to be reinstalled. The outcome is reported with
+/// "venv-arch-changed".
+#[tauri::command]
+pub fn rebuild_venv_for_arch(
+    app_handle: AppHandle,
+    state: tauri::State<crate::daemon::DaemonState>,
+    arch: String,
+) -> Result<String, String> {
+    let arch = match arch.as_str() {
+        "x86_64" | "amd64" => "x86_64",
+        "aarch64" | "arm64" => "aarch64",
+        other => return Err(format!("Unsupported architecture: {}", other)),
+    };
+
+    if state.process.lock().unwrap().is_some() || *state.attached.lock().unwrap() {
+        return Err("Stop the daemon before rebuilding the venv".to_string());
+    }
+
+    let uv_folder = crate::python::find_uv_folder()?;
+    let venv_dir = crate::python::venv_dir(&uv_folder);
+    let cpython_folder = uv_wrapper::find_cpython_folder(&uv_folder, &venv_dir)?;
+    let version = uv_wrapper::cpython_folder_version(&cpython_folder)
+        .ok_or_else(|| format!("Unable to read the Python version of {}", cpython_folder))?;
+    let request = uv_wrapper::python_request(version, Some(arch));
+
+    // Read what to reinstall before the venv is cleared
+    let venv_path = venv_dir.display().to_string();
+    let requirement = reinstall_requirement(&app_handle, &venv_dir);
+
+    println!("[tauri] 🏗️  Rebuilding the venv with {} ({})", request, requirement);
+
+    let steps = venv_creation_steps(&request, &venv_path, &requirement);
+
+    let app_handle_clone = app_handle.clone();
+    let arch_owned = arch.to_string();
+    spawn_uv_steps(&app_handle, "venv-rebuild", steps, move |result| {
+        // The new interpreter and native libraries must be signed before the daemon loads them
+        #[cfg(target_os = "macos")]
+        if result.is_ok() {
+            tauri::async_runtime::spawn(crate::signing::sign_python_binaries(app_handle_clone.clone()));
+        }
+
+        match &result {
+            Ok(()) => println!("[tauri] ✅ Venv rebuilt for {}", arch_owned),
+            Err(e) => eprintln!("[tauri] ❌ Venv rebuild for {} failed: {}", arch_owned, e),
+        }
+
+        let _ = app_handle_clone.emit("venv-arch-changed", VenvArchChange {
+            arch: arch_owned,
+            success: result.is_ok(),
+            error: result.err(),
+        });
+    })?;
+
+    Ok(format!("Rebuilding the venv for {}", arch))
+}
+
+// ============================================================================
+// PYTHON INTERPRETERS
+// ============================================================================
+
+/// Interpreter folder installed in the uv folder
+#[derive(Serialize)]
+pub struct PythonInterpreter {
+    /// Folder name, e.g. "cpython-3.12.8-macos-aarch64-none"
+    pub folder: String,
+    pub version: Option<String>,
+    pub arch: Option<String>,
+    /// The venv was created from this interpreter
+    pub in_use: bool,
+}
+
+/// Payload of the "venv-interpreter-changed" event
+#[derive(Clone, Serialize)]
+pub struct VenvInterpreterChange {
+    pub folder: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Interpreters installed next to uv (several coexist after upgrades or an
+/// architecture change), newest version first
+#[tauri::command]
+pub fn list_python_interpreters() -> Result<Vec<PythonInterpreter>, String> {
+    let uv_folder = crate::python::find_uv_folder()?;
+    let venv_home = uv_wrapper::read_pyvenv_home(&crate::python::venv_dir(&uv_folder));
+
+    let mut interpreters: Vec<PythonInterpreter> = uv_wrapper::list_cpython_folders(&uv_folder)?
+        .into_iter()
+        .map(|folder| PythonInterpreter {
+            version: uv_wrapper::cpython_folder_version(&folder).map(str::to_string),
+            arch: uv_wrapper::cpython_folder_arch(&folder).map(str::to_string),
+            in_use: venv_home
+                .as_deref()
+                .is_some_and(|home| std::path::Path::new(home).components().any(|c| c.as_os_str() == folder.as_str())),
+            folder,
+        })
+        .collect();
+    interpreters.sort_by(|a, b| {
+        let version = |i: &PythonInterpreter| i.version.clone().unwrap_or_default();
+        crate::python::compare_versions(&version(b), &version(a)).then_with(|| a.folder.cmp(&b.folder))
+    });
+
+    Ok(interpreters)
+}
+
+/// Recreate the venv against one of the installed interpreters
+///
+/// Keeps the installed reachy-mini version and extras; other packages
+/// (installed apps) have to be reinstalled. pyvenv.cfg is re-patched and the
+/// binaries re-signed afterwards. The outcome is reported with "venv-interpreter-changed".
+#[tauri::command]
+pub fn rebuild_venv_with_interpreter(
+    app_handle: AppHandle,
+    state: tauri::State<crate::daemon::DaemonState>,
+    folder: String,
+) -> Result<String, String> {
+    if state.process.lock().unwrap().is_some() || *state.attached.lock().unwrap() {
+        return Err("Stop the daemon before rebuilding the venv".to_string());
+    }
+
+    let uv_folder = crate::python::find_uv_folder()?;
+    if !uv_wrapper::list_cpython_folders(&uv_folder)?.contains(&folder) {
+        return Err(format!("No interpreter named {} in {}", folder, uv_folder.display()));
+    }
+    let interpreter = uv_wrapper::cpython_executable(&uv_folder, &folder);
+    if !interpreter.exists() {
+        return Err(format!("Interpreter {} does not exist", interpreter.display()));
+    }
+
+    // Read what to reinstall before the venv is cleared
+    let venv_dir = crate::python::venv_dir(&uv_folder);
+    let requirement = reinstall_requirement(&app_handle, &venv_dir);
+
+    println!("[tauri] 🐍 Rebuilding the venv with {} ({})", folder, requirement);
+
+    let interpreter_path = interpreter.display().to_string();
+    let venv_path = venv_dir.display().to_string();
+    let steps = [
+        vec!["venv", "--clear", "--python", interpreter_path.as_str(), venv_path.as_str()],
+        vec!["pip", "install", requirement.as_str()],
+    ]
+    .into_iter()
+    .map(|step| step.into_iter().map(String::from).collect())
+    .collect();
+
+    let app_handle_clone = app_handle.clone();
+    let folder_owned = folder.clone();
+    spawn_uv_steps(&app_handle, "venv-rebuild", steps, move |result| {
+        // Point pyvenv.cfg at the chosen folder the way the trampoline expects
+        let result = result.and_then(|()| uv_wrapper::patching_pyvenv_cfg(&uv_folder, &venv_dir, &folder_owned));
+
+        #[cfg(target_os = "macos")]
+        if result.is_ok() {
+            tauri::async_runtime::spawn(crate::signing::sign_python_binaries(app_handle_clone.clone()));
+        }
+
+        match &result {
+            Ok(()) => println!("[tauri] ✅ Venv rebuilt with {}", folder_owned),
+            Err(e) => eprintln!("[tauri] ❌ Venv rebuild with {} failed: {}", folder_owned, e),
+        }
+
+        let _ = app_handle_clone.emit("venv-interpreter-changed", VenvInterpreterChange {
+            folder: folder_owned,
+            success: result.is_ok(),
+            error: result.err(),
+        });
+    })?;
+
+    Ok(format!("Rebuilding the venv with {}", folder))
+}
+
+// ============================================================================
+// VERSION PIN
+// ============================================================================
+
+/// Payload of the "reachy-mini-version-installed" event
+#[derive(Clone, Serialize)]
+pub struct VersionInstall {
+    pub version: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Plain release version such as "1.2.3", "1.2.0rc1" or "1.1.0.post2"
+/// (no specifier operators, spaces or local labels)
+fn is_valid_version(version: &str) -> bool {
+    version.starts_with(|c: char| c.is_ascii_digit())
+        && !version.ends_with('.')
+        && !version.contains("..")
+        && version.chars().all(|c| c.is_ascii_alphanumeric() || c == '.')
+}
+
+/// Install an exact reachy-mini version (rollback after a regression,
+/// reproducing a report), keeping the extras currently installed
+///
+/// Stops the daemon first; the outcome is reported with "reachy-mini-version-installed".
+#[tauri::command]
+pub async fn install_reachy_mini_version(app_handle: AppHandle, version: String) -> Result<String, String> {
+    use tauri::Manager;
+
+    let version = version.trim().trim_start_matches("==").trim().to_string();
+    if !is_valid_version(&version) {
+        return Err(format!("Invalid version \"{}\", expected e.g. 1.2.3", version));
+    }
+
+    // Taken before the daemon is stopped, so a refused install leaves it running
+    let lock = InstallLock::acquire()?;
+
+    let app_handle_clone = app_handle.clone();
+    let pinned = version.clone();
+    let requirement = tauri::async_runtime::spawn_blocking(move || -> Result<String, String> {
+        let state: tauri::State<crate::daemon::DaemonState> = app_handle_clone.state();
+        if *state.attached.lock().unwrap() {
+            return Err("The daemon was started outside the app, stop it before changing its version".to_string());
+        }
+
+        // The daemon must not import packages while they are replaced
+        let pid = crate::daemon::kill_daemon(&state, &crate::settings::current(&app_handle_clone).shutdown);
+        let endpoint = state.endpoint.lock().unwrap().clone();
+        crate::daemon::wait_for_daemon_shutdown(&endpoint, pid, Duration::from_secs(5))?;
+
+        let venv_dir = crate::python::find_venv_dir()?;
+        Ok(format!("reachy-mini{}=={}", installed_extras(&app_handle_clone, &venv_dir), pinned))
+    })
+    .await
+    .map_err(|e| format!("Failed to execute version install task: {}", e))??;
+
+    if crate::python::compare_versions(&version, crate::python::MIN_REACHY_MINI_VERSION).is_lt()
+        || crate::python::compare_versions(&version, crate::python::MAX_REACHY_MINI_VERSION).is_ge()
+    {
+        println!(
+            "[tauri] ⚠️  reachy-mini {} is outside the supported range ({} to {})",
+            version,
+            crate::python::MIN_REACHY_MINI_VERSION,
+            crate::python::MAX_REACHY_MINI_VERSION
+        );
+    }
+    println!("[tauri] 📌 Installing {}", requirement);
+
+    let app_handle_clone = app_handle.clone();
+    let version_owned = version.clone();
+    let failure = format!("Failed to install {}", requirement);
+    spawn_uv_command(&app_handle, "version-install", &["pip", "install", &requirement], move |success| {
+        drop(lock);
+
+        // Native libraries of the new version must be re-signed before the daemon loads them
+        #[cfg(target_os = "macos")]
+        if success {
+            tauri::async_runtime::spawn(crate::signing::sign_python_binaries(app_handle_clone.clone()));
+        }
+
+        if success {
+            println!("[tauri] ✅ reachy-mini {} installed", version_owned);
+        } else {
+            eprintln!("[tauri] ❌ {}", failure);
+        }
+
+        let _ = app_handle_clone.emit("reachy-mini-version-installed", VersionInstall {
+            version: version_owned,
+            success,
+            error: (!success).then_some(failure),
+        });
+    })?;
+
+    Ok(format!("Installing reachy-mini {}", version))
+}
+
+// ============================================================================
+// VENV SETUP
+// ============================================================================
+
+/// Payload of the "venv-setup-finished" event
+#[derive(Clone, Serialize)]
+pub struct VenvSetup {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Create the venv at its default location with the bundled interpreter and
+/// the latest reachy-mini, reporting the outcome with "venv-setup-finished"
+pub fn spawn_venv_setup(app_handle: &AppHandle) -> Result<(), String> {
+    let uv_folder = crate::python::find_uv_folder()?;
+    let venv_dir = crate::python::venv_dir(&uv_folder);
+    let cpython_folder = uv_wrapper::find_cpython_folder(&uv_folder, &venv_dir)?;
+    let version = uv_wrapper::cpython_folder_version(&cpython_folder)
+        .ok_or_else(|| format!("Unable to read the Python version of {}", cpython_folder))?;
+    let venv_path = venv_dir.display().to_string();
+    let requirement = match crate::settings::current(app_handle).kinematics_engine.extra() {
+        Some(extra) => format!("reachy-mini[{}]", extra),
+        None => "reachy-mini".to_string(),
+    };
+
+    println!("[tauri] 🏗️  Creating the venv in {} ({})", venv_path, requirement);
+
+    let steps = venv_creation_steps(&uv_wrapper::python_request(version, None), &venv_path, &requirement);
+    let app_handle_clone = app_handle.clone();
+    let started = Instant::now();
+    spawn_timed_uv_steps(app_handle, "venv-setup", steps, move |result, timings| {
+        tauri::async_runtime::spawn(async move {
+            #[cfg_attr(not(target_os = "macos"), allow(unused_mut))]
+            let mut timings = timings;
+
+            // Signed before reporting, so the daemon isn't started on unsigned binaries
+            #[cfg(target_os = "macos")]
+            if result.is_ok() {
+                let signing_started = Instant::now();
+                let _ = crate::signing::sign_python_binaries(app_handle_clone.clone()).await;
+                timings.push(PhaseTiming { phase: "signing".to_string(), duration_ms: signing_started.elapsed().as_millis() as u64 });
+            }
+
+            match &result {
+                Ok(()) => {
+                    println!("[tauri] ✅ Venv created in {}s", started.elapsed().as_secs());
+                    record_setup_timings(&app_handle_clone, started.elapsed(), timings);
+                }
+                Err(e) => eprintln!("[tauri] ❌ Venv setup failed: {}", e),
+            }
+
+            let _ = app_handle_clone.emit("venv-setup-finished", VenvSetup {
+                success: result.is_ok(),
+                error: result.err(),
+            });
+        });
+    })
+}
+
+// ============================================================================
+// SETUP TIMINGS
+// ============================================================================
+
+const SETUP_TIMINGS_FILE: &str = "setup_timings.json";
+
+#[derive(Clone, Serialize, serde::Deserialize)]
+pub struct PhaseTiming {
+    /// "python install", "venv", "pip install" or "signing"
+    pub phase: String,
+    pub duration_ms: u64,
+}
+
+/// Durations of the last successful setup, to tell users what to expect
+#[derive(Clone, Serialize, serde::Deserialize)]
+pub struct SetupTimings {
+    /// Seconds since the Unix epoch
+    pub recorded_at: u64,
+    pub total_ms: u64,
+    pub phases: Vec<PhaseTiming>,
+}
+
+/// Name of a uv step as a setup phase ("pip install", "venv")
+fn phase_name(step: &[String]) -> String {
+    match step {
+        [command, subcommand, ..] if command == "python" || command == "pip" => format!("{} {}", command, subcommand),
+        [command, ..] => command.clone(),
+        [] => String::new(),
+    }
+}
+
+fn setup_timings_path(app_handle: &AppHandle) -> Option<std::path::PathBuf> {
+    use tauri::Manager;
+
+    app_handle.path().app_data_dir().ok().map(|dir| dir.join(SETUP_TIMINGS_FILE))
+}
+
+/// Persist the phases of a successful setup, replacing the previous ones
+fn record_setup_timings(app_handle: &AppHandle, total: Duration, phases: Vec<PhaseTiming>) {
+    let Some(path) = setup_timings_path(app_handle) else {
+        return;
+    };
+    let timings = SetupTimings {
+        recorded_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        total_ms: total.as_millis() as u64,
+        phases,
+    };
+
+    let written = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| std::fs::write(&path, serde_json::to_string_pretty(&timings).unwrap_or_default()));
+    if let Err(e) = written {
+        eprintln!("[tauri] ⚠️  Failed to save setup timings {}: {}", path.display(), e);
+    }
+}
+
+/// Timings of the last successful setup, None before the first one
+pub fn last_setup_timings(app_handle: &AppHandle) -> Option<SetupTimings> {
+    let content = std::fs::read_to_string(setup_timings_path(app_handle)?).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// How long each phase of the last successful setup took
+#[tauri::command]
+pub fn get_setup_timings(app_handle: AppHandle) -> Option<SetupTimings> {
+    last_setup_timings(&app_handle)
+}
+
+// ============================================================================
+// INSTALL VERIFICATION
+// ============================================================================
+
+/// Packages checked when none are given: what install_mujoco requests
+const DEFAULT_VERIFIED_PACKAGES: &[&str] = &["mujoco", "reachy-mini"];
+
+#[derive(Serialize)]
+pub struct InstalledPackage {
+    pub name: String,
+    pub installed: bool,
+    pub version: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct InstallVerification {
+    pub packages: Vec<InstalledPackage>,
+    /// `uv pip check` found no missing or incompatible dependency
+    pub consistent: bool,
+    /// Problems reported by `uv pip check`
+    pub problems: Vec<String>,
+}
+
+/// Run a uv command through uv-trampoline and wait for it, returning (success, stdout, stderr)
+async fn run_uv_output(app_handle: &AppHandle, args: &[&str]) -> Result<(bool, String, String), String> {
+    let output = app_handle
+        .shell()
+        .sidecar("uv-trampoline")
+        .map_err(|e| format!("Failed to find uv-trampoline: {}", e))?
+        .args(args)
+        .envs(crate::python::trampoline_env())
+        .output()
+        .await
+        .map_err(|e| format!("Failed to run uv {}: {}", args.join(" "), e))?;
+
+    Ok((
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    ))
+}
+
+/// Project name of a requirement ("reachy-mini[mujoco]==1.2.0" -> "reachy-mini")
+fn requirement_name(requirement: &str) -> &str {
+    let end = requirement.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'));
+    requirement[..end.unwrap_or(requirement.len())].trim()
+}
+
+/// Names are compared the way pip normalizes them (case, `-`, `_` and `.` are equivalent)
+fn normalize_name(name: &str) -> String {
+    name.to_ascii_lowercase().replace(['_', '.'], "-")
+}
+
+/// Installed versions by normalized name, from the `Name:` / `Version:` blocks of `uv pip show`
+fn parse_pip_show(stdout: &str) -> std::collections::HashMap<String, String> {
+    let mut versions = std::collections::HashMap::new();
+    let mut name = None;
+    for line in stdout.lines() {
+        if let Some(value) = line.strip_prefix("Name:") {
+            name = Some(normalize_name(value.trim()));
+        } else if let Some(value) = line.strip_prefix("Version:") {
+            if let Some(name) = name.take() {
+                versions.insert(name, value.trim().to_string());
+            }
+        }
+    }
+    versions
+}
+
+/// Check which of the requested packages are installed in the venv, at what
+/// version, and whether its dependency set is consistent (`uv pip check`),
+/// e.g. after an install that may have partially failed
+///
+/// `packages` may be requirements such as "reachy-mini[mujoco]", defaults to
+/// the packages installed by install_mujoco.
+#[tauri::command]
+pub async fn verify_install(app_handle: AppHandle, packages: Option<Vec<String>>) -> Result<InstallVerification, String> {
+    crate::bundle::ensure_sidecar("uv-trampoline")?;
+
+    let names: Vec<String> = match packages {
+        Some(packages) => packages
+            .iter()
+            .map(|package| requirement_name(package).to_string())
+            .filter(|name| !name.is_empty())
+            .collect(),
+        None => DEFAULT_VERIFIED_PACKAGES.iter().map(|name| name.to_string()).collect(),
+    };
+    if names.is_empty() {
+        return Err("No package to verify".to_string());
+    }
+
+    // Exits with an error when any package is missing, the others are still listed
+    let mut show_args = vec!["pip", "show"];
+    show_args.extend(names.iter().map(String::as_str));
+    let (_, show_stdout, _) = run_uv_output(&app_handle, &show_args).await?;
+    let versions = parse_pip_show(&show_stdout);
+
+    let packages: Vec<InstalledPackage> = names
+        .into_iter()
+        .map(|name| {
+            let version = versions.get(&normalize_name(&name)).cloned();
+            InstalledPackage { installed: version.is_some(), version, name }
+        })
+        .collect();
+
+    let (consistent, check_stdout, check_stderr) = run_uv_output(&app_handle, &["pip", "check"]).await?;
+    let problems: Vec<String> = check_stdout
+        .lines()
+        .chain(check_stderr.lines())
+        .map(str::trim)
+        .filter(|line| line.starts_with("The package") || line.starts_with("error:"))
+        .map(String::from)
+        .collect();
+
+    let missing: Vec<&str> = packages.iter().filter(|p| !p.installed).map(|p| p.name.as_str()).collect();
+    if missing.is_empty() && consistent {
+        println!("[tauri] ✅ Install verified: {} packages installed, dependencies consistent", packages.len());
+    } else {
+        eprintln!(
+            "[tauri] ⚠️  Install verification: missing [{}], {} dependency problem(s)",
+            missing.join(", "),
+            problems.len()
+        );
+    }
+
+    Ok(InstallVerification { packages, consistent, problems })
+}
@@ -1,15 +1,26 @@
 // Modules
+mod apps;
+mod bundle;
 #[macro_use]
 mod daemon;
+mod diagnostics;
+mod install;
+mod media;
 mod permissions;
+mod preflight;
+mod process;
 mod python;
+mod reset;
+mod secrets;
+mod settings;
 mod signing;
+mod system;
+mod updates;
 mod usb;
 mod window;
 
 use tauri::{State, Manager};
-use tauri_plugin_shell::ShellExt;
-use daemon::{DaemonState, add_log, kill_daemon, cleanup_system_daemons, spawn_and_monitor_sidecar};
+use daemon::{DaemonState, LogEntry, SidecarLine, add_log, start_log_session, kill_daemon, cleanup_system_daemons, spawn_and_monitor_sidecar, wait_for_daemon_shutdown};
 
 #[cfg(not(windows))]
 use signal_hook::{consts::TERM_SIGNALS, iterator::Signals};
@@ -18,48 +29,174 @@ use signal_hook::{consts::TERM_SIGNALS, iterator::Signals};
 // TAURI COMMANDS
 // ============================================================================
 
+/// Whether the venv already has MuJoCo (simulation mode)
+fn mujoco_installed() -> bool {
+    python::find_venv_dir()
+        .ok()
+        .and_then(|venv| python::installed_package_version(&venv, "mujoco"))
+        .is_some()
+}
+
+/// Use uv-trampoline to run: uv pip install mujoco reachy-mini[mujoco]
+/// Install mujoco first, then reachy-mini[mujoco] to ensure all dependencies are available
+/// This ensures we install in the same Python environment as the daemon
+fn spawn_mujoco_install<F>(app_handle: &tauri::AppHandle, on_finished: F) -> Result<(), String>
+where
+    F: FnOnce(bool) + Send + 'static,
+{
+    install::spawn_uv_install(app_handle, "mujoco-install", &["pip", "install", "mujoco", "reachy-mini[mujoco]"], on_finished)
+}
+
 /// Install MuJoCo dependencies for simulation mode
 /// Uses uv-trampoline to install mujoco and reachy-mini[mujoco] in the same environment as the daemon
-/// Monitors installation in background
+/// Returns once the install started, its progress and end come through the install events
 #[tauri::command]
 fn install_mujoco(app_handle: tauri::AppHandle) -> Result<String, String> {
     println!("[tauri] 🎭 Installing MuJoCo dependencies for simulation mode...");
+    spawn_mujoco_install(&app_handle, |_| {})?;
+    Ok("MuJoCo installation started".to_string())
+}
+
+/// Label of a daemon run in the logs
+fn mode_label(sim_mode: bool, safe_mode: bool) -> &'static str {
+    match (sim_mode, safe_mode) {
+        (true, true) => "simulation, safe mode",
+        (true, false) => "simulation",
+        (false, true) => "hardware, safe mode",
+        (false, false) => "hardware",
+    }
+}
+
+/// Select the robot, then clean up and spawn the daemon (dependencies must already be installed)
+fn launch_daemon(
+    app_handle: &tauri::AppHandle,
+    state: &State<DaemonState>,
+    sim_mode: bool,
+    safe_mode: bool,
+    robot: Option<&str>,
+    working_dir: Option<&std::path::Path>,
+) -> Result<(), String> {
+    // 1. Resolve which robot to bind to (hardware mode only)
+    let selected_robot = if sim_mode {
+        None
+    } else {
+        let last_serial = settings::current(app_handle).last_robot_serial;
+        usb::select_robot(robot, last_serial.as_deref())?
+    };
+    
+    if let Some(ref robot) = selected_robot {
+        add_log(state, format!("🤖 Using robot on {}", robot.port_name));
+        if robot.serial_number.is_some() {
+            let serial = robot.serial_number.clone();
+            if let Err(e) = settings::update(app_handle, |s| s.last_robot_serial = serial) {
+                println!("[tauri] ⚠️  Failed to persist selected robot: {}", e);
+            }
+        }
+    }
     
-    // Use uv-trampoline to run: uv pip install mujoco reachy-mini[mujoco]
-    // Install mujoco first, then reachy-mini[mujoco] to ensure all dependencies are available
-    // This ensures we install in the same Python environment as the daemon
-    let (mut rx, _child) = app_handle
-        .shell()
-        .sidecar("uv-trampoline")
-        .map_err(|e| format!("Failed to find uv-trampoline: {}", e))?
-        .args(&["pip", "install", "mujoco", "reachy-mini[mujoco]"])
-        .spawn()
-        .map_err(|e| format!("Failed to spawn uv-trampoline: {}", e))?;
-    
-    // Monitor output in background using shared helper
-    crate::spawn_sidecar_monitor!(rx, app_handle, Some("mujoco-install".to_string()));
-    
-    // Wait a bit for installation to start (it runs async)
-    // Note: We can't easily wait for completion without blocking, so we rely on
-    // the frontend to detect when MuJoCo is available via health checks
-    std::thread::sleep(std::time::Duration::from_secs(3));
+    // 2. Clean up and spawn, retrying spawn failures; early exits are retried by the monitor
+    let retries = settings::current(app_handle).readiness.start_retries;
+    let serial_port = selected_robot.map(|r| r.port_name);
+    let mut attempt = 0;
+    loop {
+        // ⚡ Aggressive cleanup of all existing daemons (including zombies)
+        let cleanup_msg = if sim_mode {
+            "🧹 Cleaning up existing daemons (simulation mode)..."
+        } else {
+            "🧹 Cleaning up existing daemons..."
+        };
+        add_log(state, cleanup_msg.to_string());
+        kill_daemon(state, &settings::current(app_handle).shutdown);
+        
+        match spawn_and_monitor_sidecar(app_handle.clone(), state, sim_mode, safe_mode, serial_port.clone(), retries - attempt, working_dir.map(Into::into)) {
+            Ok(()) => break,
+            Err(e) if attempt < retries => {
+                attempt += 1;
+                add_log(state, format!("⚠️ Daemon launch failed ({}), retrying ({}/{})", e, attempt, retries));
+            }
+            Err(e) => return Err(e),
+        }
+    }
     
-    Ok("MuJoCo installation started".to_string())
+    // 3. Log success
+    let success_msg = if safe_mode {
+        "✓ Daemon started in safe mode via embedded sidecar"
+    } else if sim_mode {
+        "✓ Daemon started in simulation mode (MuJoCo) via embedded sidecar"
+    } else {
+        "✓ Daemon started via embedded sidecar"
+    };
+    add_log(state, success_msg.to_string());
+    
+    Ok(())
 }
 
-#[tauri::command]
-fn start_daemon(app_handle: tauri::AppHandle, state: State<DaemonState>, sim_mode: Option<bool>) -> Result<String, String> {
-    let sim_mode = sim_mode.unwrap_or(false);
+/// Start the daemon with the options of `config` (see DaemonLaunchConfig),
+/// validated as a whole before anything is stopped or spawned
+///
+/// The separate `sim_mode`, `robot`, `safe_mode` and `working_dir` options are
+/// deprecated, they are still accepted when `config` is absent.
+///
+/// Runs off the main thread: a simulation start waits for the MuJoCo install.
+#[tauri::command(async)]
+fn start_daemon(
+    app_handle: tauri::AppHandle,
+    state: State<DaemonState>,
+    config: Option<daemon::DaemonLaunchConfig>,
+    sim_mode: Option<bool>,
+    robot: Option<String>,
+    safe_mode: Option<bool>,
+    working_dir: Option<String>,
+) -> Result<String, String> {
+    let legacy = sim_mode.is_some() || robot.is_some() || safe_mode.is_some() || working_dir.is_some();
+    let config = match config {
+        Some(_) if legacy => return Err("Pass the launch options either in `config` or separately, not both".to_string()),
+        Some(config) => config,
+        None => {
+            if legacy {
+                println!("[tauri] ⚠️  start_daemon with separate options is deprecated, pass them in `config`");
+            }
+            daemon::DaemonLaunchConfig {
+                sim_mode: sim_mode.unwrap_or(false),
+                safe_mode: safe_mode.unwrap_or(false),
+                robot,
+                working_dir,
+            }
+        }
+    };
+    let working_dir = config.validate()?;
+    let (sim_mode, safe_mode) = (config.sim_mode, config.safe_mode);
+    
+    // Mark the beginning of a new daemon run in the logs
+    start_log_session(&state, mode_label(sim_mode, safe_mode));
+    
+    // 🛠️ Developer mode: use a daemon started by hand instead of spawning one
+    if settings::current(&app_handle).developer_mode && state.process.lock().unwrap().is_none() {
+        let endpoint = daemon::DaemonEndpoint::from_settings(&settings::current(&app_handle));
+        if daemon::is_port_in_use(&endpoint.host, endpoint.port) {
+            add_log(&state, format!("🛠️ Developer mode: attached to the daemon running on port {}", endpoint.port));
+            *state.endpoint.lock().unwrap() = endpoint;
+            *state.attached.lock().unwrap() = true;
+            return Ok("Attached to running daemon".to_string());
+        }
+    }
     
-    // 🎭 If simulation mode, ensure MuJoCo is installed first
-    // Installation happens asynchronously, we wait a bit for it to complete
-    if sim_mode {
+    // 🎭 If simulation mode, ensure MuJoCo is installed first (the safe mode mockup doesn't need it)
+    // and wait for the install to end (cancelled by the install monitor past its hard timeout)
+    if sim_mode && !safe_mode && !mujoco_installed() {
         add_log(&state, "🎭 Installing MuJoCo dependencies for simulation mode...".to_string());
-        match install_mujoco(app_handle.clone()) {
-            Ok(_) => {
+        let (tx, rx) = std::sync::mpsc::channel();
+        match spawn_mujoco_install(&app_handle, move |success| {
+            let _ = tx.send(success);
+        }) {
+            Ok(()) => {
                 add_log(&state, "✅ MuJoCo installation started, waiting...".to_string());
-                // Wait a bit longer for installation to complete (mujoco can take time)
-                std::thread::sleep(std::time::Duration::from_secs(5));
+                if rx.recv().unwrap_or(false) {
+                    add_log(&state, "✅ MuJoCo installed".to_string());
+                } else {
+                    // The daemon fails to start without it, which sidecar-terminated reports
+                    add_log(&state, "⚠️ MuJoCo installation failed, starting the daemon anyway".to_string());
+                }
             }
             Err(e) => {
                 // ✅ Improved error handling: Log detailed error but continue
@@ -76,33 +213,99 @@ fn start_daemon(app_handle: tauri::AppHandle, state: State<DaemonState>, sim_mod
         }
     }
     
-    // 1. ⚡ Aggressive cleanup of all existing daemons (including zombies)
-    let cleanup_msg = if sim_mode {
-        "🧹 Cleaning up existing daemons (simulation mode)..."
-    } else {
-        "🧹 Cleaning up existing daemons..."
+    launch_daemon(&app_handle, &state, sim_mode, safe_mode, config.robot.as_deref(), working_dir.as_deref())?;
+    
+    Ok("Daemon started successfully".to_string())
+}
+
+/// Outcome of dry_run_start
+#[derive(serde::Serialize)]
+struct DryRunStart {
+    success: bool,
+    /// Command start_daemon would run (sidecar and arguments)
+    command: Vec<String>,
+    env_keys: Vec<String>,
+    endpoint: daemon::DaemonEndpoint,
+    /// Something that isn't our daemon already listens on the daemon port
+    port_conflict: bool,
+    /// Command actually run: the daemon module with --help
+    probe: Vec<String>,
+    duration_ms: u64,
+    /// First problem found, None when start_daemon should work
+    error: Option<String>,
+}
+
+/// Go through the start_daemon pipeline (arguments, sidecar, environment,
+/// port check) and run the daemon module with --help instead of starting it
+/// A fast "will start_daemon work right now?" check without side effects
+#[tauri::command]
+async fn dry_run_start(app_handle: tauri::AppHandle, sim_mode: Option<bool>, safe_mode: Option<bool>) -> Result<DryRunStart, String> {
+    use tauri_plugin_shell::ShellExt;
+    
+    let started = std::time::Instant::now();
+    let settings = settings::current(&app_handle);
+    let endpoint = daemon::DaemonEndpoint::from_settings(&settings);
+    let sim_mode = sim_mode.unwrap_or(false);
+    let daemon_args = python::daemon_args(sim_mode, safe_mode.unwrap_or(false), None, &settings);
+    let env = daemon::daemon_env(&settings, sim_mode, None);
+    
+    let mut report = DryRunStart {
+        success: false,
+        command: std::iter::once(daemon::DAEMON_SIDECAR.to_string()).chain(daemon_args.iter().cloned()).collect(),
+        env_keys: env.iter().map(|(key, _)| key.to_string()).collect(),
+        endpoint: endpoint.clone(),
+        port_conflict: false,
+        // Interpreter, -m and the module: imports the daemon, then argparse exits
+        probe: daemon_args.iter().take(3).cloned().chain(std::iter::once("--help".to_string())).collect(),
+        duration_ms: 0,
+        error: None,
     };
-    add_log(&state, cleanup_msg.to_string());
-    kill_daemon(&state);
     
-    // 2. Spawn embedded daemon sidecar
-    spawn_and_monitor_sidecar(app_handle, &state, sim_mode)?;
+    let state: State<DaemonState> = app_handle.state();
+    let ours = state.process.lock().unwrap().is_some() || *state.attached.lock().unwrap();
+    let host = endpoint.host.clone();
+    report.port_conflict = !ours
+        && tauri::async_runtime::spawn_blocking(move || daemon::is_port_in_use(&host, endpoint.port))
+            .await
+            .map_err(|e| format!("Failed to execute port check task: {}", e))?;
     
-    // 3. Log success
-    let success_msg = if sim_mode {
-        "✓ Daemon started in simulation mode (MuJoCo) via embedded sidecar"
-    } else {
-        "✓ Daemon started via embedded sidecar"
+    let probe = match bundle::ensure_sidecar(daemon::DAEMON_SIDECAR) {
+        Err(e) => Err(e),
+        Ok(()) => app_handle
+            .shell()
+            .sidecar(daemon::DAEMON_SIDECAR)
+            .map_err(|e| format!("Failed to find {}: {}", daemon::DAEMON_SIDECAR, e))?
+            .args(&report.probe)
+            .envs(env)
+            .output()
+            .await
+            .map_err(|e| format!("Failed to spawn {}: {}", daemon::DAEMON_SIDECAR, e)),
     };
-    add_log(&state, success_msg.to_string());
     
-    Ok("Daemon started successfully".to_string())
+    report.error = match probe {
+        Err(e) => Some(e),
+        Ok(output) if !output.status.success() => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let last_line = stderr.lines().rev().map(str::trim).find(|line| !line.is_empty()).unwrap_or("");
+            Some(format!("Daemon module exited with {:?}: {}", output.status.code(), last_line))
+        }
+        Ok(_) if report.port_conflict => Some(format!("Port {} is already in use by another process", report.endpoint.port)),
+        Ok(_) => None,
+    };
+    report.success = report.error.is_none();
+    report.duration_ms = started.elapsed().as_millis() as u64;
+    
+    match &report.error {
+        None => println!("[tauri] ✅ Dry run: daemon start pipeline OK ({} ms)", report.duration_ms),
+        Some(e) => println!("[tauri] ❌ Dry run failed: {}", e),
+    }
+    Ok(report)
 }
 
 #[tauri::command]
-fn stop_daemon(state: State<DaemonState>) -> Result<String, String> {
+fn stop_daemon(app_handle: tauri::AppHandle, state: State<DaemonState>) -> Result<String, String> {
     // 1. Kill daemon (local process + system)
-    kill_daemon(&state);
+    kill_daemon(&state, &settings::current(&app_handle).shutdown);
     
     // 2. Log stop
     add_log(&state, "✓ Daemon stopped".to_string());
@@ -110,31 +313,525 @@ fn stop_daemon(state: State<DaemonState>) -> Result<String, String> {
     Ok("Daemon stopped successfully".to_string())
 }
 
+/// Abort a daemon launch that hasn't reported ready yet (dataset preload, sim init)
+/// Stopping also ends the readiness probe of that run
+#[tauri::command]
+fn cancel_startup(app_handle: tauri::AppHandle, state: State<DaemonState>) -> Result<String, String> {
+    use daemon::readiness::ReadinessStage;
+    
+    let is_running = state.process.lock().unwrap().is_some();
+    let readiness = *state.readiness.lock().unwrap();
+    
+    if !is_running {
+        return Err("No daemon startup in progress".to_string());
+    }
+    if readiness == Some(ReadinessStage::Ready) {
+        return Err("Daemon has already started, stop it instead".to_string());
+    }
+    
+    kill_daemon(&state, &settings::current(&app_handle).shutdown);
+    add_log(&state, "⏹️ Daemon startup cancelled".to_string());
+    
+    Ok("Startup cancelled".to_string())
+}
+
+/// Stop the daemon and wait until its port is released and the process is gone
+/// Avoids racing a dying daemon when start_daemon is called right after
+#[tauri::command]
+async fn stop_daemon_blocking(app_handle: tauri::AppHandle, timeout_ms: Option<u64>) -> Result<String, String> {
+    let timeout = std::time::Duration::from_millis(timeout_ms.unwrap_or(5000));
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let state: State<DaemonState> = app_handle.state();
+        
+        let pid = kill_daemon(&state, &settings::current(&app_handle).shutdown);
+        
+        let endpoint = state.endpoint.lock().unwrap().clone();
+        match wait_for_daemon_shutdown(&endpoint, pid, timeout) {
+            Ok(()) => {
+                add_log(&state, "✓ Daemon stopped (shutdown confirmed)".to_string());
+                Ok("Daemon stopped successfully".to_string())
+            }
+            Err(e) => {
+                add_log(&state, format!("❌ {}", e));
+                Err(e)
+            }
+        }
+    })
+    .await
+    .map_err(|e| format!("Failed to execute stop task: {}", e))?
+}
+
+#[derive(serde::Serialize)]
+struct DaemonConfig {
+    /// "daemon" when reported by the daemon API, "launch_args" when rebuilt from the command line
+    source: String,
+    config: serde_json::Value,
+}
+
+impl DaemonConfig {
+    fn from_launch(launch: &daemon::DaemonLaunch) -> Self {
+        DaemonConfig {
+            source: "launch_args".to_string(),
+            config: serde_json::json!({
+                "sim_mode": launch.sim_mode,
+                "safe_mode": launch.safe_mode,
+                "serial_port": launch.serial_port,
+                "host": launch.endpoint.host,
+                "port": launch.endpoint.port,
+                "log_level": launch.log_level.flag(),
+                "camera_device": launch.camera_device,
+                "microphone_device": launch.microphone_device,
+                "mujoco_gl": launch.mujoco_gl,
+                "flags": launch.flags_json(),
+            }),
+        }
+    }
+}
+
+/// How the daemon was last spawned, still available after it crashed
+#[tauri::command]
+fn get_last_launch(state: State<DaemonState>) -> Option<daemon::LastLaunch> {
+    state.last_launch.lock().unwrap().clone()
+}
+
+/// Environment the last daemon was spawned with (token values redacted),
+/// to find out why it used the wrong Python or packages
+#[tauri::command]
+fn get_daemon_environment(state: State<DaemonState>) -> Result<std::collections::BTreeMap<String, String>, String> {
+    state
+        .last_launch
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|launch| launch.env.clone())
+        .ok_or_else(|| "No daemon was launched yet".to_string())
+}
+
+/// How the last daemon process ended, None if none ended yet
+/// Lets the UI catch up on a crash it wasn't listening for
+#[tauri::command]
+fn get_last_termination(state: State<DaemonState>) -> Option<daemon::LastTermination> {
+    state.last_termination.lock().unwrap().clone()
+}
+
+#[derive(serde::Serialize)]
+struct DaemonStatus {
+    /// A daemon process was launched by the app and not stopped (it may have exited since)
+    running: bool,
+    attached: bool,
+    session_id: u64,
+    /// "exited_before_ready" when the process quit with status 0 during startup
+    readiness: Option<daemon::readiness::ReadinessStage>,
+    uptime_secs: Option<u64>,
+    last_termination: Option<daemon::LastTermination>,
+}
+
+/// State of the daemon run as the app tracks it, without calling the daemon API
+#[tauri::command]
+fn get_daemon_status(state: State<DaemonState>) -> DaemonStatus {
+    DaemonStatus {
+        running: state.process.lock().unwrap().is_some(),
+        attached: *state.attached.lock().unwrap(),
+        session_id: *state.session_id.lock().unwrap(),
+        readiness: *state.readiness.lock().unwrap(),
+        uptime_secs: state.started_at.lock().unwrap().map(|started| started.elapsed().as_secs()),
+        last_termination: state.last_termination.lock().unwrap().clone(),
+    }
+}
+
+/// Configuration the running daemon actually uses
+/// Asks the daemon API first, falls back to the arguments it was launched with
+#[tauri::command]
+async fn get_daemon_config(app_handle: tauri::AppHandle) -> Result<DaemonConfig, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let from_api = daemon::api_request(&app_handle, "GET", "/api/daemon/config", None, std::time::Duration::from_secs(3))
+            .ok()
+            .filter(|response| response.is_success())
+            .and_then(|response| serde_json::from_str::<serde_json::Value>(&response.body).ok());
+        
+        let state: State<DaemonState> = app_handle.state();
+        let launch = state.launch.lock().unwrap().clone();
+        
+        if let Some(mut config) = from_api {
+            // Older daemons don't report their log level nor capture devices
+            if let (Some(fields), Some(launch)) = (config.as_object_mut(), launch.as_ref()) {
+                fields.entry("log_level").or_insert_with(|| launch.log_level.flag().into());
+                fields.entry("camera_device").or_insert_with(|| launch.camera_device.clone().into());
+                fields.entry("microphone_device").or_insert_with(|| launch.microphone_device.clone().into());
+                fields.entry("mujoco_gl").or_insert_with(|| launch.mujoco_gl.clone().into());
+            }
+            return Ok(DaemonConfig { source: "daemon".to_string(), config });
+        }
+        
+        let launch = launch.ok_or("Daemon is not running")?;
+        
+        Ok(DaemonConfig::from_launch(&launch))
+    })
+    .await
+    .map_err(|e| format!("Failed to execute config task: {}", e))?
+}
+
+/// Range of daemon API versions the proxied features are written against
+/// (max is exclusive). Independent of the reachy-mini package version.
+const SUPPORTED_DAEMON_API: (&str, &str) = ("1.0.0", "2.0.0");
+
+#[derive(serde::Serialize)]
+struct DaemonApiVersion {
+    /// None when the daemon doesn't report an API version (compatibility unknown)
+    api_version: Option<String>,
+    /// Route the version was read from
+    source: Option<String>,
+    /// SUPPORTED_DAEMON_API
+    min_version: String,
+    max_version: String,
+    compatible: bool,
+}
+
+/// API version reported by the daemon: in its status, or as the version of its OpenAPI schema
+fn daemon_reported_api_version(app_handle: &tauri::AppHandle) -> Result<Option<(String, &'static str)>, String> {
+    let timeout = std::time::Duration::from_secs(3);
+    let read = |path: &str, pointer: &str| -> Result<Option<String>, String> {
+        let response = daemon::api_request(app_handle, "GET", path, None, timeout)?;
+        Ok(serde_json::from_str::<serde_json::Value>(&response.body)
+            .ok()
+            .filter(|_| response.is_success())
+            .and_then(|body| body.pointer(pointer).and_then(|version| version.as_str()).map(str::to_string)))
+    };
+    
+    // "version" in the status is the package version, not the API's
+    if let Some(version) = read("/api/daemon/status", "/api_version")? {
+        return Ok(Some((version, "/api/daemon/status")));
+    }
+    Ok(read("/openapi.json", "/info/version")?.map(|version| (version, "/openapi.json")))
+}
+
+/// Compare the API version of the running daemon with the range this app
+/// was built against, so the UI can disable proxied features that would break
+#[tauri::command]
+async fn get_daemon_api_version(app_handle: tauri::AppHandle) -> Result<DaemonApiVersion, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let (min_version, max_version) = SUPPORTED_DAEMON_API;
+        let reported = daemon_reported_api_version(&app_handle)?;
+        let compatible = reported.as_ref().is_some_and(|(version, _)| {
+            python::compare_versions(version, min_version).is_ge() && python::compare_versions(version, max_version).is_lt()
+        });
+        
+        match reported {
+            Some((ref version, _)) if !compatible => println!(
+                "[tauri] ⚠️  Daemon API {} is outside the supported range ({} to {})",
+                version, min_version, max_version
+            ),
+            None => println!("[tauri] ⚠️  Daemon doesn't report its API version"),
+            _ => {}
+        }
+        
+        let (api_version, source) = reported.map(|(version, source)| (version, source.to_string())).unzip();
+        Ok(DaemonApiVersion {
+            api_version,
+            source,
+            min_version: min_version.to_string(),
+            max_version: max_version.to_string(),
+            compatible,
+        })
+    })
+    .await
+    .map_err(|e| format!("Failed to execute API version task: {}", e))?
+}
+
+/// Switch a running daemon between hardware and simulation (`new_mode`: "hardware" or "simulation")
+/// Only does the work the new mode needs: MuJoCo is installed first when missing
+/// (progress through the usual install events), then the daemon is stopped, its
+/// shutdown confirmed and it is spawned again with the new mode. Safe mode carries over.
+#[tauri::command]
+async fn switch_mode(app_handle: tauri::AppHandle, new_mode: String) -> Result<DaemonConfig, String> {
+    let sim_mode = match new_mode.as_str() {
+        "simulation" | "sim" => true,
+        "hardware" => false,
+        other => return Err(format!("Unknown mode '{}', expected \"hardware\" or \"simulation\"", other)),
+    };
+    
+    let current = {
+        let state: State<DaemonState> = app_handle.state();
+        if *state.attached.lock().unwrap() {
+            return Err("Attached to an external daemon, its mode can't be switched from the app".to_string());
+        }
+        let launch = state.launch.lock().unwrap().clone();
+        launch
+    };
+    
+    if let Some(ref launch) = current {
+        if launch.sim_mode == sim_mode {
+            return Ok(DaemonConfig::from_launch(launch));
+        }
+    }
+    let safe_mode = current.as_ref().map(|launch| launch.safe_mode).unwrap_or(false);
+    let working_dir = current.and_then(|launch| launch.working_dir);
+    
+    // 🎭 Install MuJoCo before stopping anything, a failed install leaves the current daemon running
+    if sim_mode && !safe_mode && !mujoco_installed() {
+        add_log(&app_handle.state(), "🎭 Installing MuJoCo dependencies before switching to simulation...".to_string());
+        
+        let (tx, rx) = std::sync::mpsc::channel();
+        spawn_mujoco_install(&app_handle, move |success| {
+            let _ = tx.send(success);
+        })?;
+        let installed = tauri::async_runtime::spawn_blocking(move || rx.recv().unwrap_or(false))
+            .await
+            .map_err(|e| format!("Failed to wait for MuJoCo installation: {}", e))?;
+        if !installed {
+            return Err("MuJoCo installation failed, the daemon mode was not changed".to_string());
+        }
+    }
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let state: State<DaemonState> = app_handle.state();
+        start_log_session(&state, mode_label(sim_mode, safe_mode));
+        
+        let pid = kill_daemon(&state, &settings::current(&app_handle).shutdown);
+        let endpoint = state.endpoint.lock().unwrap().clone();
+        wait_for_daemon_shutdown(&endpoint, pid, std::time::Duration::from_secs(5))?;
+        
+        launch_daemon(&app_handle, &state, sim_mode, safe_mode, None, working_dir.as_deref())?;
+        
+        let launch = state.launch.lock().unwrap().clone().ok_or("Daemon did not start")?;
+        Ok(DaemonConfig::from_launch(&launch))
+    })
+    .await
+    .map_err(|e| format!("Failed to execute switch task: {}", e))?
+}
+
+#[derive(serde::Serialize)]
+struct LogLevelChange {
+    level: settings::DaemonLogLevel,
+    /// True when the running daemon switched right away, false when the
+    /// level only applies on the next launch
+    live: bool,
+}
+
+/// Change the daemon log level without a restart when the daemon supports it,
+/// the preference is saved for the next launch either way
+#[tauri::command]
+async fn set_daemon_log_level(app_handle: tauri::AppHandle, level: settings::DaemonLogLevel) -> Result<LogLevelChange, String> {
+    settings::update(&app_handle, |settings| settings.daemon_log_level = level)?;
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let body = serde_json::json!({ "level": level.flag() }).to_string();
+        let live = daemon::api_request(&app_handle, "POST", "/api/daemon/log-level", Some(&body), std::time::Duration::from_secs(3))
+            .map(|response| response.is_success())
+            .unwrap_or(false);
+        
+        if live {
+            let state: State<DaemonState> = app_handle.state();
+            if let Some(launch) = state.launch.lock().unwrap().as_mut() {
+                launch.log_level = level;
+            }
+            println!("[tauri] 🔊 Daemon log level switched to {}", level.flag());
+        } else {
+            println!("[tauri] 🔊 Daemon log level set to {} (applies on next launch)", level.flag());
+        }
+        
+        Ok(LogLevelChange { level, live })
+    })
+    .await
+    .map_err(|e| format!("Failed to execute log level task: {}", e))?
+}
+
+/// Daemon route reading and changing the MuJoCo simulation speed
+const SIM_TIME_SCALE_PATH: &str = "/api/simulation/time-scale";
+const MIN_SIM_TIME_SCALE: f64 = 0.1;
+const MAX_SIM_TIME_SCALE: f64 = 10.0;
+
+/// Fail unless the running daemon is a MuJoCo simulation
+fn ensure_mujoco_sim(state: &State<DaemonState>) -> Result<(), String> {
+    match state.launch.lock().unwrap().as_ref() {
+        Some(launch) if launch.sim_mode && !launch.safe_mode => Ok(()),
+        Some(_) => Err("The time scale only applies to the MuJoCo simulation".to_string()),
+        None => Err("Daemon is not running".to_string()),
+    }
+}
+
+/// Call the time-scale route, mapping a missing route to a clear error
+fn sim_time_scale_request(app_handle: &tauri::AppHandle, method: &str, body: Option<&str>) -> Result<f64, String> {
+    let response = daemon::api_request(app_handle, method, SIM_TIME_SCALE_PATH, body, std::time::Duration::from_secs(3))?;
+    
+    if response.status == 404 {
+        return Err("This daemon version doesn't support changing the simulation time scale".to_string());
+    }
+    if !response.is_success() {
+        return Err(format!("Time scale request failed: HTTP {} {}", response.status, response.body.trim()));
+    }
+    
+    serde_json::from_str::<serde_json::Value>(&response.body)
+        .ok()
+        .and_then(|body| body.get("time_scale").and_then(|scale| scale.as_f64()))
+        .ok_or_else(|| format!("Unexpected time scale response: {}", response.body.trim()))
+}
+
+/// Current simulation speed (1.0 = real time)
+#[tauri::command]
+async fn get_sim_time_scale(app_handle: tauri::AppHandle) -> Result<f64, String> {
+    ensure_mujoco_sim(&app_handle.state())?;
+    
+    tauri::async_runtime::spawn_blocking(move || sim_time_scale_request(&app_handle, "GET", None))
+        .await
+        .map_err(|e| format!("Failed to execute time scale task: {}", e))?
+}
+
+/// Run the simulation faster or slower than real time (0.1x to 10x)
+/// Returns the scale the daemon applied
+#[tauri::command]
+async fn set_sim_time_scale(app_handle: tauri::AppHandle, scale: f64) -> Result<f64, String> {
+    if !(MIN_SIM_TIME_SCALE..=MAX_SIM_TIME_SCALE).contains(&scale) {
+        return Err(format!("Time scale must be between {}x and {}x, got {}x", MIN_SIM_TIME_SCALE, MAX_SIM_TIME_SCALE, scale));
+    }
+    ensure_mujoco_sim(&app_handle.state())?;
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let body = serde_json::json!({ "time_scale": scale }).to_string();
+        let applied = sim_time_scale_request(&app_handle, "POST", Some(&body))?;
+        println!("[tauri] ⏱️  Simulation time scale set to {}x", applied);
+        Ok(applied)
+    })
+    .await
+    .map_err(|e| format!("Failed to execute time scale task: {}", e))?
+}
+
+/// Daemon route reinitializing the audio/video pipeline
+const MEDIA_RESTART_PATH: &str = "/api/media/restart";
+
+/// Reinitialize only the daemon's audio/video subsystem (device busy, stalled
+/// pipeline) while the robot connection stays up
+#[tauri::command]
+async fn restart_media_pipeline(app_handle: tauri::AppHandle) -> Result<(), String> {
+    {
+        let state: State<DaemonState> = app_handle.state();
+        let safe_mode = state.launch.lock().unwrap().as_ref().map(|launch| launch.safe_mode);
+        match safe_mode {
+            Some(true) => return Err("Media is disabled in safe mode".to_string()),
+            None if !*state.attached.lock().unwrap() => return Err("Daemon is not running".to_string()),
+            _ => {}
+        }
+    }
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        // Reopening the capture devices can take several seconds
+        let response = daemon::api_request(&app_handle, "POST", MEDIA_RESTART_PATH, None, std::time::Duration::from_secs(15))?;
+        if response.status == 404 || response.status == 405 {
+            return Err("This daemon version doesn't support restarting the media pipeline, restart the daemon instead".to_string());
+        }
+        if !response.is_success() {
+            return Err(format!("Media restart failed: HTTP {} {}", response.status, response.body.trim()));
+        }
+        
+        let state: State<DaemonState> = app_handle.state();
+        add_log(&state, "🎥 Media pipeline restarted".to_string());
+        println!("[tauri] 🎥 Media pipeline restarted");
+        Ok(())
+    })
+    .await
+    .map_err(|e| format!("Failed to execute media restart task: {}", e))?
+}
+
+/// Open the daemon's own web interface in the default browser
+/// Uses the port the daemon actually runs on, once it answers its status route
+/// Returns the opened URL
+#[tauri::command]
+async fn open_daemon_web_ui(app_handle: tauri::AppHandle) -> Result<String, String> {
+    use tauri_plugin_opener::OpenerExt;
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let response = daemon::api_request(&app_handle, "GET", "/api/daemon/status", None, std::time::Duration::from_secs(3))
+            .map_err(|e| format!("Daemon is not reachable: {}", e))?;
+        if !response.is_success() {
+            return Err(format!("Daemon is not ready: HTTP {}", response.status));
+        }
+        
+        let endpoint = app_handle.state::<DaemonState>().endpoint.lock().unwrap().clone();
+        let host = if endpoint.host == "127.0.0.1" { "localhost" } else { endpoint.host.as_str() };
+        let url = format!("http://{}:{}/", host, endpoint.port);
+        
+        app_handle
+            .opener()
+            .open_url(url.as_str(), None::<&str>)
+            .map_err(|e| format!("Failed to open {}: {}", url, e))?;
+        println!("[tauri] 🌐 Opened daemon web UI at {}", url);
+        Ok(url)
+    })
+    .await
+    .map_err(|e| format!("Failed to execute web UI task: {}", e))?
+}
+
 #[tauri::command]
 fn get_logs(state: State<DaemonState>) -> Vec<String> {
+    let logs = state.logs.lock().unwrap();
+    logs.iter().map(|entry| entry.formatted()).collect()
+}
+
+/// Same as get_logs but with the session id of each entry,
+/// so the UI can group or collapse previous daemon runs
+#[tauri::command]
+fn get_session_logs(state: State<DaemonState>) -> Vec<LogEntry> {
     let logs = state.logs.lock().unwrap();
     logs.iter().cloned().collect()
 }
 
+#[derive(Clone, serde::Serialize)]
+struct LogReplay {
+    logs: Vec<String>,
+    sidecar: Vec<SidecarLine>,
+}
+
+/// Flush the current log buffers to the frontend as a single "logs-replay" event
+/// Called by a (re)loaded frontend or a late-opened log window before relying on
+/// the live sidecar-stdout/sidecar-stderr stream, so it doesn't start blank
+#[tauri::command]
+fn subscribe_logs(app_handle: tauri::AppHandle, state: State<DaemonState>) -> Result<(), String> {
+    use tauri::Emitter;
+    
+    let replay = LogReplay {
+        logs: state.logs.lock().unwrap().iter().map(|entry| entry.formatted()).collect(),
+        sidecar: state.sidecar_output.lock().unwrap().iter().cloned().collect(),
+    };
+    
+    app_handle
+        .emit("logs-replay", replay)
+        .map_err(|e| format!("Failed to emit logs replay: {}", e))
+}
+
+/// Stop the per-line sidecar events (lines are still read and buffered),
+/// e.g. while a busy install would flood a slow webview
+#[tauri::command]
+fn pause_log_stream(state: State<DaemonState>) {
+    let mut deferred = state.deferred_lines.lock().unwrap();
+    if deferred.is_none() {
+        *deferred = Some(daemon::DeferredLines::default());
+    }
+}
+
+/// Resume the per-line events, after a single "sidecar-batch" with the lines
+/// received while paused
+#[tauri::command]
+fn resume_log_stream(app_handle: tauri::AppHandle, state: State<DaemonState>) -> Result<(), String> {
+    use tauri::Emitter;
+    
+    // Taking the queue resumes streaming, new lines no longer get deferred
+    let batch = match state.deferred_lines.lock().unwrap().take() {
+        Some(batch) => batch,
+        None => return Ok(()),
+    };
+    
+    app_handle
+        .emit("sidecar-batch", batch)
+        .map_err(|e| format!("Failed to emit log batch: {}", e))
+}
+
 // ============================================================================
 // ENTRY POINT
 // ============================================================================
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    // Setup signal handler for brutal kill (SIGTERM, SIGINT, etc.) - Unix only
-    #[cfg(not(windows))]
-    {
-        std::thread::spawn(|| {
-            let mut signals = Signals::new(TERM_SIGNALS).expect("Failed to register signal handlers");
-            for sig in signals.forever() {
-                eprintln!("🔴 Signal {:?} received - cleaning up daemon", sig);
-                cleanup_system_daemons();
-                std::process::exit(0);
-            }
-        });
-    }
-
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
@@ -145,24 +842,87 @@ pub fn run() {
         .manage(DaemonState {
             process: std::sync::Mutex::new(None),
             logs: std::sync::Mutex::new(std::collections::VecDeque::new()),
+            session_id: std::sync::Mutex::new(0),
+            sidecar_output: std::sync::Mutex::new(std::collections::VecDeque::new()),
+            started_at: std::sync::Mutex::new(None),
+            endpoint: std::sync::Mutex::new(daemon::DaemonEndpoint::default()),
+            readiness: std::sync::Mutex::new(None),
+            attached: std::sync::Mutex::new(false),
+            launch: std::sync::Mutex::new(None),
+            log_file: std::sync::Mutex::new(None),
+            deferred_lines: std::sync::Mutex::new(None),
+            awake: std::sync::Mutex::new(false),
+            sim_viewer: std::sync::Mutex::new(None),
+            log_capture: std::sync::Mutex::new(None),
+            last_launch: std::sync::Mutex::new(None),
+            last_termination: std::sync::Mutex::new(None),
+            error_rate: std::sync::Mutex::new(daemon::alerts::ErrorRate::default()),
+            api_recording: std::sync::Mutex::new(None),
+            restart: std::sync::Mutex::new(daemon::supervisor::RestartState::default()),
         })
+        .manage(signing::SigningState::default())
+        .manage(process::MetricsStreamState::default())
         .setup(|app| {
+            app.manage(settings::SettingsState::load(app.handle()));
+            app.manage(bundle::check_sidecars());
+            
+            // A venv moved by relocate_venv, handed to the sidecars as REACHY_MINI_VENV
+            // (a dataset location chosen in the settings wins over its cache)
+            python::apply_venv_location(settings::current(app.handle()).venv_location.as_deref());
+            bundle::check_bundle_integrity();
+            // A lost execute bit or quarantine makes every spawn fail
+            bundle::check_sidecar_permissions(true);
+            python::apply_layout_manifest_location(app.handle());
+            
+            // First launch of a new version means the updater installed it
+            if let Err(e) = updates::record_current_version(app.handle()) {
+                eprintln!("[tauri] ⚠️  {}", e);
+            }
+            
+            // Until a daemon is launched, cleanup targets the configured port
+            // (e.g. a daemon left over from a previous run)
+            {
+                let state: State<DaemonState> = app.state();
+                *state.endpoint.lock().unwrap() = daemon::DaemonEndpoint::from_settings(&settings::current(app.handle()));
+            }
+            
+            // Logs of a previous session are removed unless the user keeps them
+            let log_retention = settings::current(app.handle()).log_retention;
+            if log_retention != settings::LogRetention::Persistent {
+                if let Err(e) = daemon::logfile::purge(app.handle(), false) {
+                    eprintln!("[tauri] ⚠️  {}", e);
+                }
+            }
+            daemon::logfile::apply_retention(app.handle(), log_retention);
+            
+            // Setup signal handler for brutal kill (SIGTERM, SIGINT, etc.) - Unix only
+            // Registered here so cleanup honors the user's shutdown settings
+            #[cfg(not(windows))]
+            {
+                let app_handle = app.handle().clone();
+                std::thread::spawn(move || {
+                    let mut signals = Signals::new(TERM_SIGNALS).expect("Failed to register signal handlers");
+                    for sig in signals.forever() {
+                        eprintln!("🔴 Signal {:?} received - cleaning up daemon", sig);
+                        let state: State<DaemonState> = app_handle.state();
+                        if !*state.attached.lock().unwrap() {
+                            let endpoint = state.endpoint.lock().unwrap().clone();
+                            let pid = process::daemon_pid(&state);
+                            cleanup_system_daemons(&settings::current(&app_handle).shutdown, &endpoint, pid);
+                        }
+                        std::process::exit(0);
+                    }
+                });
+            }
+            
             #[cfg(target_os = "macos")]
             {
                 let window = app.get_webview_window("main").unwrap();
-                use cocoa::base::{id, YES};
-                use objc::{msg_send, sel, sel_impl};
                 
-                unsafe {
-                    let ns_window = window.ns_window().unwrap() as id;
-                    
-                    // Transparent titlebar and fullscreen content
-                    let _: () = msg_send![ns_window, setTitlebarAppearsTransparent: YES];
-                    
-                    // Full size content view so content goes under titlebar
-                    let style_mask: u64 = msg_send![ns_window, styleMask];
-                    let new_style = style_mask | (1 << 15); // NSWindowStyleMaskFullSizeContentView
-                    let _: () = msg_send![ns_window, setStyleMask: new_style];
+                // Transparent titlebar and fullscreen content, unless the user prefers the standard one
+                let transparent = !settings::current(app.handle()).standard_titlebar;
+                if let Err(e) = window::set_titlebar_style(&window, transparent) {
+                    eprintln!("[tauri] ⚠️  {}", e);
                 }
                 
                 // Request all macOS permissions (camera, microphone, etc.)
@@ -174,24 +934,165 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             start_daemon,
+            dry_run_start,
             stop_daemon,
+            stop_daemon_blocking,
+            switch_mode,
+            daemon::wakeup::wake_up_robot,
+            daemon::pose::save_robot_pose,
+            daemon::pose::restore_robot_pose,
+            daemon::pose::list_robot_poses,
+            daemon::viewer::get_sim_viewer_status,
+            cancel_startup,
+            get_daemon_config,
+            get_last_launch,
+            get_last_termination,
+            get_daemon_status,
+            get_daemon_environment,
+            get_daemon_api_version,
+            set_daemon_log_level,
+            get_sim_time_scale,
+            set_sim_time_scale,
+            open_daemon_web_ui,
+            restart_media_pipeline,
+            daemon::logfile::read_log_file,
+            daemon::logfile::start_log_capture,
+            daemon::logfile::stop_log_capture,
+            daemon::recording::daemon_request,
+            daemon::recording::record_daemon_call,
+            daemon::recording::start_api_recording,
+            daemon::recording::stop_api_recording,
+            daemon::recording::replay_api_recording,
+            daemon::supervisor::get_restart_state,
+            daemon::supervisor::cancel_pending_restart,
+            daemon::logfile::purge_log_files,
             get_logs,
+            get_session_logs,
+            subscribe_logs,
+            pause_log_stream,
+            resume_log_stream,
             usb::check_usb_robot,
+            usb::list_robots,
+            usb::dump_usb_tree,
             install_mujoco,
             window::apply_transparent_titlebar,
             window::close_window,
+            window::set_transparent_titlebar,
             signing::sign_python_binaries,
+            signing::resign_failed,
+            signing::verify_app_signature,
+            signing::get_interpreter_entitlements,
+            signing::is_quarantined,
+            signing::clear_quarantine,
             permissions::open_camera_settings,
-            permissions::open_microphone_settings
+            permissions::open_microphone_settings,
+            system::get_runtime_arch,
+            system::get_install_disk_status,
+            system::clear_dataset_cache,
+            system::get_dataset_location,
+            system::set_dataset_location,
+            system::get_system_memory,
+            system::check_venv_writable,
+            system::check_graphics_capability,
+            system::check_sandbox,
+            system::get_fd_limits,
+            system::raise_fd_limits,
+            system::check_network_endpoints,
+            settings::get_settings,
+            settings::set_signing_config,
+            settings::get_uv_concurrency,
+            settings::set_uv_concurrency,
+            settings::set_keep_daemon_on_close,
+            settings::set_log_retention,
+            settings::set_rest_pose_on_exit,
+            settings::set_mujoco_rendering,
+            settings::set_error_alerts_enabled,
+            settings::set_error_alert_threshold,
+            settings::set_auto_restart,
+            settings::set_settings,
+            settings::export_settings,
+            settings::import_settings,
+            settings::set_verbose_installs,
+            settings::set_daemon_host,
+            settings::set_daemon_port,
+            settings::set_developer_mode,
+            settings::set_capture_devices,
+            process::get_daemon_resources,
+            process::start_metrics_stream,
+            process::stop_metrics_stream,
+            process::detect_external_daemons,
+            process::set_daemon_priority,
+            process::kill_process,
+            process::dump_daemon_stack,
+            python::check_version_compatibility,
+            python::repatch_venv,
+            python::relocate_venv,
+            python::test_mujoco,
+            python::check_interpreter_isolation,
+            python::preview_daemon_command,
+            python::repair_venv_permissions,
+            python::repair_mjpython,
+            python::get_trampoline_layout,
+            media::capture_preview_frame,
+            media::list_capture_devices,
+            apps::list_store_apps,
+            apps::install_app,
+            apps::uninstall_app,
+            secrets::set_hf_token,
+            secrets::clear_hf_token,
+            secrets::has_hf_token,
+            preflight::run_preflight,
+            install::set_kinematics_engine,
+            install::rebuild_venv_for_arch,
+            install::list_python_interpreters,
+            install::get_setup_timings,
+            install::verify_install,
+            install::check_uv_binary,
+            install::get_cached_uv,
+            install::rebuild_venv_with_interpreter,
+            install::install_reachy_mini_version,
+            diagnostics::get_install_id,
+            diagnostics::collect_diagnostics,
+            updates::get_update_history,
+            updates::get_update_check_interval,
+            updates::set_update_check_interval,
+            updates::should_check_for_updates,
+            updates::record_update_check,
+            reset::factory_reset,
+            bundle::get_sidecar_check,
+            bundle::verify_bundle_integrity,
+            bundle::repair_sidecar_permissions
         ])
         .on_window_event(|window, event| {
             match event {
-                tauri::WindowEvent::CloseRequested { .. } => {
+                tauri::WindowEvent::CloseRequested { api, .. } => {
                     // Only kill daemon if main window is closing
                     if window.label() == "main" {
+                        let settings = settings::current(window.app_handle());
+                        // Only macOS can reopen the hidden window (dock, RunEvent::Reopen)
+                        if cfg!(target_os = "macos") && settings.keep_daemon_on_close {
+                            println!("🔴 Main window close requested - hiding, daemon keeps running");
+                            api.prevent_close();
+                            let _ = window.hide();
+                            return;
+                        }
+                        if settings.rest_pose_on_exit.is_some() {
+                            // Parking makes HTTP calls and waits for the move: keep the
+                            // window up meanwhile and close it once the daemon is stopped
+                            println!("🔴 Main window close requested - parking the robot, then killing daemon");
+                            api.prevent_close();
+                            let window = window.clone();
+                            tauri::async_runtime::spawn_blocking(move || {
+                                daemon::pose::park_before_exit(window.app_handle());
+                                let state: tauri::State<DaemonState> = window.state();
+                                kill_daemon(&state, &settings.shutdown);
+                                let _ = window.destroy();
+                            });
+                            return;
+                        }
                         println!("🔴 Main window close requested - killing daemon");
                     let state: tauri::State<DaemonState> = window.state();
-                    kill_daemon(&state);
+                    kill_daemon(&state, &settings.shutdown);
                     } else {
                         println!("🔴 Secondary window close requested: {}", window.label());
                     }
@@ -200,7 +1101,17 @@ pub fn run() {
                     // Only cleanup if main window is destroyed
                     if window.label() == "main" {
                         println!("🔴 Main window destroyed - final cleanup");
-                    cleanup_system_daemons();
+                    let state: tauri::State<DaemonState> = window.state();
+                    let settings = settings::current(window.app_handle());
+                    if !*state.attached.lock().unwrap() {
+                        let endpoint = state.endpoint.lock().unwrap().clone();
+                        let pid = process::daemon_pid(&state);
+                        cleanup_system_daemons(&settings.shutdown, &endpoint, pid);
+                    }
+                    if settings.log_retention == settings::LogRetention::SessionOnly {
+                        *state.log_file.lock().unwrap() = None;
+                        let _ = daemon::logfile::purge(window.app_handle(), false);
+                    }
                     } else {
                         println!("🔴 Secondary window destroyed: {}", window.label());
                     }
@@ -208,6 +1119,16 @@ pub fn run() {
                 _ => {}
             }
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|_app_handle, _event| {
+            // Bring back a main window hidden by close (keep_daemon_on_close) from the dock
+            #[cfg(target_os = "macos")]
+            if let tauri::RunEvent::Reopen { .. } = _event {
+                if let Some(window) = _app_handle.get_webview_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+        });
 }
@@ -0,0 +1,191 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use cocoa::base::{id, nil, BOOL, NO, YES};
+use cocoa::foundation::{NSAutoreleasePool, NSString};
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use super::{CaptureDevice, CaptureDeviceKind};
+
+#[link(name = "AVFoundation", kind = "framework")]
+extern "C" {}
+
+#[link(name = "CoreImage", kind = "framework")]
+extern "C" {}
+
+#[link(name = "CoreMedia", kind = "framework")]
+extern "C" {
+    fn CMSampleBufferGetImageBuffer(sample_buffer: id) -> id;
+}
+
+extern "C" {
+    fn dispatch_queue_create(label: *const c_char, attr: id) -> id;
+    fn dispatch_release(object: id);
+}
+
+/// AVMediaTypeVideo / AVMediaTypeAudio
+const MEDIA_TYPE_VIDEO: &str = "vide";
+const MEDIA_TYPE_AUDIO: &str = "soun";
+/// NSBitmapImageFileTypeJPEG
+const JPEG_FILE_TYPE: usize = 3;
+/// The first frames are dark while the camera adjusts its exposure
+const WARMUP_FRAMES: usize = 10;
+const FRAME_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Where the delegate sends the frame of the running capture
+static FRAME_SENDER: Mutex<Option<Sender<Result<String, String>>>> = Mutex::new(None);
+static FRAMES_SEEN: AtomicUsize = AtomicUsize::new(0);
+/// One capture at a time, they share FRAME_SENDER
+static CAPTURE: Mutex<()> = Mutex::new(());
+
+unsafe fn to_string(string: id) -> String {
+    if string == nil {
+        return String::new();
+    }
+    let bytes: *const c_char = msg_send![string, UTF8String];
+    CStr::from_ptr(bytes).to_string_lossy().into_owned()
+}
+
+/// Capture devices of an AVMediaType, in AVFoundation order (the order camera indexes refer to)
+unsafe fn devices_with_media_type(media_type: &str) -> Vec<id> {
+    let media_type: id = NSString::alloc(nil).init_str(media_type);
+    let devices: id = msg_send![class!(AVCaptureDevice), devicesWithMediaType: media_type];
+    let _: () = msg_send![media_type, release];
+
+    let count: usize = msg_send![devices, count];
+    (0..count).map(|i| -> id { msg_send![devices, objectAtIndex: i] }).collect()
+}
+
+/// Cameras and microphones, identified by name like on Windows
+pub fn list_devices() -> Vec<CaptureDevice> {
+    unsafe {
+        let pool = NSAutoreleasePool::new(nil);
+        let devices = [(CaptureDeviceKind::Camera, MEDIA_TYPE_VIDEO), (CaptureDeviceKind::Microphone, MEDIA_TYPE_AUDIO)]
+            .into_iter()
+            .flat_map(|(kind, media_type)| {
+                devices_with_media_type(media_type).into_iter().map(move |device| {
+                    let name = to_string(msg_send![device, localizedName]);
+                    CaptureDevice { kind, id: name.clone(), name }
+                })
+            })
+            .collect();
+        pool.drain();
+        devices
+    }
+}
+
+/// Base64 JPEG of a video sample buffer
+unsafe fn encode_jpeg(sample_buffer: id) -> Result<String, String> {
+    let image_buffer = CMSampleBufferGetImageBuffer(sample_buffer);
+    if image_buffer == nil {
+        return Err("the frame holds no image".to_string());
+    }
+
+    let image: id = msg_send![class!(CIImage), imageWithCVImageBuffer: image_buffer];
+    let rep: id = msg_send![class!(NSBitmapImageRep), alloc];
+    let rep: id = msg_send![rep, initWithCIImage: image];
+    let properties: id = msg_send![class!(NSDictionary), dictionary];
+    let data: id = msg_send![rep, representationUsingType: JPEG_FILE_TYPE properties: properties];
+    let encoded = if data == nil {
+        Err("JPEG encoding failed".to_string())
+    } else {
+        let base64: id = msg_send![data, base64EncodedStringWithOptions: 0usize];
+        Ok(to_string(base64))
+    };
+    let _: () = msg_send![rep, release];
+    encoded
+}
+
+/// captureOutput:didOutputSampleBuffer:fromConnection:, runs on the capture queue
+extern "C" fn did_output_sample_buffer(_this: &Object, _sel: Sel, _output: id, sample_buffer: id, _connection: id) {
+    if FRAMES_SEEN.fetch_add(1, Ordering::SeqCst) < WARMUP_FRAMES {
+        return;
+    }
+    let Some(sender) = FRAME_SENDER.lock().unwrap().take() else {
+        return;
+    };
+    unsafe {
+        let pool = NSAutoreleasePool::new(nil);
+        let _ = sender.send(encode_jpeg(sample_buffer));
+        pool.drain();
+    }
+}
+
+fn delegate_class() -> &'static Class {
+    static DELEGATE: OnceLock<&'static Class> = OnceLock::new();
+    DELEGATE.get_or_init(|| {
+        let mut decl = ClassDecl::new("ReachyMiniPreviewFrameDelegate", class!(NSObject))
+            .expect("preview frame delegate class already registered");
+        unsafe {
+            decl.add_method(
+                sel!(captureOutput:didOutputSampleBuffer:fromConnection:),
+                did_output_sample_buffer as extern "C" fn(&Object, Sel, id, id, id),
+            );
+        }
+        decl.register()
+    })
+}
+
+/// Run a capture session on the camera until the delegate delivers a frame
+unsafe fn capture_with_session(index: u32, frame: &mpsc::Receiver<Result<String, String>>) -> Result<String, String> {
+    let cameras = devices_with_media_type(MEDIA_TYPE_VIDEO);
+    let device = *cameras
+        .get(index as usize)
+        .ok_or_else(|| format!("camera {} not found ({} connected)", index, cameras.len()))?;
+
+    let mut error: id = nil;
+    let input: id = msg_send![class!(AVCaptureDeviceInput), deviceInputWithDevice: device error: &mut error];
+    if input == nil {
+        let reason = if error == nil { "unknown error".to_string() } else { to_string(msg_send![error, localizedDescription]) };
+        return Err(reason);
+    }
+
+    let session: id = msg_send![class!(AVCaptureSession), new];
+    let output: id = msg_send![class!(AVCaptureVideoDataOutput), new];
+    let delegate: id = msg_send![delegate_class(), new];
+    let queue = dispatch_queue_create(b"com.pollen-robotics.preview-frame\0".as_ptr() as *const c_char, nil);
+    let _: () = msg_send![output, setAlwaysDiscardsLateVideoFrames: YES];
+    let _: () = msg_send![output, setSampleBufferDelegate: delegate queue: queue];
+
+    let can_add_input: BOOL = msg_send![session, canAddInput: input];
+    let can_add_output: BOOL = msg_send![session, canAddOutput: output];
+    let result = if can_add_input == NO || can_add_output == NO {
+        Err("camera in use by another app".to_string())
+    } else {
+        let _: () = msg_send![session, addInput: input];
+        let _: () = msg_send![session, addOutput: output];
+        let _: () = msg_send![session, startRunning];
+        let result = frame
+            .recv_timeout(FRAME_TIMEOUT)
+            .unwrap_or_else(|_| Err(format!("no frame within {}s (camera in use by another app?)", FRAME_TIMEOUT.as_secs())));
+        let _: () = msg_send![session, stopRunning];
+        result
+    };
+
+    let _: () = msg_send![output, setSampleBufferDelegate: nil queue: nil];
+    let _: () = msg_send![session, release];
+    let _: () = msg_send![output, release];
+    let _: () = msg_send![delegate, release];
+    dispatch_release(queue);
+    result
+}
+
+/// Grab one frame from a camera as a base64 JPEG
+pub fn capture_jpeg(index: u32) -> Result<String, String> {
+    let _capture = CAPTURE.lock().unwrap();
+    let (sender, frame) = mpsc::channel();
+    FRAMES_SEEN.store(0, Ordering::SeqCst);
+    *FRAME_SENDER.lock().unwrap() = Some(sender);
+
+    unsafe {
+        let pool = NSAutoreleasePool::new(nil);
+        let result = capture_with_session(index, &frame);
+        *FRAME_SENDER.lock().unwrap() = None;
+        pool.drain();
+        result
+    }
+}
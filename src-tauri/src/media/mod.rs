@@ -0,0 +1,181 @@
+use serde::Serialize;
+use tauri::AppHandle;
+
+#[cfg(target_os = "macos")]
+mod avfoundation;
+
+/// Grab one frame with OpenCV and print it as a base64 JPEG (AVFoundation is used on macOS)
+#[cfg(not(target_os = "macos"))]
+const CAPTURE_FRAME_SCRIPT: &str = r#"
+import base64, sys
+try:
+    import cv2
+except ImportError:
+    sys.exit("OpenCV is not installed in the venv")
+cap = cv2.VideoCapture(int(sys.argv[1]))
+ok, frame = cap.read()
+cap.release()
+if not ok or frame is None:
+    sys.exit("no frame (camera not connected, or in use by another app)")
+ok, buf = cv2.imencode(".jpg", frame)
+if not ok:
+    sys.exit("JPEG encoding failed")
+print("FRAME:" + base64.b64encode(buf.tobytes()).decode("ascii"))
+"#;
+
+#[cfg(not(target_os = "macos"))]
+const FRAME_MARKER: &str = "FRAME:";
+
+#[derive(Serialize)]
+pub struct PreviewFrame {
+    pub mime_type: String,
+    /// Base64-encoded image data
+    pub data: String,
+}
+
+/// Fail early with a specific error when camera access was refused (macOS)
+#[cfg(target_os = "macos")]
+fn ensure_camera_access() -> Result<(), String> {
+    use crate::permissions::{AUTHORIZATION_DENIED, AUTHORIZATION_RESTRICTED};
+    
+    match crate::permissions::camera_authorization_status() {
+        AUTHORIZATION_RESTRICTED => Err("Camera access is restricted on this Mac".to_string()),
+        AUTHORIZATION_DENIED => Err("Camera access denied: allow it in System Settings > Privacy & Security > Camera".to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn ensure_camera_access() -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+async fn capture_frame(_app_handle: &AppHandle, index: u32) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || avfoundation::capture_jpeg(index))
+        .await
+        .map_err(|e| format!("Failed to execute capture task: {}", e))?
+}
+
+#[cfg(not(target_os = "macos"))]
+async fn capture_frame(app_handle: &AppHandle, index: u32) -> Result<String, String> {
+    let index = index.to_string();
+    let output = crate::python::run_venv_python(app_handle, CAPTURE_FRAME_SCRIPT, &[&index]).await?;
+    
+    match output.marked_value(FRAME_MARKER) {
+        Some(data) if output.success => Ok(data.to_string()),
+        _ => Err(output.stderr.lines().last().unwrap_or("unknown error").trim().to_string()),
+    }
+}
+
+/// Capture a single frame from the camera, without starting the daemon media pipeline
+#[tauri::command]
+pub async fn capture_preview_frame(app_handle: AppHandle, camera_index: Option<u32>) -> Result<PreviewFrame, String> {
+    ensure_camera_access()?;
+    
+    let index = camera_index.unwrap_or(0);
+    println!("[tauri] 📷 Capturing preview frame from camera {}", index);
+    
+    let data = capture_frame(&app_handle, index)
+        .await
+        .map_err(|reason| format!("Failed to capture a frame from camera {}: {}", index, reason))?;
+    Ok(PreviewFrame {
+        mime_type: "image/jpeg".to_string(),
+        data,
+    })
+}
+
+// ============================================================================
+// CAPTURE DEVICES
+// ============================================================================
+
+#[derive(Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureDeviceKind {
+    Camera,
+    Microphone,
+}
+
+#[derive(Serialize)]
+pub struct CaptureDevice {
+    pub kind: CaptureDeviceKind,
+    /// Value to pass to set_capture_devices (device node on Linux, name elsewhere)
+    pub id: String,
+    pub name: String,
+}
+
+#[cfg(target_os = "macos")]
+fn probe_capture_devices() -> Result<Vec<CaptureDevice>, String> {
+    Ok(avfoundation::list_devices())
+}
+
+#[cfg(target_os = "windows")]
+fn probe_capture_devices() -> Result<Vec<CaptureDevice>, String> {
+    use std::process::Command;
+
+    let output = Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg("Get-CimInstance Win32_PnPEntity | Where-Object { $_.PNPClass -in 'Camera','Image' -or ($_.PNPClass -eq 'AudioEndpoint' -and $_.Name -match 'Microphone|Mic|Line In|Input') } | ForEach-Object { \"$($_.PNPClass)`t$($_.Name)\" }")
+        .output()
+        .map_err(|e| format!("Failed to query capture devices: {}", e))?;
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (class, name) = line.trim().split_once('\t')?;
+            let kind = if class == "AudioEndpoint" { CaptureDeviceKind::Microphone } else { CaptureDeviceKind::Camera };
+            Some(CaptureDevice { kind, id: name.to_string(), name: name.to_string() })
+        })
+        .collect())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn probe_capture_devices() -> Result<Vec<CaptureDevice>, String> {
+    use std::process::Command;
+
+    let mut devices = Vec::new();
+
+    // V4L2: a camera exposes several nodes, the capture one has index 0
+    let mut nodes: Vec<_> = std::fs::read_dir("/sys/class/video4linux")
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default();
+    nodes.sort();
+    for node in nodes {
+        let read = |file: &str| std::fs::read_to_string(node.join(file)).map(|v| v.trim().to_string()).ok();
+        if read("index").is_some_and(|index| index != "0") {
+            continue;
+        }
+        let id = format!("/dev/{}", node.file_name().unwrap_or_default().to_string_lossy());
+        devices.push(CaptureDevice {
+            kind: CaptureDeviceKind::Camera,
+            name: read("name").unwrap_or_else(|| id.clone()),
+            id,
+        });
+    }
+
+    // ALSA capture devices: "card 1: Audio [Reachy Mini Audio], device 0: USB Audio [USB Audio]"
+    if let Ok(output) = Command::new("arecord").arg("-l").output() {
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let parsed = line.strip_prefix("card ").and_then(|rest| {
+                let (card, rest) = rest.split_once(':')?;
+                let name = rest.split_once('[')?.1.split_once(']')?.0;
+                let device = rest.split_once("device ")?.1.split_once(':')?.0;
+                Some((format!("hw:{},{}", card.trim(), device.trim()), name.to_string()))
+            });
+            if let Some((id, name)) = parsed {
+                devices.push(CaptureDevice { kind: CaptureDeviceKind::Microphone, id, name });
+            }
+        }
+    }
+
+    Ok(devices)
+}
+
+/// Cameras and microphones the daemon can be told to use (set_capture_devices)
+#[tauri::command]
+pub async fn list_capture_devices() -> Result<Vec<CaptureDevice>, String> {
+    tauri::async_runtime::spawn_blocking(probe_capture_devices)
+        .await
+        .map_err(|e| format!("Failed to list capture devices: {}", e))?
+}
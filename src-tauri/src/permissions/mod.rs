@@ -80,3 +80,37 @@ pub fn open_camera_settings() -> Result<(), String> {
 pub fn open_microphone_settings() -> Result<(), String> {
     Ok(())
 }
+
+/// AVAuthorizationStatus values
+#[cfg(target_os = "macos")]
+pub const AUTHORIZATION_RESTRICTED: isize = 1;
+#[cfg(target_os = "macos")]
+pub const AUTHORIZATION_DENIED: isize = 2;
+
+/// Capture access status for an AVMediaType (macOS)
+/// AVAuthorizationStatus: 0 = not determined, 1 = restricted, 2 = denied, 3 = authorized
+#[cfg(target_os = "macos")]
+fn authorization_status(media_type: &str) -> isize {
+    use cocoa::base::{id, nil};
+    use cocoa::foundation::NSString;
+    use objc::{class, msg_send, sel, sel_impl};
+    
+    unsafe {
+        let media_type: id = NSString::alloc(nil).init_str(media_type);
+        msg_send![class!(AVCaptureDevice), authorizationStatusForMediaType: media_type]
+    }
+}
+
+/// Camera access status for the app (macOS)
+#[cfg(target_os = "macos")]
+pub fn camera_authorization_status() -> isize {
+    // AVMediaTypeVideo
+    authorization_status("vide")
+}
+
+/// Microphone access status for the app (macOS)
+#[cfg(target_os = "macos")]
+pub fn microphone_authorization_status() -> isize {
+    // AVMediaTypeAudio
+    authorization_status("soun")
+}
@@ -0,0 +1,418 @@
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+use crate::daemon::DaemonState;
+
+#[derive(Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Serialize)]
+pub struct PreflightCheck {
+    pub id: String,
+    pub label: String,
+    pub status: CheckStatus,
+    pub message: String,
+    /// What the user can do about a warning or failure
+    pub remediation: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct PreflightReport {
+    pub sim_mode: bool,
+    pub checks: Vec<PreflightCheck>,
+    /// False if any check failed ("Start" should stay disabled)
+    pub ready: bool,
+}
+
+fn check(id: &str, label: &str, status: CheckStatus, message: String, remediation: Option<&str>) -> PreflightCheck {
+    PreflightCheck {
+        id: id.to_string(),
+        label: label.to_string(),
+        status,
+        message,
+        remediation: remediation.map(str::to_string),
+    }
+}
+
+// ============================================================================
+// INDIVIDUAL CHECKS
+// ============================================================================
+
+fn check_sidecars(app_handle: &AppHandle) -> PreflightCheck {
+    let sidecars = app_handle.state::<crate::bundle::SidecarCheck>();
+    match &sidecars.message {
+        None => check("sidecars", "App bundle", CheckStatus::Pass, "All sidecars present".to_string(), None),
+        Some(message) => check("sidecars", "App bundle", CheckStatus::Fail, message.clone(), Some("Reinstall the app")),
+    }
+}
+
+fn check_venv() -> PreflightCheck {
+    match crate::python::find_venv_dir() {
+        Ok(venv) if venv.exists() => check("venv", "Python environment", CheckStatus::Pass, venv.display().to_string(), None),
+        Ok(venv) => check(
+            "venv",
+            "Python environment",
+            CheckStatus::Fail,
+            format!("No venv at {}", venv.display()),
+            Some("Reinstall the app"),
+        ),
+        Err(e) => check("venv", "Python environment", CheckStatus::Fail, e, Some("Reinstall the app")),
+    }
+}
+
+async fn check_reachy_mini_import(app_handle: &AppHandle) -> PreflightCheck {
+    const SCRIPT: &str = "import reachy_mini; print('IMPORT:ok')";
+
+    match crate::python::run_venv_python(app_handle, SCRIPT, &[]).await {
+        Ok(output) if output.success && output.marked_value("IMPORT:").is_some() => check(
+            "reachy_mini_import",
+            "reachy-mini package",
+            CheckStatus::Pass,
+            "reachy_mini imports correctly".to_string(),
+            None,
+        ),
+        Ok(output) => check(
+            "reachy_mini_import",
+            "reachy-mini package",
+            CheckStatus::Fail,
+            output.stderr.lines().last().unwrap_or("import failed").trim().to_string(),
+            Some("Repair the Python environment or reinstall the app"),
+        ),
+        Err(e) => check(
+            "reachy_mini_import",
+            "reachy-mini package",
+            CheckStatus::Fail,
+            e,
+            Some("Reinstall the app"),
+        ),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn check_permissions() -> PreflightCheck {
+    use crate::permissions::{camera_authorization_status, microphone_authorization_status, AUTHORIZATION_DENIED, AUTHORIZATION_RESTRICTED};
+
+    let denied: Vec<&str> = [("camera", camera_authorization_status()), ("microphone", microphone_authorization_status())]
+        .into_iter()
+        .filter(|(_, status)| *status == AUTHORIZATION_DENIED || *status == AUTHORIZATION_RESTRICTED)
+        .map(|(name, _)| name)
+        .collect();
+
+    if denied.is_empty() {
+        check("permissions", "Camera & microphone access", CheckStatus::Pass, "Not denied".to_string(), None)
+    } else {
+        check(
+            "permissions",
+            "Camera & microphone access",
+            CheckStatus::Warn,
+            format!("Access denied: {}", denied.join(", ")),
+            Some("Allow access in System Settings > Privacy & Security"),
+        )
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn check_permissions() -> PreflightCheck {
+    check("permissions", "Camera & microphone access", CheckStatus::Pass, "No permission required".to_string(), None)
+}
+
+fn check_app_location() -> PreflightCheck {
+    let status = crate::signing::quarantine_status();
+    
+    if !status.applicable {
+        return check("app_location", "App location", CheckStatus::Pass, "Not applicable".to_string(), None);
+    }
+    
+    if status.translocated {
+        check(
+            "app_location",
+            "App location",
+            CheckStatus::Fail,
+            "Running from a read-only translocated copy".to_string(),
+            status.guidance.as_deref(),
+        )
+    } else if status.quarantined {
+        check(
+            "app_location",
+            "App location",
+            CheckStatus::Warn,
+            "App is quarantined (com.apple.quarantine)".to_string(),
+            status.guidance.as_deref(),
+        )
+    } else {
+        check("app_location", "App location", CheckStatus::Pass, status.bundle.unwrap_or_default(), None)
+    }
+}
+
+fn check_robot() -> PreflightCheck {
+    match crate::usb::find_robots() {
+        Ok(robots) if !robots.is_empty() => check(
+            "usb_robot",
+            "Robot connected",
+            CheckStatus::Pass,
+            robots.iter().map(|r| r.port_name.as_str()).collect::<Vec<_>>().join(", "),
+            None,
+        ),
+        Ok(_) => check(
+            "usb_robot",
+            "Robot connected",
+            CheckStatus::Fail,
+            "No Reachy Mini detected over USB".to_string(),
+            Some("Plug the robot in and power it on, or use simulation mode"),
+        ),
+        Err(e) => check("usb_robot", "Robot connected", CheckStatus::Warn, e, Some("Check USB permissions")),
+    }
+}
+
+fn check_disk_space() -> PreflightCheck {
+    const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+    match crate::system::install_disk_status() {
+        Ok(status) if status.sufficient => check(
+            "disk_space",
+            "Disk space",
+            CheckStatus::Pass,
+            format!("{:.1} GB free", status.free_bytes as f64 / GB),
+            None,
+        ),
+        Ok(status) => check(
+            "disk_space",
+            "Disk space",
+            CheckStatus::Warn,
+            format!("Only {:.1} GB free on {}", status.free_bytes as f64 / GB, status.path),
+            Some("Free up disk space before installing updates or MuJoCo"),
+        ),
+        Err(e) => check("disk_space", "Disk space", CheckStatus::Warn, e, None),
+    }
+}
+
+/// Simulation on macOS: the daemon runs under mjpython, broken once the venv moved
+fn check_mjpython() -> PreflightCheck {
+    match crate::python::inspect_mjpython() {
+        Ok(mjpython) if !mjpython.exists => check(
+            "mjpython",
+            "mjpython",
+            CheckStatus::Fail,
+            format!("{} not found", mjpython.path),
+            Some("Reinstall MuJoCo from the simulation settings"),
+        ),
+        Ok(mjpython) if mjpython.valid => check("mjpython", "mjpython", CheckStatus::Pass, mjpython.path, None),
+        Ok(mjpython) => check(
+            "mjpython",
+            "mjpython",
+            CheckStatus::Warn,
+            format!("mjpython runs a missing interpreter: {}", mjpython.interpreter.unwrap_or_else(|| "unknown".to_string())),
+            Some("Repair mjpython (also done when the simulation starts)"),
+        ),
+        Err(e) => check("mjpython", "mjpython", CheckStatus::Warn, e, None),
+    }
+}
+
+fn check_venv_writable() -> PreflightCheck {
+    match crate::system::venv_writable() {
+        Ok(status) if status.writable => check("venv_writable", "Environment writable", CheckStatus::Pass, status.path, None),
+        Ok(status) => check(
+            "venv_writable",
+            "Environment writable",
+            CheckStatus::Warn,
+            format!("Cannot write to {}: {}", status.path, status.error.unwrap_or_default()),
+            Some(match status.failure {
+                Some(crate::system::WriteFailure::Translocated) => "Move the app to the Applications folder and open it from there",
+                Some(crate::system::WriteFailure::ReadOnly) => "The app is on a read-only volume, install it on a writable disk",
+                _ => "Installs and updates will fail, check the permissions of the app folder",
+            }),
+        ),
+        Err(e) => check("venv_writable", "Environment writable", CheckStatus::Warn, e, None),
+    }
+}
+
+/// Simulation only: MuJoCo and datasets on a nearly full RAM get the daemon OOM-killed
+fn check_memory() -> PreflightCheck {
+    const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+    match crate::system::system_memory() {
+        Ok(memory) if memory.sufficient_for_sim => check(
+            "memory",
+            "Memory",
+            CheckStatus::Pass,
+            format!("{:.1} GB available", memory.available_bytes as f64 / GB),
+            None,
+        ),
+        Ok(memory) => check(
+            "memory",
+            "Memory",
+            CheckStatus::Warn,
+            format!(
+                "Only {:.1} GB of {:.1} GB available, simulation needs about {:.0} GB",
+                memory.available_bytes as f64 / GB,
+                memory.total_bytes as f64 / GB,
+                memory.sim_required_bytes as f64 / GB
+            ),
+            Some("Close other applications; if the daemon stops unexpectedly, it was likely killed for lack of memory"),
+        ),
+        Err(e) => check("memory", "Memory", CheckStatus::Warn, e, None),
+    }
+}
+
+fn check_mujoco() -> PreflightCheck {
+    let version = crate::python::find_venv_dir()
+        .ok()
+        .and_then(|venv| crate::python::installed_package_version(&venv, "mujoco"));
+
+    match version {
+        Some(version) => check("mujoco", "MuJoCo", CheckStatus::Pass, format!("mujoco {}", version), None),
+        None => check(
+            "mujoco",
+            "MuJoCo",
+            CheckStatus::Warn,
+            "MuJoCo is not installed yet".to_string(),
+            Some("It will be installed on the first simulation start (internet required)"),
+        ),
+    }
+}
+
+/// Only run when MuJoCo is installed (check_mujoco reports it missing otherwise)
+async fn check_mujoco_self_test(app_handle: &AppHandle) -> Option<PreflightCheck> {
+    crate::python::find_venv_dir()
+        .ok()
+        .and_then(|venv| crate::python::installed_package_version(&venv, "mujoco"))?;
+
+    let result = crate::python::mujoco_self_test(app_handle).await;
+    Some(match result.error {
+        None => check(
+            "mujoco_self_test",
+            "MuJoCo self-test",
+            CheckStatus::Pass,
+            "MuJoCo loads a model".to_string(),
+            None,
+        ),
+        Some(error) => check(
+            "mujoco_self_test",
+            "MuJoCo self-test",
+            CheckStatus::Fail,
+            error,
+            Some("Reinstall MuJoCo, or repair the Python environment"),
+        ),
+    })
+}
+
+fn check_graphics() -> PreflightCheck {
+    let capability = crate::system::check_graphics_capability();
+    let renderer = capability.renderer.clone().unwrap_or_else(|| "unknown renderer".to_string());
+
+    match capability.warning {
+        None => check("graphics", "Graphics", CheckStatus::Pass, renderer, None),
+        Some(warning) if !capability.has_display => check(
+            "graphics",
+            "Graphics",
+            CheckStatus::Fail,
+            warning,
+            Some("Run the app from a desktop session, or use the mockup simulation"),
+        ),
+        Some(warning) => check("graphics", "Graphics", CheckStatus::Warn, warning, Some("Install your GPU drivers")),
+    }
+}
+
+fn check_network() -> PreflightCheck {
+    use crate::system::Reachability;
+
+    let endpoints = crate::system::network_endpoints_status();
+    let names = |status: Reachability| {
+        endpoints
+            .iter()
+            .filter(|e| e.status == status)
+            .map(|e| e.name.as_str())
+            .collect::<Vec<_>>()
+    };
+    let blocked = names(Reachability::Blocked);
+    let slow = names(Reachability::Slow);
+
+    if !blocked.is_empty() {
+        check(
+            "network",
+            "Network access",
+            CheckStatus::Warn,
+            format!("Unreachable: {}", blocked.join(", ")),
+            Some("Installs and updates need these services, check your firewall or proxy"),
+        )
+    } else if !slow.is_empty() {
+        check(
+            "network",
+            "Network access",
+            CheckStatus::Warn,
+            format!("Slow: {}", slow.join(", ")),
+            Some("Installs may take longer than usual"),
+        )
+    } else {
+        check("network", "Network access", CheckStatus::Pass, "All services reachable".to_string(), None)
+    }
+}
+
+fn check_port(app_handle: &AppHandle) -> PreflightCheck {
+    let state: State<DaemonState> = app_handle.state();
+    let managed = state.process.lock().unwrap().is_some();
+    let endpoint = crate::daemon::DaemonEndpoint::from_settings(&crate::settings::current(app_handle));
+
+    if managed || !crate::daemon::is_port_in_use(&endpoint.host, endpoint.port) {
+        check("port", "Daemon port", CheckStatus::Pass, format!("Port {} available", endpoint.port), None)
+    } else {
+        check(
+            "port",
+            "Daemon port",
+            CheckStatus::Warn,
+            format!("Port {} is used by another process", endpoint.port),
+            Some("It will be freed on start; close other Reachy Mini daemons if the start fails"),
+        )
+    }
+}
+
+// ============================================================================
+// TAURI COMMANDS
+// ============================================================================
+
+/// Run every environment check needed before starting the daemon
+#[tauri::command]
+pub async fn run_preflight(app_handle: AppHandle, sim_mode: Option<bool>) -> Result<PreflightReport, String> {
+    let sim_mode = sim_mode.unwrap_or(false);
+
+    let mut checks = vec![check_sidecars(&app_handle), check_venv(), check_reachy_mini_import(&app_handle).await];
+    if sim_mode {
+        checks.extend(check_mujoco_self_test(&app_handle).await);
+    }
+
+    let app_handle_clone = app_handle.clone();
+    let system_checks = tauri::async_runtime::spawn_blocking(move || {
+        let mut checks = vec![check_app_location(), check_permissions()];
+        if sim_mode {
+            checks.push(check_mujoco());
+            if cfg!(target_os = "macos") {
+                checks.push(check_mjpython());
+            }
+            checks.push(check_graphics());
+            checks.push(check_memory());
+        } else {
+            checks.push(check_robot());
+        }
+        checks.push(check_disk_space());
+        checks.push(check_venv_writable());
+        checks.push(check_network());
+        checks.push(check_port(&app_handle_clone));
+        checks
+    })
+    .await
+    .map_err(|e| format!("Failed to execute preflight checks: {}", e))?;
+    checks.extend(system_checks);
+
+    let ready = checks.iter().all(|c| c.status != CheckStatus::Fail);
+    println!(
+        "[tauri] 🩺 Preflight: {}/{} checks passed",
+        checks.iter().filter(|c| c.status == CheckStatus::Pass).count(),
+        checks.len()
+    );
+
+    Ok(PreflightReport { sim_mode, checks, ready })
+}
@@ -0,0 +1,761 @@
+use std::sync::Mutex;
+use std::time::Duration;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+use crate::daemon::readiness::ReadinessStage;
+use crate::daemon::{is_current_session, DaemonState};
+
+// ============================================================================
+// PID RESOLUTION
+// ============================================================================
+
+/// List the direct children of a process
+#[cfg(not(target_os = "windows"))]
+pub fn child_pids(pid: u32) -> Vec<u32> {
+    use std::process::Command;
+
+    Command::new("pgrep")
+        .arg("-P")
+        .arg(pid.to_string())
+        .output()
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| line.trim().parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "windows")]
+pub fn child_pids(pid: u32) -> Vec<u32> {
+    use std::process::Command;
+
+    Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg(format!(
+            "Get-CimInstance Win32_Process -Filter \"ParentProcessId={}\" | ForEach-Object {{ $_.ProcessId }}",
+            pid
+        ))
+        .output()
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| line.trim().parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// PID of the Python daemon spawned by the tracked uv-trampoline sidecar
+/// Falls back to the trampoline itself if the child can't be found
+pub fn daemon_pid(state: &State<DaemonState>) -> Option<u32> {
+    let trampoline_pid = state.process.lock().unwrap().as_ref().map(|child| child.pid())?;
+    Some(child_pids(trampoline_pid).into_iter().next().unwrap_or(trampoline_pid))
+}
+
+// ============================================================================
+// RESOURCE USAGE
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct DaemonResources {
+    pub pid: u32,
+    /// Resident memory in bytes
+    pub memory_bytes: u64,
+    pub cpu_percent: f64,
+    pub uptime_secs: u64,
+}
+
+/// Sample memory (bytes) and CPU (%) of a process
+#[cfg(not(target_os = "windows"))]
+fn sample_process(pid: u32) -> Result<(u64, f64), String> {
+    use std::process::Command;
+
+    let output = Command::new("ps")
+        .arg("-o")
+        .arg("rss=,%cpu=")
+        .arg("-p")
+        .arg(pid.to_string())
+        .output()
+        .map_err(|e| format!("Failed to run ps: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!("Process {} is not running", pid));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut fields = stdout.split_whitespace();
+    let rss_kb = fields.next().and_then(|v| v.parse::<u64>().ok());
+    let cpu = fields.next().and_then(|v| v.replace(',', ".").parse::<f64>().ok());
+
+    match (rss_kb, cpu) {
+        (Some(rss_kb), Some(cpu)) => Ok((rss_kb * 1024, cpu)),
+        _ => Err(format!("Unexpected ps output: {}", stdout.trim())),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn sample_process(pid: u32) -> Result<(u64, f64), String> {
+    use std::process::Command;
+
+    let output = Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg(format!(
+            "$p = Get-CimInstance Win32_PerfFormattedData_PerfProc_Process -Filter \"IDProcess={}\"; \"$($p.WorkingSetPrivate) $($p.PercentProcessorTime)\"",
+            pid
+        ))
+        .output()
+        .map_err(|e| format!("Failed to query process: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut fields = stdout.split_whitespace();
+    let memory = fields.next().and_then(|v| v.parse::<u64>().ok());
+    let cpu = fields.next().and_then(|v| v.parse::<f64>().ok());
+
+    match (memory, cpu) {
+        (Some(memory), Some(cpu)) => Ok((memory, cpu)),
+        _ => Err(format!("Process {} is not running", pid)),
+    }
+}
+
+/// Current resource usage of the daemon, None when it isn't running
+pub fn daemon_resources(state: &State<DaemonState>) -> Result<Option<DaemonResources>, String> {
+    let pid = match daemon_pid(state) {
+        Some(pid) => pid,
+        None => return Ok(None),
+    };
+
+    let (memory_bytes, cpu_percent) = sample_process(pid)?;
+    let uptime_secs = state
+        .started_at
+        .lock()
+        .unwrap()
+        .map(|started| started.elapsed().as_secs())
+        .unwrap_or(0);
+
+    Ok(Some(DaemonResources {
+        pid,
+        memory_bytes,
+        cpu_percent,
+        uptime_secs,
+    }))
+}
+
+#[tauri::command]
+pub fn get_daemon_resources(state: State<DaemonState>) -> Result<Option<DaemonResources>, String> {
+    daemon_resources(&state)
+}
+
+// ============================================================================
+// METRICS STREAM
+// ============================================================================
+
+const MIN_METRICS_INTERVAL_MS: u64 = 250;
+const DEFAULT_METRICS_INTERVAL_MS: u64 = 1000;
+
+/// Bumped on every start/stop so a running stream knows it was superseded
+#[derive(Default)]
+pub struct MetricsStreamState {
+    pub generation: Mutex<u64>,
+}
+
+/// Payload of the "daemon-metrics" event
+#[derive(Clone, Serialize)]
+pub struct DaemonMetrics {
+    pub session_id: u64,
+    pub pid: Option<u32>,
+    pub memory_bytes: Option<u64>,
+    pub cpu_percent: Option<f64>,
+    pub uptime_secs: u64,
+    /// Last readiness probe result (health of the daemon API)
+    pub readiness: Option<ReadinessStage>,
+}
+
+fn is_current_stream(app_handle: &AppHandle, generation: u64) -> bool {
+    *app_handle.state::<MetricsStreamState>().generation.lock().unwrap() == generation
+}
+
+/// Emit "daemon-metrics" every `interval_ms` (default 1s) until stopped or
+/// until the daemon run it was started for ends
+#[tauri::command]
+pub fn start_metrics_stream(app_handle: AppHandle, interval_ms: Option<u64>) -> Result<(), String> {
+    let interval = Duration::from_millis(interval_ms.unwrap_or(DEFAULT_METRICS_INTERVAL_MS).max(MIN_METRICS_INTERVAL_MS));
+
+    let session_id = {
+        let state: State<DaemonState> = app_handle.state();
+        if state.process.lock().unwrap().is_none() {
+            return Err("Daemon is not running".to_string());
+        }
+        let session_id = *state.session_id.lock().unwrap();
+        session_id
+    };
+
+    let generation = {
+        let stream: State<MetricsStreamState> = app_handle.state();
+        let mut generation = stream.generation.lock().unwrap();
+        *generation += 1;
+        *generation
+    };
+
+    println!("[tauri] 📈 Metrics stream started ({}ms)", interval.as_millis());
+
+    tauri::async_runtime::spawn_blocking(move || {
+        while is_current_stream(&app_handle, generation) && is_current_session(&app_handle, session_id) {
+            let state: State<DaemonState> = app_handle.state();
+            let resources = daemon_resources(&state).ok().flatten();
+            let uptime_secs = state
+                .started_at
+                .lock()
+                .unwrap()
+                .map(|started| started.elapsed().as_secs())
+                .unwrap_or(0);
+
+            let _ = app_handle.emit("daemon-metrics", DaemonMetrics {
+                session_id,
+                pid: resources.as_ref().map(|r| r.pid),
+                memory_bytes: resources.as_ref().map(|r| r.memory_bytes),
+                cpu_percent: resources.as_ref().map(|r| r.cpu_percent),
+                uptime_secs,
+                readiness: *state.readiness.lock().unwrap(),
+            });
+
+            std::thread::sleep(interval);
+        }
+        println!("[tauri] 📈 Metrics stream stopped");
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_metrics_stream(stream: State<MetricsStreamState>) {
+    *stream.generation.lock().unwrap() += 1;
+}
+
+// ============================================================================
+// EXTERNAL DAEMONS
+// ============================================================================
+
+/// Module the daemon runs as, in every daemon's command line
+const DAEMON_MODULE: &str = "reachy_mini.daemon.app.main";
+
+#[derive(Serialize)]
+pub struct ExternalDaemon {
+    pub pid: u32,
+    pub command_line: String,
+    /// Matched by its command line (a reachy_mini daemon)
+    pub matches_module: bool,
+    /// Listening on the daemon port (may be another program holding the port)
+    pub on_daemon_port: bool,
+}
+
+/// PIDs and command lines of every running process
+#[cfg(not(target_os = "windows"))]
+fn list_processes() -> Vec<(u32, String)> {
+    use std::process::Command;
+
+    Command::new("ps")
+        .arg("-axo")
+        .arg("pid=,command=")
+        .output()
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| {
+                    let (pid, command) = line.trim().split_once(char::is_whitespace)?;
+                    Some((pid.parse().ok()?, command.trim().to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "windows")]
+fn list_processes() -> Vec<(u32, String)> {
+    use std::process::Command;
+
+    Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg("Get-CimInstance Win32_Process | Where-Object { $_.CommandLine } | ForEach-Object { \"$($_.ProcessId) $($_.CommandLine)\" }")
+        .output()
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| {
+                    let (pid, command) = line.trim().split_once(' ')?;
+                    Some((pid.parse().ok()?, command.trim().to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// PIDs listening on a TCP port
+#[cfg(not(target_os = "windows"))]
+fn listening_pids(port: u16) -> Vec<u32> {
+    use std::process::Command;
+
+    Command::new("lsof")
+        .arg(format!("-tiTCP:{}", port))
+        .arg("-sTCP:LISTEN")
+        .output()
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| line.trim().parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "windows")]
+fn listening_pids(port: u16) -> Vec<u32> {
+    use std::process::Command;
+
+    Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg(format!(
+            "Get-NetTCPConnection -LocalPort {} -State Listen -ErrorAction SilentlyContinue | ForEach-Object {{ $_.OwningProcess }}",
+            port
+        ))
+        .output()
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| line.trim().parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The tracked sidecar and all its descendants
+fn app_spawned_pids(state: &State<DaemonState>) -> Vec<u32> {
+    let mut pids: Vec<u32> = state.process.lock().unwrap().as_ref().map(|child| child.pid()).into_iter().collect();
+    let mut index = 0;
+    while index < pids.len() {
+        let children = child_pids(pids[index]);
+        pids.extend(children);
+        index += 1;
+    }
+    pids
+}
+
+/// Daemons running on this machine that the app did not spawn
+/// (matched by module name or by listening on the daemon port)
+pub fn external_daemons(state: &State<DaemonState>) -> Vec<ExternalDaemon> {
+    let own_pids = app_spawned_pids(state);
+    let port_pids = listening_pids(state.endpoint.lock().unwrap().port);
+    let app_pid = std::process::id();
+
+    list_processes()
+        .into_iter()
+        .filter(|(pid, _)| *pid != app_pid && !own_pids.contains(pid))
+        .filter_map(|(pid, command_line)| {
+            let matches_module = command_line.contains(DAEMON_MODULE);
+            let on_daemon_port = port_pids.contains(&pid);
+            // pgrep/pkill lookups for the module match it too, skip them
+            let is_lookup = command_line.starts_with("pgrep") || command_line.starts_with("pkill");
+            ((matches_module && !is_lookup) || on_daemon_port).then_some(ExternalDaemon {
+                pid,
+                command_line,
+                matches_module,
+                on_daemon_port,
+            })
+        })
+        .collect()
+}
+
+/// List daemons the app did not start (e.g. launched by hand from a terminal),
+/// so the UI can warn before they collide with the app's own daemon
+#[tauri::command]
+pub async fn detect_external_daemons(app_handle: AppHandle) -> Result<Vec<ExternalDaemon>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let state: State<DaemonState> = app_handle.state();
+        let daemons = external_daemons(&state);
+        for daemon in &daemons {
+            println!("[tauri] ⚠️  External daemon detected (PID {}): {}", daemon.pid, daemon.command_line);
+        }
+        daemons
+    })
+    .await
+    .map_err(|e| format!("Failed to detect external daemons: {}", e))
+}
+
+// ============================================================================
+// PRIORITY
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct DaemonPriority {
+    pub pid: u32,
+    /// Unix niceness (-20 highest priority, 19 lowest)
+    pub niceness: i32,
+    /// Windows priority class the niceness maps to
+    pub priority_class: Option<String>,
+    /// CPUs the daemon is pinned to, None when affinity was not changed
+    pub affinity: Option<Vec<usize>>,
+}
+
+/// Thread ids of a process (Linux applies niceness per thread)
+#[cfg(target_os = "linux")]
+fn process_threads(pid: u32) -> Vec<String> {
+    std::fs::read_dir(format!("/proc/{}/task", pid))
+        .map(|entries| entries.flatten().map(|entry| entry.file_name().to_string_lossy().into_owned()).collect())
+        .unwrap_or_else(|_| vec![pid.to_string()])
+}
+
+#[cfg(not(target_os = "windows"))]
+fn apply_priority(pid: u32, niceness: i32, cpus: Option<&[usize]>) -> Result<Option<String>, String> {
+    use std::process::Command;
+
+    #[cfg(not(target_os = "linux"))]
+    if cpus.is_some() {
+        return Err("CPU affinity is not supported on macOS".to_string());
+    }
+
+    #[cfg(target_os = "linux")]
+    let targets = process_threads(pid);
+    #[cfg(not(target_os = "linux"))]
+    let targets = vec![pid.to_string()];
+
+    let output = Command::new("renice")
+        .arg("-n")
+        .arg(niceness.to_string())
+        .arg("-p")
+        .args(&targets)
+        .output()
+        .map_err(|e| format!("Failed to run renice: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(if niceness < 0 {
+            format!("Raising the daemon priority requires administrator rights: {}", stderr.trim())
+        } else {
+            format!("renice failed: {}", stderr.trim())
+        });
+    }
+
+    #[cfg(target_os = "linux")]
+    if let Some(cpus) = cpus {
+        let list = cpus.iter().map(|cpu| cpu.to_string()).collect::<Vec<_>>().join(",");
+        let output = Command::new("taskset")
+            .arg("-a")
+            .arg("-pc")
+            .arg(&list)
+            .arg(pid.to_string())
+            .output()
+            .map_err(|e| format!("Failed to run taskset: {}", e))?;
+        if !output.status.success() {
+            return Err(format!("taskset failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(target_os = "windows")]
+fn apply_priority(pid: u32, niceness: i32, cpus: Option<&[usize]>) -> Result<Option<String>, String> {
+    use std::process::Command;
+
+    let priority_class = match niceness {
+        i32::MIN..=-10 => "High",
+        -9..=-1 => "AboveNormal",
+        0 => "Normal",
+        1..=9 => "BelowNormal",
+        _ => "Idle",
+    };
+
+    let mut script = format!("$p = Get-Process -Id {}; $p.PriorityClass = '{}'", pid, priority_class);
+    if let Some(cpus) = cpus {
+        let mask = cpus.iter().filter(|cpu| **cpu < 64).fold(0u64, |mask, cpu| mask | (1 << cpu));
+        script.push_str(&format!("; $p.ProcessorAffinity = {}", mask));
+    }
+
+    let output = Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg(script)
+        .output()
+        .map_err(|e| format!("Failed to set process priority: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("Failed to set process priority: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+
+    Ok(Some(priority_class.to_string()))
+}
+
+/// Change the daemon's scheduling priority, and optionally pin it to some CPUs
+/// (Linux and Windows). Raising the priority (negative niceness) needs
+/// administrator rights on Unix.
+#[tauri::command]
+pub fn set_daemon_priority(
+    state: State<DaemonState>,
+    niceness: i32,
+    cpus: Option<Vec<usize>>,
+) -> Result<DaemonPriority, String> {
+    if !(-20..=19).contains(&niceness) {
+        return Err(format!("Niceness must be between -20 and 19, got {}", niceness));
+    }
+    if cpus.as_ref().is_some_and(|cpus| cpus.is_empty()) {
+        return Err("At least one CPU is needed for the affinity".to_string());
+    }
+
+    let pid = daemon_pid(&state).ok_or("Daemon is not running")?;
+    let priority_class = apply_priority(pid, niceness, cpus.as_deref())?;
+
+    println!("[tauri] ⚖️  Daemon (PID {}) niceness set to {}", pid, niceness);
+
+    Ok(DaemonPriority {
+        pid,
+        niceness,
+        priority_class,
+        affinity: cpus,
+    })
+}
+
+// ============================================================================
+// TARGETED KILL
+// ============================================================================
+
+/// Console script reachy-mini installs to start the daemon
+const DAEMON_ENTRYPOINT: &str = "reachy-mini-daemon";
+/// Executables a daemon runs under (python3.X is accepted too)
+const DAEMON_EXECUTABLES: &[&str] = &["python", "python3", "mjpython", "uv-trampoline", DAEMON_ENTRYPOINT];
+
+#[derive(Serialize)]
+pub struct ProcessKill {
+    pub pid: u32,
+    /// Executable name of the killed process
+    pub name: String,
+    /// True when it ignored the graceful request and had to be force-killed
+    pub forced: bool,
+}
+
+/// File name of a command line token, without quotes nor .exe
+fn executable_name(token: &str) -> String {
+    let name = token.trim_matches('"').rsplit(['/', '\\']).next().unwrap_or_default().to_lowercase();
+    name.strip_suffix(".exe").map(str::to_string).unwrap_or(name)
+}
+
+fn is_daemon_executable(name: &str) -> bool {
+    DAEMON_EXECUTABLES.contains(&name)
+        || name.strip_prefix("python").is_some_and(|version| version.chars().all(|c| c.is_ascii_digit() || c == '.'))
+}
+
+/// Executable name of a daemon's command line: a known interpreter running the
+/// daemon module (-m) or the daemon entry point, None for any other process
+fn daemon_executable(command_line: &str) -> Option<String> {
+    let tokens: Vec<&str> = command_line.split_whitespace().collect();
+    let target = (0..tokens.len()).find(|&i| {
+        (tokens[i] == DAEMON_MODULE && i > 0 && tokens[i - 1] == "-m") || executable_name(tokens[i]) == DAEMON_ENTRYPOINT
+    })?;
+
+    // The executable path may contain spaces ("Reachy Mini Control.app"), its
+    // name is in the last token before the target that isn't an option
+    let executable = tokens[..target]
+        .iter()
+        .rev()
+        .find(|token| !token.starts_with('-'))
+        .map(|token| executable_name(token))
+        .unwrap_or_else(|| executable_name(tokens[target]));
+    is_daemon_executable(&executable).then_some(executable)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn is_process_alive(pid: u32) -> bool {
+    std::process::Command::new("kill")
+        .arg("-0")
+        .arg(pid.to_string())
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(target_os = "windows")]
+fn is_process_alive(pid: u32) -> bool {
+    std::process::Command::new("tasklist")
+        .args(["/NH", "/FI", &format!("PID eq {}", pid)])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn terminate(pid: u32, force: bool) {
+    let _ = std::process::Command::new("kill")
+        .arg(if force { "-KILL" } else { "-TERM" })
+        .arg(pid.to_string())
+        .output();
+}
+
+#[cfg(target_os = "windows")]
+fn terminate(pid: u32, force: bool) {
+    let mut command = std::process::Command::new("taskkill");
+    command.arg("/PID").arg(pid.to_string()).arg("/T");
+    if force {
+        command.arg("/F");
+    }
+    let _ = command.output();
+}
+
+/// Terminate one Reachy Mini daemon (e.g. found by detect_external_daemons)
+/// Refuses processes other than a Python interpreter, mjpython or the trampoline
+/// running the daemon module or entry point, and the app's own daemon (stop it instead). Graceful first, forced after the
+/// configured shutdown wait.
+#[tauri::command]
+pub async fn kill_process(app_handle: AppHandle, pid: u32) -> Result<ProcessKill, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let state: State<DaemonState> = app_handle.state();
+        if pid == std::process::id() || app_spawned_pids(&state).contains(&pid) {
+            return Err("This process belongs to the app's daemon, stop the daemon instead".to_string());
+        }
+
+        let command_line = list_processes()
+            .into_iter()
+            .find_map(|(p, command_line)| (p == pid).then_some(command_line))
+            .ok_or_else(|| format!("No process with PID {}", pid))?;
+        let name = daemon_executable(&command_line)
+            .ok_or_else(|| format!("PID {} is not a Reachy Mini daemon: {}", pid, command_line))?;
+
+        terminate(pid, false);
+        let term_wait = Duration::from_millis(crate::settings::current(&app_handle).shutdown.term_wait_ms);
+        let start = std::time::Instant::now();
+        while is_process_alive(pid) && start.elapsed() < term_wait {
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        let forced = is_process_alive(pid);
+        if forced {
+            terminate(pid, true);
+            std::thread::sleep(Duration::from_millis(200));
+            if is_process_alive(pid) {
+                return Err(format!("Failed to kill {} (PID {})", name, pid));
+            }
+        }
+
+        println!("[tauri] 🔪 Killed {} (PID {}){}", name, pid, if forced { " after force" } else { "" });
+
+        Ok(ProcessKill { pid, name, forced })
+    })
+    .await
+    .map_err(|e| format!("Failed to execute kill task: {}", e))?
+}
+
+// ============================================================================
+// STACK DUMP
+// ============================================================================
+
+/// Give the aborted daemon this long to print its traceback and exit
+#[cfg(not(target_os = "windows"))]
+const ABORT_WAIT: Duration = Duration::from_secs(3);
+
+#[derive(Serialize)]
+pub struct DaemonStackDump {
+    pub pid: u32,
+    /// "py-spy" (daemon keeps running) or "faulthandler" (daemon aborted)
+    pub method: String,
+    /// Traceback of every thread, also written to the logs
+    pub output: String,
+    pub daemon_terminated: bool,
+}
+
+/// py-spy installed in the venv, if any
+fn py_spy_path() -> Option<std::path::PathBuf> {
+    let venv = crate::python::find_venv_dir().ok()?;
+    let path = if cfg!(target_os = "windows") { venv.join("Scripts/py-spy.exe") } else { venv.join("bin/py-spy") };
+    path.is_file().then_some(path)
+}
+
+/// Traceback printed by faulthandler, from its "Fatal Python error" header
+#[cfg(not(target_os = "windows"))]
+fn faulthandler_output(state: &State<DaemonState>) -> Option<String> {
+    let lines = state.sidecar_output.lock().unwrap();
+    let start = lines.iter().rposition(|line| line.line.contains("Fatal Python error"))?;
+    Some(lines.iter().skip(start).map(|line| line.line.as_str()).collect::<Vec<_>>().join("\n"))
+}
+
+/// Abort the daemon so faulthandler prints its threads' tracebacks on stderr
+#[cfg(not(target_os = "windows"))]
+fn abort_for_traceback(app_handle: &AppHandle, pid: u32) -> Result<DaemonStackDump, String> {
+    let state: State<DaemonState> = app_handle.state();
+    let has_faulthandler = state
+        .last_launch
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|launch| launch.env_keys.iter().any(|key| key == crate::daemon::FAULTHANDLER_ENV));
+    if !has_faulthandler {
+        return Err("The daemon was started without faulthandler, restart it to enable stack dumps".to_string());
+    }
+
+    let status = std::process::Command::new("kill")
+        .arg("-ABRT")
+        .arg(pid.to_string())
+        .status()
+        .map_err(|e| format!("Failed to signal PID {}: {}", pid, e))?;
+    if !status.success() {
+        return Err(format!("Failed to signal PID {}", pid));
+    }
+
+    let start = std::time::Instant::now();
+    while is_process_alive(pid) && start.elapsed() < ABORT_WAIT {
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    // Let the output monitor catch up with the last lines
+    std::thread::sleep(Duration::from_millis(300));
+
+    let output = faulthandler_output(&state).unwrap_or_else(|| "No traceback found in the daemon output".to_string());
+    let daemon_terminated = !is_process_alive(pid);
+    if daemon_terminated {
+        crate::daemon::kill_daemon(&state, &crate::settings::current(app_handle).shutdown);
+    }
+
+    Ok(DaemonStackDump { pid, method: "faulthandler".to_string(), output, daemon_terminated })
+}
+
+#[cfg(target_os = "windows")]
+fn abort_for_traceback(_app_handle: &AppHandle, _pid: u32) -> Result<DaemonStackDump, String> {
+    Err("Stack dumps need py-spy in the venv on Windows (pip install py-spy)".to_string())
+}
+
+/// Capture where a hung daemon is stuck
+///
+/// Uses py-spy when it is installed in the venv (the daemon keeps running).
+/// Otherwise, on macOS and Linux, aborts the daemon with SIGABRT: it was
+/// launched with PYTHONFAULTHANDLER=1, so Python prints the traceback of
+/// every thread before exiting. The traceback also ends up in the logs.
+#[tauri::command]
+pub async fn dump_daemon_stack(app_handle: AppHandle) -> Result<DaemonStackDump, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let state: State<DaemonState> = app_handle.state();
+        let trampoline_pid = state.process.lock().unwrap().as_ref().map(|child| child.pid()).ok_or("Daemon is not running")?;
+        let pid = child_pids(trampoline_pid)
+            .into_iter()
+            .next()
+            .ok_or("The daemon's Python process was not found")?;
+
+        if let Some(py_spy) = py_spy_path() {
+            let output = std::process::Command::new(&py_spy)
+                .args(["dump", "--pid", &pid.to_string()])
+                .output()
+                .map_err(|e| format!("Failed to run py-spy: {}", e))?;
+            if output.status.success() {
+                let output = String::from_utf8_lossy(&output.stdout).into_owned();
+                crate::daemon::add_log(&state, format!("🧵 Daemon stack (py-spy):\n{}", output.trim_end()));
+                return Ok(DaemonStackDump { pid, method: "py-spy".to_string(), output, daemon_terminated: false });
+            }
+            // Usually missing privileges (ptrace, macOS SIP), try faulthandler
+            println!("[tauri] ⚠️  py-spy failed: {}", String::from_utf8_lossy(&output.stderr).trim());
+        }
+
+        let dump = abort_for_traceback(&app_handle, pid)?;
+        println!("[tauri] 🧵 Daemon stack dumped with faulthandler (PID {})", pid);
+        Ok(dump)
+    })
+    .await
+    .map_err(|e| format!("Failed to execute stack dump task: {}", e))?
+}
@@ -1,61 +1,1002 @@
-// Helper to fix mjpython shebang on macOS
-// mjpython's shebang points to binaries/.venv but we're in target/debug/.venv
-#[cfg(target_os = "macos")]
-pub fn fix_mjpython_shebang() -> Result<(), String> {
-    use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// ============================================================================
+// VENV LOCATION
+// ============================================================================
+
+/// Find the folder holding uv, the cpython install and .venv
+/// 
+/// - Production macOS: App.app/Contents/Resources
+/// - Production Windows: next to the executable (MSI structure)
+/// - Production Linux: /usr/lib/<app> or /usr/share/<app> (.deb structure)
+/// - Dev mode: src-tauri/binaries/, then target/debug/, then current dir
+pub fn find_uv_folder() -> Result<PathBuf, String> {
     use std::env;
     
-    // Find the current working directory (where uv-trampoline runs)
-    let current_dir = env::current_dir().map_err(|e| format!("Failed to get current dir: {}", e))?;
-    let mjpython_path = current_dir.join(".venv/bin/mjpython");
+    let exe_path = env::current_exe()
+        .map_err(|e| format!("Failed to get current executable path: {}", e))?;
+    let exe_dir = exe_path
+        .parent()
+        .ok_or("Failed to get executable directory")?
+        .to_path_buf();
     
-    if !mjpython_path.exists() {
-        return Ok(()); // mjpython doesn't exist, skip
+    // Production mode on macOS: always Contents/Resources in the app bundle
+    if exe_path.to_string_lossy().contains(".app/Contents/MacOS") {
+        let contents_dir = exe_dir.parent().ok_or("Failed to find app bundle path")?;
+        return Ok(contents_dir.join("Resources"));
     }
     
-    // Read mjpython content
-    let content = fs::read_to_string(&mjpython_path)
-        .map_err(|e| format!("Failed to read mjpython: {}", e))?;
+    let current_dir = env::current_dir()
+        .map_err(|e| format!("Failed to get current directory: {}", e))?;
     
-    // Get the correct Python path (absolute path)
-    let python_path = current_dir.join(".venv/bin/python3");
-    let python_path_str = python_path.to_str()
-        .ok_or("Invalid Python path")?;
+    let mut candidates: Vec<PathBuf> = Vec::new();
     
-    // Check if shebang needs fixing (points to binaries/.venv)
-    if content.contains("binaries/.venv/bin/python3") {
-        // Fix the shebang on line 2
-        let lines: Vec<&str> = content.lines().collect();
-        if lines.len() >= 2 {
-            let mut new_lines: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
-            new_lines[1] = format!("'''exec' '{}' \"$0\" \"$@\"", python_path_str);
-            let new_content = new_lines.join("\n");
-            
-            fs::write(&mjpython_path, new_content)
-                .map_err(|e| format!("Failed to write mjpython: {}", e))?;
+    #[cfg(target_os = "windows")]
+    {
+        candidates.push(exe_dir.clone());
+        candidates.push(exe_dir.join("resources"));
+    }
+    
+    #[cfg(target_os = "linux")]
+    {
+        candidates.push(exe_dir.join("../lib/reachy-mini-control"));
+        candidates.push(exe_dir.join("../share/reachy-mini-control"));
+        candidates.push(PathBuf::from("/usr/lib/reachy-mini-control"));
+        candidates.push(PathBuf::from("/usr/share/reachy-mini-control"));
+    }
+    
+    // Dev mode: we may be running from src-tauri/ or from the project root
+    let is_in_src_tauri = current_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name == "src-tauri")
+        .unwrap_or(false);
+    
+    if is_in_src_tauri {
+        candidates.push(current_dir.join("binaries"));
+        candidates.push(current_dir.join("target/debug"));
+    } else {
+        candidates.push(current_dir.join("src-tauri/binaries"));
+        candidates.push(current_dir.join("src-tauri/target/debug"));
+    }
+    candidates.push(exe_dir);
+    
+    // Fallback: current dir (caller reports the missing .venv)
+    Ok(candidates
+        .into_iter()
+        .find(|dir| dir.join(".venv").exists() || uv_wrapper::find_cpython_folder(dir, &venv_dir(dir)).is_ok())
+        .unwrap_or(current_dir))
+}
+
+/// Find the .venv used by the daemon (may not exist yet on first run),
+/// following the relocation target once it was relocated
+pub fn find_venv_dir() -> Result<PathBuf, String> {
+    Ok(venv_dir(&find_uv_folder()?))
+}
+
+/// .venv of a uv folder, or the relocated one (apply_venv_location)
+pub fn venv_dir(uv_folder: &std::path::Path) -> PathBuf {
+    match VENV_LOCATION.lock().unwrap().as_ref() {
+        Some(location) => location.join(RELOCATED_VENV),
+        None => uv_wrapper::venv_dir(uv_folder),
+    }
+}
+
+// ============================================================================
+// VENV RELOCATION
+// ============================================================================
+
+/// Subfolder of the relocation target holding the venv
+const RELOCATED_VENV: &str = ".venv";
+/// Subfolder of the relocation target used as HF_HOME (datasets, models)
+const RELOCATED_HF_HOME: &str = "huggingface";
+
+/// Target of relocate_venv, None for the default location. Kept here and
+/// handed to the sidecars rather than set on the app's own environment.
+static VENV_LOCATION: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Dataset cache moved along with a venv relocated to `location`
+pub fn relocated_hf_home(location: &std::path::Path) -> std::path::PathBuf {
+    location.join(RELOCATED_HF_HOME)
+}
+
+/// Point the venv and dataset cache to their relocated directory, for this
+/// process (find_venv_dir) and the sidecars it spawns (trampoline_env)
+/// None goes back to the default locations.
+pub fn apply_venv_location(location: Option<&std::path::Path>) {
+    if let Some(location) = location {
+        println!("[tauri] 📦 Using venv relocated to {}", location.display());
+    }
+    *VENV_LOCATION.lock().unwrap() = location.map(std::path::Path::to_path_buf);
+}
+
+/// Environment every uv-trampoline run gets on top of the app's own:
+/// REACHY_MINI_VENV and HF_HOME of a relocated venv, and where to write the
+/// layout manifest
+pub fn trampoline_env() -> Vec<(&'static str, String)> {
+    let mut env = Vec::new();
+    if let Some(path) = LAYOUT_FILE.lock().unwrap().as_ref() {
+        env.push((uv_wrapper::LAYOUT_FILE_ENV, path.display().to_string()));
+    }
+    if let Some(location) = VENV_LOCATION.lock().unwrap().as_ref() {
+        env.push((uv_wrapper::VENV_ENV, location.join(RELOCATED_VENV).display().to_string()));
+        // A cache location chosen by the user wins
+        if std::env::var_os(crate::system::HUB_CACHE_ENV).is_none() {
+            env.push(("HF_HOME", relocated_hf_home(location).display().to_string()));
+        }
+    }
+    env
+}
+
+/// Copy a directory tree, keeping symlinks as symlinks
+fn copy_tree(src: &std::path::Path, dst: &std::path::Path) -> Result<(), String> {
+    let mut pending = vec![(src.to_path_buf(), dst.to_path_buf())];
+    
+    while let Some((from, to)) = pending.pop() {
+        std::fs::create_dir_all(&to).map_err(|e| format!("Failed to create {}: {}", to.display(), e))?;
+        
+        let entries = std::fs::read_dir(&from).map_err(|e| format!("Failed to read {}: {}", from.display(), e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+            let source = entry.path();
+            let target = to.join(entry.file_name());
+            let file_type = entry.file_type().map_err(|e| format!("Failed to read {}: {}", source.display(), e))?;
             
-            println!("[tauri] ✅ Fixed mjpython shebang to point to {}", python_path_str);
+            if file_type.is_symlink() {
+                copy_symlink(&source, &target)?;
+            } else if file_type.is_dir() {
+                pending.push((source, target));
+            } else {
+                std::fs::copy(&source, &target).map_err(|e| format!("Failed to copy {}: {}", source.display(), e))?;
+            }
         }
     }
     
     Ok(())
 }
 
+#[cfg(unix)]
+fn copy_symlink(link: &std::path::Path, target: &std::path::Path) -> Result<(), String> {
+    let destination = std::fs::read_link(link).map_err(|e| format!("Failed to read link {}: {}", link.display(), e))?;
+    std::os::unix::fs::symlink(destination, target).map_err(|e| format!("Failed to create link {}: {}", target.display(), e))
+}
+
+#[cfg(not(unix))]
+fn copy_symlink(link: &std::path::Path, target: &std::path::Path) -> Result<(), String> {
+    std::fs::copy(link, target)
+        .map(|_| ())
+        .map_err(|e| format!("Failed to copy {}: {}", link.display(), e))
+}
+
+/// Move a directory, copying it when it crosses volumes (rename can't)
+fn move_dir(src: &std::path::Path, dst: &std::path::Path) -> Result<(), String> {
+    if let Some(parent) = dst.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    if std::fs::rename(src, dst).is_ok() {
+        return Ok(());
+    }
+    
+    if let Err(e) = copy_tree(src, dst) {
+        let _ = std::fs::remove_dir_all(dst);
+        return Err(e);
+    }
+    std::fs::remove_dir_all(src).map_err(|e| format!("Copied but failed to remove {}: {}", src.display(), e))
+}
+
+/// Rewrite the old venv path in the venv's scripts (console-script shebangs, activate scripts)
+fn rewrite_venv_scripts(venv_dir: &std::path::Path, old_path: &str, new_path: &str) {
+    let scripts_dir = if cfg!(target_os = "windows") { venv_dir.join("Scripts") } else { venv_dir.join("bin") };
+    let entries = match std::fs::read_dir(&scripts_dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_file() || path.is_symlink() {
+            continue;
+        }
+        // Binaries (interpreter, .exe launchers) aren't valid UTF-8 and are left alone
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if content.contains(old_path) {
+                if let Err(e) = std::fs::write(&path, content.replace(old_path, new_path)) {
+                    println!("[tauri] ⚠️  Failed to rewrite {}: {}", path.display(), e);
+                }
+            }
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct VenvRelocation {
+    pub venv_dir: String,
+    /// New dataset cache, None when it was left in place (user-chosen cache location)
+    pub cache_dir: Option<String>,
+    pub moved_bytes: u64,
+}
+
+/// Move the venv and the dataset cache to another directory (e.g. a larger
+/// external volume), then point the app, the trampoline and the daemon to it
+#[tauri::command]
+pub async fn relocate_venv(app_handle: tauri::AppHandle, target_dir: String) -> Result<VenvRelocation, String> {
+    use tauri::Manager;
+    
+    {
+        let state = app_handle.state::<crate::daemon::DaemonState>();
+        if state.process.lock().unwrap().is_some() || *state.attached.lock().unwrap() {
+            return Err("Stop the daemon before relocating the venv".to_string());
+        }
+    }
+    
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let target = PathBuf::from(&target_dir);
+        if !target.is_absolute() {
+            return Err(format!("{} is not an absolute path", target_dir));
+        }
+        
+        let uv_folder = find_uv_folder()?;
+        let venv_src = venv_dir(&uv_folder);
+        let venv_dst = target.join(RELOCATED_VENV);
+        if !venv_src.exists() {
+            return Err(format!("No venv found at {}", venv_src.display()));
+        }
+        if venv_dst.exists() {
+            return Err(format!("{} already exists", venv_dst.display()));
+        }
+        
+        // The cache only follows when its location is managed by the app
+        let settings = crate::settings::current(&app_handle);
+        let managed_cache = settings.dataset_location.is_none()
+            && std::env::var_os(crate::system::HUB_CACHE_ENV).is_none()
+            && (std::env::var_os("HF_HOME").is_none() || settings.venv_location.is_some());
+        let cache_src = crate::system::dataset_cache_dir(&settings).filter(|dir| managed_cache && dir.exists());
+        let cache_dst = target.join(RELOCATED_HF_HOME).join("hub");
+        
+        // Validate the target before touching anything
+        std::fs::create_dir_all(&target).map_err(|e| format!("Failed to create {}: {}", target.display(), e))?;
+        let probe = target.join(".reachy-mini-write-test");
+        std::fs::write(&probe, b"")
+            .map_err(|e| format!("{} is not writable: {}", target.display(), e))?;
+        let _ = std::fs::remove_file(&probe);
+        
+        let moved_bytes = crate::system::dir_size(&venv_src) + cache_src.as_deref().map(crate::system::dir_size).unwrap_or(0);
+        let free_bytes = crate::system::free_space_bytes(&target)?;
+        if free_bytes < moved_bytes {
+            return Err(format!(
+                "Not enough space on {}: {} MB needed, {} MB free",
+                target.display(),
+                moved_bytes / (1024 * 1024),
+                free_bytes / (1024 * 1024)
+            ));
+        }
+        
+        println!("[tauri] 📦 Moving {} to {}", venv_src.display(), venv_dst.display());
+        move_dir(&venv_src, &venv_dst)?;
+        rewrite_venv_scripts(&venv_dst, &venv_src.display().to_string(), &venv_dst.display().to_string());
+        
+        if let Some(ref cache_src) = cache_src {
+            println!("[tauri] 📦 Moving {} to {}", cache_src.display(), cache_dst.display());
+            if let Err(e) = move_dir(cache_src, &cache_dst) {
+                // Not fatal, datasets are downloaded again when missing
+                println!("[tauri] ⚠️  Failed to move the dataset cache: {}", e);
+            }
+        }
+        
+        crate::settings::update(&app_handle, |settings| settings.venv_location = Some(target.clone()))?;
+        apply_venv_location(Some(&target));
+        
+        let cpython_folder = uv_wrapper::find_cpython_folder(&uv_folder, &venv_dst)?;
+        uv_wrapper::patching_pyvenv_cfg(&uv_folder, &venv_dst, &cpython_folder)?;
+        
+        // The moved binaries must be signed again at their new location
+        #[cfg(target_os = "macos")]
+        tauri::async_runtime::spawn(crate::signing::sign_python_binaries(app_handle.clone()));
+        
+        Ok(VenvRelocation {
+            venv_dir: venv_dst.display().to_string(),
+            cache_dir: cache_src.map(|_| cache_dst.display().to_string()),
+            moved_bytes,
+        })
+    })
+    .await
+    .map_err(|e| format!("Failed to execute relocation task: {}", e))?;
+    
+    result
+}
+
+// ============================================================================
+// TRAMPOLINE LAYOUT
+// ============================================================================
+
+const TRAMPOLINE_LAYOUT_FILE: &str = "trampoline-layout.json";
+
+/// Manifest path handed to the trampoline runs (REACHY_MINI_LAYOUT_FILE)
+static LAYOUT_FILE: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Folders the trampoline resolved on its last run (see uv_wrapper::Layout)
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct TrampolineLayout {
+    pub exe_folder: PathBuf,
+    /// Candidates searched for uv, in order
+    pub searched: Vec<PathBuf>,
+    pub uv_folder: Option<PathBuf>,
+    pub cpython_folder: Option<String>,
+    pub working_dir: Option<PathBuf>,
+    pub venv_dir: Option<PathBuf>,
+    /// Why resolution stopped, None when everything was found
+    pub error: Option<String>,
+}
+
+/// Ask every trampoline run to describe its layout in the app data dir
+pub fn apply_layout_manifest_location(app_handle: &tauri::AppHandle) {
+    use tauri::Manager;
+    
+    if let Ok(dir) = app_handle.path().app_data_dir() {
+        if std::fs::create_dir_all(&dir).is_ok() {
+            *LAYOUT_FILE.lock().unwrap() = Some(dir.join(TRAMPOLINE_LAYOUT_FILE));
+        }
+    }
+}
+
+/// Layout written by the last trampoline run, without running it
+pub fn last_trampoline_layout() -> Result<TrampolineLayout, String> {
+    let path = LAYOUT_FILE.lock().unwrap().clone().ok_or("Layout manifest location is unavailable")?;
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Trampoline layout not written yet ({}): {}", path.display(), e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid trampoline layout manifest: {}", e))
+}
+
+/// Run the trampoline once (`uv --version`) and report the folders it resolved
+#[tauri::command]
+pub async fn get_trampoline_layout(app_handle: tauri::AppHandle) -> Result<TrampolineLayout, String> {
+    use tauri_plugin_shell::ShellExt;
+    
+    crate::bundle::ensure_sidecar("uv-trampoline")?;
+    let output = app_handle
+        .shell()
+        .sidecar("uv-trampoline")
+        .map_err(|e| format!("Failed to find uv-trampoline: {}", e))?
+        .args(["--version"])
+        .envs(trampoline_env())
+        .output()
+        .await
+        .map_err(|e| format!("Failed to run uv-trampoline: {}", e))?;
+    
+    // The manifest is written even when resolution fails, the exit status doesn't matter
+    last_trampoline_layout().map_err(|e| {
+        format!("{} (trampoline stderr: {})", e, String::from_utf8_lossy(&output.stderr).trim())
+    })
+}
+
+// ============================================================================
+// PYVENV.CFG REPAIR
+// ============================================================================
+
+#[derive(serde::Serialize)]
+pub struct VenvRepatch {
+    pub uv_folder: String,
+    pub previous_home: Option<String>,
+    pub home: String,
+    /// False when pyvenv.cfg already pointed to the interpreter
+    pub changed: bool,
+}
+
+/// Re-point pyvenv.cfg's home to the bundled interpreter (e.g. after the app
+/// was moved or the cpython folder regenerated), the way uv-trampoline does on launch
+#[tauri::command]
+pub fn repatch_venv() -> Result<VenvRepatch, String> {
+    let uv_folder = find_uv_folder()?;
+    let venv_dir = venv_dir(&uv_folder);
+    repatch_pyvenv_cfg(&uv_folder, &venv_dir)
+}
+
+/// Re-point the pyvenv.cfg of `venv_dir`, wherever it lives, to an interpreter of the uv folder
+fn repatch_pyvenv_cfg(uv_folder: &std::path::Path, venv_dir: &std::path::Path) -> Result<VenvRepatch, String> {
+    let previous_home = uv_wrapper::read_pyvenv_home(venv_dir);
+    
+    let cpython_folder = uv_wrapper::find_cpython_folder(uv_folder, venv_dir)?;
+    let home = uv_wrapper::pyvenv_home(uv_folder, &cpython_folder);
+    if !home.exists() {
+        return Err(format!("Interpreter folder {} does not exist", home.display()));
+    }
+    
+    uv_wrapper::patching_pyvenv_cfg(uv_folder, venv_dir, &cpython_folder)?;
+    
+    let home = home.display().to_string();
+    let patched_home = uv_wrapper::read_pyvenv_home(venv_dir);
+    if patched_home.as_deref() != Some(home.as_str()) {
+        return Err(format!(
+            "pyvenv.cfg home is {:?} after patching, expected {}",
+            patched_home, home
+        ));
+    }
+    
+    let changed = previous_home.as_deref() != Some(home.as_str());
+    if changed {
+        println!("[tauri] 🔧 pyvenv.cfg home updated: {:?} -> {}", previous_home, home);
+    }
+    
+    Ok(VenvRepatch {
+        uv_folder: uv_folder.display().to_string(),
+        previous_home,
+        home,
+        changed,
+    })
+}
+
+// ============================================================================
+// VENV PYTHON EXECUTION
+// ============================================================================
+
+/// Venv interpreter, relative to the uv folder (resolved by uv-trampoline)
+#[cfg(not(target_os = "windows"))]
+pub const VENV_PYTHON: &str = ".venv/bin/python3";
+#[cfg(target_os = "windows")]
+pub const VENV_PYTHON: &str = ".venv/Scripts/python.exe";
+
+pub struct PythonOutput {
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl PythonOutput {
+    /// Value printed by the script on a line starting with `marker`
+    /// (uv-trampoline prints its own diagnostics on stdout too)
+    pub fn marked_value(&self, marker: &str) -> Option<&str> {
+        self.stdout
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(marker))
+            .map(|value| value.trim())
+    }
+}
+
+/// Run a Python snippet with the venv interpreter through uv-trampoline and wait for it
+pub async fn run_venv_python(app_handle: &tauri::AppHandle, script: &str, args: &[&str]) -> Result<PythonOutput, String> {
+    run_venv_interpreter(app_handle, VENV_PYTHON, script, args).await
+}
+
+/// Same as run_venv_python with another venv interpreter (e.g. mjpython)
+pub async fn run_venv_interpreter(
+    app_handle: &tauri::AppHandle,
+    interpreter: &str,
+    script: &str,
+    args: &[&str],
+) -> Result<PythonOutput, String> {
+    use tauri_plugin_shell::ShellExt;
+    
+    crate::bundle::ensure_sidecar("uv-trampoline")?;
+    
+    let mut command_args = vec![interpreter, "-c", script];
+    command_args.extend_from_slice(args);
+    
+    let output = app_handle
+        .shell()
+        .sidecar("uv-trampoline")
+        .map_err(|e| format!("Failed to find uv-trampoline: {}", e))?
+        .args(command_args)
+        .envs(trampoline_env())
+        .output()
+        .await
+        .map_err(|e| format!("Failed to run venv Python: {}", e))?;
+    
+    Ok(PythonOutput {
+        success: output.status.success(),
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+    })
+}
+
+// ============================================================================
+// MUJOCO SELF-TEST
+// ============================================================================
+
+/// Loads an empty model, which exercises the native library and its JIT setup
+const MUJOCO_TEST_SCRIPT: &str =
+    "import mujoco; mujoco.MjModel.from_xml_string('<mujoco/>'); print('MUJOCO:' + mujoco.__version__)";
+
+#[derive(serde::Serialize)]
+pub struct MujocoSelfTest {
+    pub success: bool,
+    pub mujoco_version: Option<String>,
+    /// Whether mjpython (needed by the sim daemon on macOS) runs, None elsewhere
+    pub mjpython_ok: Option<bool>,
+    /// Last line of the captured error output on failure
+    pub error: Option<String>,
+}
+
+/// Last meaningful line of a failed run's stderr (usually the exception)
+fn last_error_line(output: &PythonOutput) -> String {
+    output
+        .stderr
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("MuJoCo test failed")
+        .to_string()
+}
+
+/// Import MuJoCo and build a model through the trampoline, without starting the daemon
+pub async fn mujoco_self_test(app_handle: &tauri::AppHandle) -> MujocoSelfTest {
+    let output = match run_venv_python(app_handle, MUJOCO_TEST_SCRIPT, &[]).await {
+        Ok(output) => output,
+        Err(e) => return MujocoSelfTest { success: false, mujoco_version: None, mjpython_ok: None, error: Some(e) },
+    };
+    
+    let mujoco_version = output.marked_value("MUJOCO:").map(str::to_string);
+    if !output.success || mujoco_version.is_none() {
+        return MujocoSelfTest { success: false, mujoco_version, mjpython_ok: None, error: Some(last_error_line(&output)) };
+    }
+    
+    // The sim daemon runs under mjpython on macOS
+    let mut error = None;
+    let mjpython_ok = if cfg!(target_os = "macos") {
+        let _ = fix_mjpython_shebang();
+        let mjpython = run_venv_interpreter(app_handle, ".venv/bin/mjpython", MUJOCO_TEST_SCRIPT, &[]).await;
+        let ok = match mjpython {
+            Ok(output) if output.success && output.marked_value("MUJOCO:").is_some() => true,
+            Ok(output) => {
+                error = Some(format!("mjpython: {}", last_error_line(&output)));
+                false
+            }
+            Err(e) => {
+                error = Some(format!("mjpython: {}", e));
+                false
+            }
+        };
+        Some(ok)
+    } else {
+        None
+    };
+    
+    MujocoSelfTest {
+        success: mjpython_ok.unwrap_or(true),
+        mujoco_version,
+        mjpython_ok,
+        error,
+    }
+}
+
+#[tauri::command]
+pub async fn test_mujoco(app_handle: tauri::AppHandle) -> Result<MujocoSelfTest, String> {
+    let result = mujoco_self_test(&app_handle).await;
+    match &result.error {
+        None => println!("[tauri] ✅ MuJoCo self-test passed ({})", result.mujoco_version.as_deref().unwrap_or("?")),
+        Some(e) => println!("[tauri] ❌ MuJoCo self-test failed: {}", e),
+    }
+    Ok(result)
+}
+
+// ============================================================================
+// INTERPRETER ISOLATION
+// ============================================================================
+
+/// Reports where reachy_mini is imported from and what could leak other code in
+const ISOLATION_SCRIPT: &str = "import os, sys, reachy_mini; \
+print('MODULE:' + reachy_mini.__file__); \
+print('PREFIX:' + sys.prefix); \
+print('PYTHONPATH:' + os.environ.get('PYTHONPATH', ''))";
+
+#[derive(serde::Serialize)]
+pub struct InterpreterIsolation {
+    /// True when reachy_mini resolves inside the venv
+    pub isolated: bool,
+    pub venv_dir: String,
+    /// File reachy_mini was imported from, None when the import failed
+    pub module_path: Option<String>,
+    /// sys.prefix of the interpreter (the venv when it is activated properly)
+    pub sys_prefix: Option<String>,
+    /// PYTHONPATH seen by the interpreter, None when unset
+    pub pythonpath: Option<String>,
+    pub warning: Option<String>,
+}
+
+/// Whether `path` lies inside `dir`, comparing canonical paths when possible
+fn is_inside(path: &std::path::Path, dir: &std::path::Path) -> bool {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    path.starts_with(dir)
+}
+
+/// Import reachy_mini with the venv interpreter and check it isn't shadowed
+/// by a system-wide install (PYTHONPATH, user site-packages, conda)
+#[tauri::command]
+pub async fn check_interpreter_isolation(app_handle: tauri::AppHandle) -> Result<InterpreterIsolation, String> {
+    let venv_dir = find_venv_dir()?;
+    let output = run_venv_python(&app_handle, ISOLATION_SCRIPT, &[]).await?;
+    
+    let module_path = output.marked_value("MODULE:").map(str::to_string);
+    let sys_prefix = output.marked_value("PREFIX:").map(str::to_string);
+    let pythonpath = output.marked_value("PYTHONPATH:").filter(|p| !p.is_empty()).map(str::to_string);
+    
+    let isolated = module_path
+        .as_deref()
+        .is_some_and(|module| is_inside(std::path::Path::new(module), &venv_dir));
+    let warning = match module_path.as_deref() {
+        None => Some(match output.stderr.lines().rev().map(str::trim).find(|line| !line.is_empty()) {
+            Some(error) => format!("reachy_mini could not be imported: {}", error),
+            None => "reachy_mini could not be imported".to_string(),
+        }),
+        Some(module) if !isolated => Some(format!(
+            "reachy_mini is imported from {} instead of the venv{}",
+            module,
+            pythonpath.as_deref().map(|p| format!(" (PYTHONPATH={})", p)).unwrap_or_default()
+        )),
+        Some(_) => None,
+    };
+    
+    match &warning {
+        None => println!("[tauri] ✅ reachy_mini resolves inside the venv"),
+        Some(w) => println!("[tauri] ⚠️  {}", w),
+    }
+    
+    Ok(InterpreterIsolation {
+        isolated,
+        venv_dir: venv_dir.display().to_string(),
+        module_path,
+        sys_prefix,
+        pythonpath,
+        warning,
+    })
+}
+
+// ============================================================================
+// INSTALLED PACKAGES
+// ============================================================================
+
+/// Range of reachy-mini versions this app supports (max is exclusive)
+pub const MIN_REACHY_MINI_VERSION: &str = "1.0.0";
+pub const MAX_REACHY_MINI_VERSION: &str = "2.0.0";
+
+/// Find the site-packages folders of a venv
+/// (lib/python3.X/site-packages on Unix, Lib/site-packages on Windows)
+pub fn site_packages_dirs(venv_dir: &std::path::Path) -> Vec<PathBuf> {
+    let mut dirs = vec![venv_dir.join("Lib").join("site-packages")];
+    
+    if let Ok(entries) = std::fs::read_dir(venv_dir.join("lib")) {
+        for entry in entries.flatten() {
+            if entry.file_name().to_string_lossy().starts_with("python") {
+                dirs.push(entry.path().join("site-packages"));
+            }
+        }
+    }
+    
+    dirs.into_iter().filter(|dir| dir.is_dir()).collect()
+}
+
+/// Read the installed version of a package from its .dist-info folder
+/// (avoids spawning Python, which may not start if the venv is broken)
+pub fn installed_package_version(venv_dir: &std::path::Path, package: &str) -> Option<String> {
+    // Wheel metadata folders use the normalized name: reachy_mini-1.2.3.dist-info
+    let prefix = format!("{}-", package.replace('-', "_").to_lowercase());
+    
+    site_packages_dirs(venv_dir).into_iter().find_map(|dir| {
+        std::fs::read_dir(dir).ok()?.flatten().find_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            name.strip_suffix(".dist-info")
+                .and_then(|stem| stem.strip_prefix(&prefix))
+                .map(|version| version.to_string())
+        })
+    })
+}
+
+/// Parse the numeric release part of a version ("1.2.3rc1" -> [1, 2, 3])
+pub fn parse_version(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map_while(|part| {
+            let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits.parse().ok()
+        })
+        .collect()
+}
+
+/// Compare the release parts of two versions, missing parts counting as 0
+/// ("1.0" and "1.0.0" are equal)
+pub fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let (mut a, mut b) = (parse_version(a), parse_version(b));
+    let len = a.len().max(b.len());
+    a.resize(len, 0);
+    b.resize(len, 0);
+    a.cmp(&b)
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compatibility {
+    Compatible,
+    TooOld,
+    TooNew,
+    NotInstalled,
+}
+
+#[derive(serde::Serialize)]
+pub struct VersionCompatibility {
+    pub status: Compatibility,
+    pub installed_version: Option<String>,
+    pub min_version: String,
+    pub max_version: String,
+}
+
+/// Compare the installed reachy-mini version against the range this app supports
+#[tauri::command]
+pub fn check_version_compatibility() -> Result<VersionCompatibility, String> {
+    let venv_dir = find_venv_dir()?;
+    let installed_version = installed_package_version(&venv_dir, "reachy-mini");
+    
+    let status = match installed_version.as_deref() {
+        None => Compatibility::NotInstalled,
+        Some(version) => {
+            if compare_versions(version, MIN_REACHY_MINI_VERSION).is_lt() {
+                Compatibility::TooOld
+            } else if compare_versions(version, MAX_REACHY_MINI_VERSION).is_ge() {
+                Compatibility::TooNew
+            } else {
+                Compatibility::Compatible
+            }
+        }
+    };
+    
+    Ok(VersionCompatibility {
+        status,
+        installed_version,
+        min_version: MIN_REACHY_MINI_VERSION.to_string(),
+        max_version: MAX_REACHY_MINI_VERSION.to_string(),
+    })
+}
+
+// ============================================================================
+// MJPYTHON REPAIR
+// ============================================================================
+
+/// State of the venv's mjpython launcher script
+#[derive(serde::Serialize)]
+pub struct MjpythonRepair {
+    pub path: String,
+    pub exists: bool,
+    /// Interpreter the script ran before the repair
+    pub interpreter: Option<String>,
+    /// Whether that interpreter existed
+    pub valid: bool,
+    /// Set when the script was rewritten to the venv python
+    pub fixed_to: Option<String>,
+}
+
+/// Interpreter of a pip launcher script and the number of header lines naming it:
+/// `#!/path/python3`, or the `#!/bin/sh` + `'''exec' '/path/python3' "$0" "$@"`
+/// form pip uses for paths with spaces
+fn launcher_interpreter(content: &str) -> Option<(String, usize)> {
+    let mut lines = content.lines();
+    let shebang = lines.next()?.strip_prefix("#!")?.trim();
+    if shebang != "/bin/sh" {
+        return Some((shebang.to_string(), 1));
+    }
+    let exec = lines.next()?.strip_prefix("'''exec' '")?;
+    let interpreter = &exec[..exec.find('\'')?];
+    // Closing line of the sh block: ' '''
+    let header_lines = if lines.next().is_some_and(|line| line.trim() == "' '''") { 3 } else { 2 };
+    Some((interpreter.to_string(), header_lines))
+}
+
+/// Launcher header running `python`, in the same forms pip writes
+fn launcher_header(python: &str) -> String {
+    if python.contains(' ') {
+        format!("#!/bin/sh\n'''exec' '{}' \"$0\" \"$@\"\n' '''", python)
+    } else {
+        format!("#!{}", python)
+    }
+}
+
+/// Inspect mjpython without changing it
+pub fn inspect_mjpython() -> Result<MjpythonRepair, String> {
+    let mjpython = find_venv_dir()?.join("bin/mjpython");
+    let mut repair = MjpythonRepair {
+        path: mjpython.display().to_string(),
+        exists: mjpython.is_file(),
+        interpreter: None,
+        valid: false,
+        fixed_to: None,
+    };
+    if !repair.exists {
+        return Ok(repair);
+    }
+
+    let content = std::fs::read_to_string(&mjpython).map_err(|e| format!("Failed to read mjpython: {}", e))?;
+    repair.interpreter = launcher_interpreter(&content).map(|(interpreter, _)| interpreter);
+    repair.valid = repair.interpreter.as_deref().is_some_and(|path| std::path::Path::new(path).is_file());
+    Ok(repair)
+}
+
+/// Check that mjpython runs an interpreter that exists, and point it back at
+/// the venv python when it doesn't (e.g. after the app or the venv was moved)
+fn repair_mjpython_script() -> Result<MjpythonRepair, String> {
+    let mut repair = inspect_mjpython()?;
+    if !repair.exists || repair.valid {
+        return Ok(repair);
+    }
+
+    let content = std::fs::read_to_string(&repair.path).map_err(|e| format!("Failed to read mjpython: {}", e))?;
+    let (_, header_lines) = launcher_interpreter(&content)
+        .ok_or_else(|| format!("{} is not a Python launcher script", repair.path))?;
+    let python = find_venv_dir()?.join("bin/python3");
+    if !python.exists() {
+        return Err(format!("mjpython can't be repaired, {} is missing", python.display()));
+    }
+    let python = python.display().to_string();
+    let body: Vec<&str> = content.lines().skip(header_lines).collect();
+    let repaired = format!("{}\n{}\n", launcher_header(&python), body.join("\n"));
+    std::fs::write(&repair.path, repaired).map_err(|e| format!("Failed to write mjpython: {}", e))?;
+
+    println!("[tauri] ✅ Fixed mjpython to run {} (was {})", python, repair.interpreter.as_deref().unwrap_or(""));
+    repair.fixed_to = Some(python);
+    Ok(repair)
+}
+
+/// Repair mjpython before the sim daemon runs it (macOS only, where it is used)
+#[cfg(target_os = "macos")]
+pub fn fix_mjpython_shebang() -> Result<(), String> {
+    repair_mjpython_script().map(|_| ())
+}
+
 #[cfg(not(target_os = "macos"))]
 pub fn fix_mjpython_shebang() -> Result<(), String> {
     Ok(()) // No-op on non-macOS
 }
 
-// Helper to build daemon arguments
+/// Check that the venv's mjpython points at an existing interpreter and
+/// rewrite it to the venv python if not, returning what was found and fixed
+/// (Windows installs an .exe launcher instead, nothing to repair there)
+#[tauri::command]
+pub fn repair_mjpython() -> Result<MjpythonRepair, String> {
+    if cfg!(target_os = "windows") {
+        return Err("mjpython is only a launcher script on macOS and Linux".to_string());
+    }
+    repair_mjpython_script()
+}
+
+// ============================================================================
+// VENV PERMISSIONS
+// ============================================================================
+
+#[derive(serde::Serialize)]
+pub struct PermissionRepair {
+    pub bin_dir: String,
+    /// Executables that had lost their execute bit
+    pub fixed: Vec<String>,
+    /// Executables whose permissions could not be changed, with the reason
+    pub failed: Vec<String>,
+}
+
+/// Interpreters, native launchers and scripts with a shebang
+/// (activate scripts are sourced, not executed, and are left alone)
+#[cfg(unix)]
+fn is_venv_executable(path: &std::path::Path) -> bool {
+    use std::io::Read;
+    
+    let name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+    if name.starts_with("python") || name == "mjpython" {
+        return true;
+    }
+    
+    let mut magic = [0u8; 4];
+    let read = std::fs::File::open(path).and_then(|mut file| file.read_exact(&mut magic));
+    if read.is_err() {
+        return false;
+    }
+    
+    magic.starts_with(b"#!")
+        || magic == [0x7f, b'E', b'L', b'F']
+        || matches!(u32::from_be_bytes(magic), 0xfeedfacf | 0xcffaedfe | 0xcafebabe | 0xbebafeca)
+}
+
+/// Set the execute bit where it is missing, returning the fixed and failed files
+#[cfg(unix)]
+fn repair_executable_bits(bin_dir: &std::path::Path) -> Result<(Vec<String>, Vec<String>), String> {
+    use std::os::unix::fs::PermissionsExt;
+    
+    let mut fixed = Vec::new();
+    let mut failed = Vec::new();
+    let entries = std::fs::read_dir(bin_dir).map_err(|e| format!("Failed to read {}: {}", bin_dir.display(), e))?;
+    let mut seen = std::collections::HashSet::new();
+    
+    for entry in entries.flatten() {
+        // Follow symlinks: python3 points to the interpreter, which is the file to fix
+        let path = match std::fs::canonicalize(entry.path()) {
+            Ok(path) => path,
+            Err(_) => continue,
+        };
+        if !path.is_file() || !seen.insert(path.clone()) || !is_venv_executable(&path) {
+            continue;
+        }
+        
+        let mut permissions = match std::fs::metadata(&path) {
+            Ok(metadata) => metadata.permissions(),
+            Err(_) => continue,
+        };
+        if permissions.mode() & 0o100 != 0 {
+            continue;
+        }
+        
+        // Execute for everyone who can read it
+        let mode = permissions.mode();
+        permissions.set_mode(mode | ((mode & 0o444) >> 2));
+        match std::fs::set_permissions(&path, permissions) {
+            Ok(()) => fixed.push(path.display().to_string()),
+            Err(e) => failed.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+    
+    Ok((fixed, failed))
+}
+
+/// Windows has no execute bit
+#[cfg(not(unix))]
+fn repair_executable_bits(_bin_dir: &std::path::Path) -> Result<(Vec<String>, Vec<String>), String> {
+    Ok((Vec::new(), Vec::new()))
+}
+
+/// Restore the execute bit on the venv interpreter and entry points, which
+/// copies and restores don't always preserve ("permission denied" at spawn)
+/// Also fixes the mjpython shebang on macOS. Nothing to do on Windows.
+#[tauri::command]
+pub fn repair_venv_permissions() -> Result<PermissionRepair, String> {
+    let bin_dir = find_venv_dir()?.join(if cfg!(target_os = "windows") { "Scripts" } else { "bin" });
+    let (fixed, failed) = repair_executable_bits(&bin_dir)?;
+    let repair = PermissionRepair {
+        bin_dir: bin_dir.display().to_string(),
+        fixed,
+        failed,
+    };
+    
+    fix_mjpython_shebang()?;
+    
+    if repair.fixed.is_empty() && repair.failed.is_empty() {
+        println!("[tauri] ✅ Venv permissions are correct");
+    } else {
+        println!("[tauri] 🔧 Venv permissions: {} fixed, {} failed", repair.fixed.len(), repair.failed.len());
+    }
+    
+    Ok(repair)
+}
+
+/// Daemon arguments for start_daemon, with the mjpython shebang fixed first
+/// when the daemon runs under mjpython
+pub fn build_daemon_args(
+    sim_mode: bool,
+    safe_mode: bool,
+    serial_port: Option<&str>,
+    settings: &crate::settings::AppSettings,
+) -> Result<Vec<String>, String> {
+    let args = daemon_args(sim_mode, safe_mode, serial_port, settings);
+    if args[0].ends_with("mjpython") {
+        fix_mjpython_shebang()?;
+    }
+    Ok(args)
+}
+
+// Helper to build daemon arguments, without touching the venv (previews and dry runs)
 // On macOS with simulation mode, we need to use mjpython (required by MuJoCo)
 // IMPORTANT: Use .venv/bin/python3 directly instead of "uv run python" to ensure
 // we use the venv Python with all installed packages, not the cpython bundle
-pub fn build_daemon_args(sim_mode: bool) -> Result<Vec<String>, String> {
+// Host, port, engine, log level and capture devices come from the settings
+pub fn daemon_args(
+    sim_mode: bool,
+    safe_mode: bool,
+    serial_port: Option<&str>,
+    settings: &crate::settings::AppSettings,
+) -> Vec<String> {
+    let api_port = crate::daemon::DaemonEndpoint::from_settings(settings).port;
+    let log_level = settings.daemon_log_level;
+    
+    // Safe mode simulates with the mockup backend, which doesn't need MuJoCo
+    let mujoco_sim = sim_mode && !safe_mode;
+    
     // Use Python from .venv directly (not via uv run)
     // This ensures we use the venv with all installed packages
-    let python_cmd = if sim_mode && cfg!(target_os = "macos") {
-        // Fix mjpython shebang before using it
-        fix_mjpython_shebang()?;
+    let python_cmd = if mujoco_sim && cfg!(target_os = "macos") {
         ".venv/bin/mjpython"
     } else {
         ".venv/bin/python3"
@@ -66,14 +1007,133 @@ pub fn build_daemon_args(sim_mode: bool) -> Result<Vec<String>, String> {
         "-m".to_string(),
         "reachy_mini.daemon.app.main".to_string(),
         "--kinematics-engine".to_string(),
-        "Placo".to_string(),
+        settings.kinematics_engine.flag().to_string(),
         "--desktop-app-daemon".to_string(),
     ];
     
-    if sim_mode {
+    if mujoco_sim {
         args.push("--sim".to_string());
+    } else if sim_mode {
+        args.push("--mockup-sim".to_string());
+    } else if let Some(port) = serial_port {
+        // Bind to a specific robot when several are connected
+        args.push("--serialport".to_string());
+        args.push(port.to_string());
     }
     
-    Ok(args)
+    // Localhost-only unless the user chose to expose the API on the network
+    if let Some(host) = settings.daemon_host.as_deref() {
+        args.push("--no-localhost-only".to_string());
+        if host != "0.0.0.0" {
+            args.push("--fastapi-host".to_string());
+            args.push(host.to_string());
+        }
+    }
+    
+    if api_port != crate::daemon::DAEMON_PORT {
+        args.push("--fastapi-port".to_string());
+        args.push(api_port.to_string());
+    }
+    
+    if log_level != crate::settings::DaemonLogLevel::default() {
+        args.push("--log-level".to_string());
+        args.push(log_level.flag().to_string());
+    }
+    
+    // Safe mode: most minimal daemon that can run, to isolate a failing subsystem
+    // (no wake-up motion, no camera/audio pipeline, no dataset preload)
+    if safe_mode {
+        args.push("--no-wake-up-on-start".to_string());
+        args.push("--no-media".to_string());
+        args.push("--no-preload-datasets".to_string());
+    } else {
+        if let Some(ref camera) = settings.camera_device {
+            args.push("--camera-device".to_string());
+            args.push(camera.clone());
+        }
+        if let Some(ref microphone) = settings.microphone_device {
+            args.push("--audio-device".to_string());
+            args.push(microphone.clone());
+        }
+    }
+    
+    args
+}
+
+// ============================================================================
+// COMMAND PREVIEW
+// ============================================================================
+
+#[derive(serde::Serialize)]
+pub struct DaemonCommandPreview {
+    /// Absolute path of the interpreter the trampoline will run
+    pub interpreter: String,
+    /// True when MuJoCo requires mjpython instead of python3 (macOS simulation)
+    pub uses_mjpython: bool,
+    /// Arguments handed to uv-trampoline, exactly as start_daemon builds them
+    pub args: Vec<String>,
+    /// Directory the daemon runs from (the uv folder)
+    pub working_dir: String,
+    /// Ready-to-paste command line with the interpreter resolved
+    pub command_line: String,
+}
+
+/// Quote an argument for a shell when it contains spaces or quotes
+fn shell_quote(arg: &str) -> String {
+    if !arg.is_empty() && !arg.chars().any(|c| c.is_whitespace() || c == '\'' || c == '"') {
+        return arg.to_string();
+    }
+    if cfg!(target_os = "windows") {
+        format!("\"{}\"", arg.replace('"', "\\\""))
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Show the command start_daemon would run with these options, without spawning anything
+/// In hardware mode the robot is resolved like start_daemon does; when none is
+/// found the daemon is left to detect it (no --serialport).
+#[tauri::command]
+pub async fn preview_daemon_command(
+    app_handle: tauri::AppHandle,
+    sim_mode: Option<bool>,
+    robot: Option<String>,
+    safe_mode: Option<bool>,
+) -> Result<DaemonCommandPreview, String> {
+    let sim_mode = sim_mode.unwrap_or(false);
+    let safe_mode = safe_mode.unwrap_or(false);
+    let settings = crate::settings::current(&app_handle);
+
+    let serial_port = if sim_mode {
+        None
+    } else {
+        let last_serial = settings.last_robot_serial.clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            crate::usb::select_robot(robot.as_deref(), last_serial.as_deref())
+                .ok()
+                .flatten()
+                .map(|robot| robot.port_name)
+        })
+        .await
+        .map_err(|e| format!("Failed to resolve robot: {}", e))?
+    };
+
+    let args = daemon_args(sim_mode, safe_mode, serial_port.as_deref(), &settings);
+
+    let uv_folder = find_uv_folder()?;
+    let interpreter = uv_wrapper::resolve_venv_path(&uv_folder, &venv_dir(&uv_folder), &args[0]).display().to_string();
+    let command_line = std::iter::once(interpreter.as_str())
+        .chain(args[1..].iter().map(String::as_str))
+        .map(shell_quote)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Ok(DaemonCommandPreview {
+        uses_mjpython: args[0].ends_with("mjpython"),
+        interpreter,
+        args,
+        working_dir: uv_folder.display().to_string(),
+        command_line,
+    })
 }
 
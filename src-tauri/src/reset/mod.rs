@@ -0,0 +1,123 @@
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, State};
+use crate::daemon::{add_log, kill_daemon, wait_for_daemon_shutdown, DaemonState};
+
+// ============================================================================
+// FACTORY RESET
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct FactoryReset {
+    /// Paths deleted
+    pub removed: Vec<String>,
+    /// Paths that could not be deleted, with the reason
+    pub failed: Vec<String>,
+    /// True when a new venv is being created (outcome in "venv-setup-finished")
+    pub resetup_started: bool,
+}
+
+/// Delete a file or directory tree, recording the outcome
+fn remove_path(path: &Path, report: &mut FactoryReset) {
+    if !path.exists() {
+        return;
+    }
+
+    let result = if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    };
+
+    match result {
+        Ok(()) => report.removed.push(path.display().to_string()),
+        Err(e) => report.failed.push(format!("{}: {}", path.display(), e)),
+    }
+}
+
+/// Put the app back to a fresh install: stops the daemon and deletes the venv,
+/// app caches, settings, logs, install id and UI state (window layout and
+/// preferences stored by the webview). Secrets in the OS keychain are kept, as
+/// is the Hugging Face cache when it is shared with other tools
+/// (see clear_dataset_cache).
+///
+/// `confirm` must be true. With `resetup` (default true) a new venv is created
+/// right away, otherwise the daemon can't start until one is.
+#[tauri::command]
+pub async fn factory_reset(app_handle: AppHandle, confirm: bool, resetup: Option<bool>) -> Result<FactoryReset, String> {
+    if !confirm {
+        return Err("Factory reset deletes all app data, confirm to proceed".to_string());
+    }
+    let resetup = resetup.unwrap_or(true);
+
+    let app_handle_clone = app_handle.clone();
+    let mut report = tauri::async_runtime::spawn_blocking(move || reset_app_data(&app_handle_clone))
+        .await
+        .map_err(|e| format!("Failed to execute reset task: {}", e))??;
+
+    // Window geometry and UI preferences live in the webview storage
+    for (label, webview) in app_handle.webview_windows() {
+        if let Err(e) = webview.clear_all_browsing_data() {
+            report.failed.push(format!("webview data of {}: {}", label, e));
+        }
+    }
+
+    if resetup {
+        crate::install::spawn_venv_setup(&app_handle)?;
+        report.resetup_started = true;
+    }
+
+    println!(
+        "[tauri] 🧨 Factory reset: {} removed, {} failed",
+        report.removed.len(),
+        report.failed.len()
+    );
+
+    Ok(report)
+}
+
+fn reset_app_data(app_handle: &AppHandle) -> Result<FactoryReset, String> {
+    let state: State<DaemonState> = app_handle.state();
+    let settings = crate::settings::current(app_handle);
+
+    // 1. Stop the daemon, its venv is about to disappear
+    let pid = kill_daemon(&state, &settings.shutdown);
+    let endpoint = state.endpoint.lock().unwrap().clone();
+    wait_for_daemon_shutdown(&endpoint, pid, std::time::Duration::from_secs(5))?;
+
+    let mut report = FactoryReset {
+        removed: Vec::new(),
+        failed: Vec::new(),
+        resetup_started: false,
+    };
+
+    // 2. The venv, and the dataset cache that moved along with a relocated venv
+    match crate::python::find_venv_dir() {
+        Ok(venv_dir) => remove_path(&venv_dir, &mut report),
+        Err(e) => report.failed.push(format!("venv: {}", e)),
+    }
+    if let Some(location) = settings.venv_location.as_deref() {
+        remove_path(&crate::python::relocated_hf_home(location), &mut report);
+    }
+    crate::python::apply_venv_location(None);
+
+    // 3. Settings, back to the defaults in memory too
+    let settings_state = app_handle.state::<crate::settings::SettingsState>();
+    if let Some(path) = settings_state.path.as_deref() {
+        remove_path(path, &mut report);
+    }
+    *settings_state.settings.lock().unwrap() = crate::settings::AppSettings::default();
+
+    // 4. App data (install id, update history), caches and logs
+    let path = app_handle.path();
+    let dirs: [tauri::Result<PathBuf>; 3] = [path.app_data_dir(), path.app_cache_dir(), path.app_log_dir()];
+    for dir in dirs.into_iter().flatten() {
+        remove_path(&dir, &mut report);
+    }
+    state.logs.lock().unwrap().clear();
+    state.sidecar_output.lock().unwrap().clear();
+
+    add_log(&state, "🧨 Factory reset, app data deleted".to_string());
+
+    Ok(report)
+}
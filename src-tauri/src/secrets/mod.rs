@@ -0,0 +1,177 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Keychain / credential store entry holding the Hugging Face token
+const SERVICE: &str = "com.pollen-robotics.reachy-mini";
+const HF_TOKEN_ACCOUNT: &str = "hf_token";
+
+/// Environment variable read by huggingface_hub in the daemon
+pub const HF_TOKEN_ENV: &str = "HF_TOKEN";
+
+// ============================================================================
+// CREDENTIAL STORE
+// ============================================================================
+// Secrets are always passed through stdin, never as arguments (visible in ps)
+
+/// Run a command with `input` on stdin, returning its stdout
+fn run_with_stdin(command: &mut Command, input: &str) -> Result<String, String> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to access the credential store: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input.as_bytes())
+            .map_err(|e| format!("Failed to access the credential store: {}", e))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to access the credential store: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Credential store error: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn store_secret(account: &str, secret: &str) -> Result<(), String> {
+    // `security -i` reads its commands from stdin
+    let command = format!("add-generic-password -U -s \"{}\" -a \"{}\" -w \"{}\"\n", SERVICE, account, secret);
+    run_with_stdin(Command::new("security").arg("-i"), &command).map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+fn load_secret(account: &str) -> Option<String> {
+    Command::new("security")
+        .args(["find-generic-password", "-s", SERVICE, "-a", account, "-w"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|secret| !secret.is_empty())
+}
+
+#[cfg(target_os = "macos")]
+fn delete_secret(account: &str) -> Result<(), String> {
+    // Exit status is non-zero when the item doesn't exist, which is fine
+    Command::new("security")
+        .args(["delete-generic-password", "-s", SERVICE, "-a", account])
+        .output()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to access the keychain: {}", e))
+}
+
+#[cfg(target_os = "windows")]
+const PASSWORD_VAULT: &str = "[void][Windows.Security.Credentials.PasswordVault,Windows.Security.Credentials,ContentType=WindowsRuntime]; $vault = New-Object Windows.Security.Credentials.PasswordVault;";
+
+#[cfg(target_os = "windows")]
+fn store_secret(account: &str, secret: &str) -> Result<(), String> {
+    let script = format!(
+        "{} $secret = [Console]::In.ReadLine(); $vault.Add((New-Object Windows.Security.Credentials.PasswordCredential('{}', '{}', $secret)))",
+        PASSWORD_VAULT, SERVICE, account
+    );
+    run_with_stdin(Command::new("powershell").args(["-NoProfile", "-Command", &script]), &format!("{}\n", secret)).map(|_| ())
+}
+
+#[cfg(target_os = "windows")]
+fn load_secret(account: &str) -> Option<String> {
+    let script = format!(
+        "{} $c = $vault.Retrieve('{}', '{}'); $c.RetrievePassword(); $c.Password",
+        PASSWORD_VAULT, SERVICE, account
+    );
+    Command::new("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|secret| !secret.is_empty())
+}
+
+#[cfg(target_os = "windows")]
+fn delete_secret(account: &str) -> Result<(), String> {
+    let script = format!(
+        "{} try {{ $vault.Remove($vault.Retrieve('{}', '{}')) }} catch {{ }}",
+        PASSWORD_VAULT, SERVICE, account
+    );
+    Command::new("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .output()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to access the credential store: {}", e))
+}
+
+/// Linux: Secret Service (GNOME Keyring, KWallet) through secret-tool
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn store_secret(account: &str, secret: &str) -> Result<(), String> {
+    run_with_stdin(
+        Command::new("secret-tool").args(["store", "--label=Reachy Mini", "service", SERVICE, "account", account]),
+        secret,
+    )
+    .map(|_| ())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn load_secret(account: &str) -> Option<String> {
+    Command::new("secret-tool")
+        .args(["lookup", "service", SERVICE, "account", account])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|secret| !secret.is_empty())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn delete_secret(account: &str) -> Result<(), String> {
+    Command::new("secret-tool")
+        .args(["clear", "service", SERVICE, "account", account])
+        .output()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to access the Secret Service: {}", e))
+}
+
+// ============================================================================
+// HUGGING FACE TOKEN
+// ============================================================================
+
+/// Stored Hugging Face token, injected into the daemon environment at spawn
+pub fn hf_token() -> Option<String> {
+    load_secret(HF_TOKEN_ACCOUNT)
+}
+
+#[tauri::command]
+pub fn set_hf_token(token: String) -> Result<(), String> {
+    let token = token.trim();
+
+    // Hub tokens are plain ASCII ("hf_..."), this also keeps them safe to quote
+    if token.is_empty() || !token.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err("Invalid Hugging Face token".to_string());
+    }
+
+    store_secret(HF_TOKEN_ACCOUNT, token)?;
+    println!("[tauri] 🔑 Hugging Face token saved to the credential store");
+    Ok(())
+}
+
+#[tauri::command]
+pub fn clear_hf_token() -> Result<(), String> {
+    delete_secret(HF_TOKEN_ACCOUNT)?;
+    println!("[tauri] 🔑 Hugging Face token removed from the credential store");
+    Ok(())
+}
+
+/// Whether a token is stored (the value itself never leaves the backend)
+#[tauri::command]
+pub fn has_hf_token() -> bool {
+    hf_token().is_some()
+}
@@ -0,0 +1,786 @@
+use std::path::PathBuf;
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const SETTINGS_FILE: &str = "settings.json";
+
+// ============================================================================
+// SETTINGS TYPES
+// ============================================================================
+
+/// Delays used when stopping the daemon (SIGTERM, then SIGKILL)
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShutdownConfig {
+    /// Maximum time given to the daemon after SIGTERM before it is force-killed.
+    /// A longer wait lets the daemon run its shutdown handlers (e.g. park the
+    /// robot in a safe pose). Cleanup moves on as soon as the port is released.
+    pub term_wait_ms: u64,
+    /// Pause after the force-kill so the OS releases the port
+    pub post_kill_wait_ms: u64,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        #[cfg(target_os = "macos")]
+        return Self { term_wait_ms: 3000, post_kill_wait_ms: 300 };
+
+        // Linux hosts include Raspberry-Pi-class machines, give them more time
+        #[cfg(target_os = "linux")]
+        return Self { term_wait_ms: 5000, post_kill_wait_ms: 500 };
+
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+        return Self { term_wait_ms: 3000, post_kill_wait_ms: 500 };
+    }
+}
+
+/// How long and how often to poll the daemon API after launch
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReadinessConfig {
+    /// Give up waiting for the daemon API after this long (hardware mode)
+    pub timeout_ms: u64,
+    /// Same for sim mode, whose first start loads MuJoCo models and assets
+    pub sim_timeout_ms: u64,
+    /// Delay between the first probes, grown by 1.5x after each attempt
+    pub initial_interval_ms: u64,
+    pub max_interval_ms: u64,
+    /// Relaunches when a fresh daemon exits before its API came up
+    /// (port cleanup races, slow venv warm-up on a cold start)
+    pub start_retries: u32,
+}
+
+impl Default for ReadinessConfig {
+    fn default() -> Self {
+        // Generous defaults: cold caches on Raspberry-Pi-class machines take minutes
+        Self {
+            timeout_ms: 120_000,
+            sim_timeout_ms: 300_000,
+            initial_interval_ms: 250,
+            max_interval_ms: 5_000,
+            start_retries: 1,
+        }
+    }
+}
+
+/// Thresholds for long-running installs (MuJoCo, upgrades)
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InstallTimeouts {
+    /// Emit "install-slow" once an install runs longer than this
+    pub slow_after_secs: u64,
+    /// Kill the install once it runs longer than this
+    pub cancel_after_secs: u64,
+}
+
+impl Default for InstallTimeouts {
+    fn default() -> Self {
+        // Large wheels on poor connections legitimately take many minutes
+        Self {
+            slow_after_secs: 120,
+            cancel_after_secs: 1800,
+        }
+    }
+}
+
+/// codesign limits when re-signing the venv; timestamping contacts Apple's
+/// timestamp server, which can stall on a bad network
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SigningConfig {
+    /// Kill a codesign invocation running longer than this
+    pub timeout_ms: u64,
+    /// Extra attempts when timestamping fails or times out
+    pub timestamp_retries: u32,
+    /// Once the retries are exhausted, sign without a timestamp (offline)
+    /// instead of leaving the binary unsigned
+    pub allow_untimestamped: bool,
+}
+
+impl Default for SigningConfig {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 30_000,
+            timestamp_retries: 2,
+            allow_untimestamped: true,
+        }
+    }
+}
+
+/// When to emit "daemon-error-spike" (unattended sessions)
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ErrorAlertConfig {
+    pub enabled: bool,
+    /// Error lines within the window that trigger the alert
+    pub threshold: u32,
+    pub window_secs: u64,
+}
+
+impl Default for ErrorAlertConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold: 10,
+            window_secs: 60,
+        }
+    }
+}
+
+/// Relaunching a daemon that crashed after coming up
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoRestartConfig {
+    /// Off by default: a crash leaves the daemon down until the user starts it
+    pub enabled: bool,
+    /// Crashes in a row before giving up (the count starts over after a minute of uptime)
+    pub max_attempts: u32,
+    /// Cooldown before the first restart, doubled after each crash
+    pub initial_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl Default for AutoRestartConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_attempts: 5,
+            initial_delay_ms: 2_000,
+            max_delay_ms: 60_000,
+        }
+    }
+}
+
+/// Parallelism of uv installs, None leaves uv's default (scaled to the machine)
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UvConcurrency {
+    /// UV_CONCURRENT_DOWNLOADS
+    pub downloads: Option<u32>,
+    /// UV_CONCURRENT_INSTALLS
+    pub installs: Option<u32>,
+}
+
+impl UvConcurrency {
+    /// Environment variables passed to uv-trampoline for installs
+    pub fn env(&self) -> Vec<(&'static str, String)> {
+        [
+            (uv_wrapper::CONCURRENT_DOWNLOADS_ENV, self.downloads),
+            (uv_wrapper::CONCURRENT_INSTALLS_ENV, self.installs),
+        ]
+        .into_iter()
+        .filter_map(|(env, value)| value.map(|value| (env, value.to_string())))
+        .collect()
+    }
+
+    /// "downloads=4, installs=default" for the logs
+    pub fn describe(&self) -> String {
+        let show = |value: Option<u32>| value.map_or_else(|| "default".to_string(), |value| value.to_string());
+        format!("downloads={}, installs={}", show(self.downloads), show(self.installs))
+    }
+}
+
+/// Kinematics backend of the daemon (--kinematics-engine)
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum KinematicsEngine {
+    #[default]
+    Placo,
+    NN,
+    Analytical,
+}
+
+impl KinematicsEngine {
+    /// Value passed to the daemon's --kinematics-engine flag
+    pub fn flag(&self) -> &'static str {
+        match self {
+            KinematicsEngine::Placo => "Placo",
+            KinematicsEngine::NN => "NN",
+            KinematicsEngine::Analytical => "Analytical",
+        }
+    }
+    
+    /// reachy-mini extra providing the engine's dependencies, if any
+    pub fn extra(&self) -> Option<&'static str> {
+        match self {
+            KinematicsEngine::Placo => Some("placo_kinematics"),
+            KinematicsEngine::NN => Some("nn_kinematics"),
+            KinematicsEngine::Analytical => None,
+        }
+    }
+}
+
+/// Daemon log level (--log-level)
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DaemonLogLevel {
+    Debug,
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+impl DaemonLogLevel {
+    /// Value passed to the daemon's --log-level flag and log-level endpoint
+    pub fn flag(&self) -> &'static str {
+        match self {
+            DaemonLogLevel::Debug => "DEBUG",
+            DaemonLogLevel::Info => "INFO",
+            DaemonLogLevel::Warning => "WARNING",
+            DaemonLogLevel::Error => "ERROR",
+        }
+    }
+}
+
+/// Whether app and daemon logs are written to daemon.log
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogRetention {
+    /// Kept across runs (rotated past 5 MB)
+    Persistent,
+    /// Written while the app runs, deleted on exit and at the next launch
+    #[default]
+    SessionOnly,
+    /// Never written to disk, only the in-memory log is kept
+    Off,
+}
+
+/// How MuJoCo renders the simulation, passed to the sim daemon as MUJOCO_GL
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MujocoRendering {
+    /// MUJOCO_GL left unset, MuJoCo picks its default backend
+    #[default]
+    Auto,
+    /// GPU through GLFW
+    Hardware,
+    /// CPU through OSMesa: slow, but works on headless or GL-broken systems
+    Software,
+}
+
+impl MujocoRendering {
+    /// MUJOCO_GL value, None to leave the variable unset
+    pub fn gl_backend(&self) -> Option<&'static str> {
+        match self {
+            MujocoRendering::Auto => None,
+            MujocoRendering::Hardware => Some("glfw"),
+            MujocoRendering::Software => Some("osmesa"),
+        }
+    }
+}
+
+/// How often the updater looks for a new version on its own
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateCheckInterval {
+    /// At launch, then every hour while the app runs
+    #[default]
+    Hourly,
+    OnLaunch,
+    Daily,
+    Weekly,
+    /// Only when the user asks (metered or locked-down networks)
+    Never,
+}
+
+/// User preferences persisted in the app config dir
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    pub shutdown: ShutdownConfig,
+    pub readiness: ReadinessConfig,
+    pub install_timeouts: InstallTimeouts,
+    pub signing: SigningConfig,
+    pub uv_concurrency: UvConcurrency,
+    pub error_alerts: ErrorAlertConfig,
+    pub auto_restart: AutoRestartConfig,
+    /// Serial number of the robot used last, chosen again when connected
+    pub last_robot_serial: Option<String>,
+    /// Pass --verbose to uv commands run through the trampoline (off by default
+    /// to avoid flooding the log buffer)
+    pub verbose_installs: bool,
+    /// Interface the daemon API binds to, None for localhost only.
+    /// Anything else exposes the robot control API on the network.
+    pub daemon_host: Option<String>,
+    /// Port of the daemon API, None for the default (8000)
+    pub daemon_port: Option<u16>,
+    /// Skip codesigning and attach to a manually started daemon (off by default)
+    pub developer_mode: bool,
+    /// Changed through set_kinematics_engine, which installs its dependencies
+    pub kinematics_engine: KinematicsEngine,
+    /// Use the standard macOS titlebar instead of the transparent one
+    pub standard_titlebar: bool,
+    /// Log level the daemon is launched with, changed live through set_daemon_log_level
+    pub daemon_log_level: DaemonLogLevel,
+    /// Rendering backend of the simulation, for systems where the default fails
+    pub mujoco_rendering: MujocoRendering,
+    /// Shared or privacy-sensitive machines can keep logs off the disk
+    pub log_retention: LogRetention,
+    /// Directory the venv and dataset cache were moved to (relocate_venv),
+    /// None when they live next to the app
+    pub venv_location: Option<PathBuf>,
+    /// Directory the daemon's dataset cache is kept in (HF_HUB_CACHE), None for the default
+    pub dataset_location: Option<PathBuf>,
+    /// Camera the daemon captures from (list_capture_devices id), None for its default
+    pub camera_device: Option<String>,
+    /// Microphone the daemon records from, None for its default
+    pub microphone_device: Option<String>,
+    pub update_check_interval: UpdateCheckInterval,
+    /// Closing the main window only hides it and leaves the daemon running
+    /// (off by default: closing the window stops the robot). macOS only, the
+    /// dock is the only way back to a hidden window.
+    pub keep_daemon_on_close: bool,
+    /// Saved robot pose (save_robot_pose) the robot moves to before the app
+    /// stops the daemon, None to leave it where it is
+    pub rest_pose_on_exit: Option<String>,
+}
+
+pub struct SettingsState {
+    pub settings: Mutex<AppSettings>,
+    /// Location of settings.json, None if the config dir could not be resolved
+    pub path: Option<PathBuf>,
+}
+
+// ============================================================================
+// PERSISTENCE
+// ============================================================================
+
+impl SettingsState {
+    /// Load settings from disk, falling back to defaults if missing or invalid
+    pub fn load(app_handle: &AppHandle) -> Self {
+        let path = app_handle
+            .path()
+            .app_config_dir()
+            .map(|dir| dir.join(SETTINGS_FILE))
+            .ok();
+
+        let settings = path
+            .as_ref()
+            .filter(|p| p.exists())
+            .and_then(|p| match std::fs::read_to_string(p) {
+                Ok(content) => match serde_json::from_str::<AppSettings>(&content) {
+                    Ok(settings) => Some(settings),
+                    Err(e) => {
+                        eprintln!("[tauri] ⚠️  Invalid settings file {}: {}", p.display(), e);
+                        None
+                    }
+                },
+                Err(e) => {
+                    eprintln!("[tauri] ⚠️  Failed to read settings file {}: {}", p.display(), e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            settings: Mutex::new(settings),
+            path,
+        }
+    }
+
+    /// Write the given settings to disk
+    pub fn save(&self, settings: &AppSettings) -> Result<(), String> {
+        let path = self.path.as_ref().ok_or("Settings location is unavailable")?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config dir {}: {}", parent.display(), e))?;
+        }
+
+        let content = serde_json::to_string_pretty(settings)
+            .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+        std::fs::write(path, content)
+            .map_err(|e| format!("Failed to write settings {}: {}", path.display(), e))
+    }
+}
+
+/// Snapshot of the current settings
+pub fn current(app_handle: &AppHandle) -> AppSettings {
+    let state = app_handle.state::<SettingsState>();
+    let settings = state.settings.lock().unwrap();
+    settings.clone()
+}
+
+/// Apply a change to the settings and persist it
+pub fn update<F>(app_handle: &AppHandle, change: F) -> Result<AppSettings, String>
+where
+    F: FnOnce(&mut AppSettings),
+{
+    let state = app_handle.state::<SettingsState>();
+    let mut settings = state.settings.lock().unwrap();
+    change(&mut settings);
+    state.save(&settings)?;
+    Ok(settings.clone())
+}
+
+// ============================================================================
+// TAURI COMMANDS
+// ============================================================================
+
+#[tauri::command]
+pub fn get_settings(app_handle: AppHandle) -> AppSettings {
+    current(&app_handle)
+}
+
+#[tauri::command]
+pub fn set_verbose_installs(app_handle: AppHandle, enabled: bool) -> Result<AppSettings, String> {
+    println!("[tauri] 🔊 Verbose uv logging {}", if enabled { "enabled" } else { "disabled" });
+    update(&app_handle, |settings| settings.verbose_installs = enabled)
+}
+
+/// Set the interface the daemon binds to (None or "127.0.0.1" = localhost only)
+/// Returns a security warning when the API becomes reachable from the network
+#[tauri::command]
+pub fn set_daemon_host(app_handle: AppHandle, host: Option<String>) -> Result<Option<String>, String> {
+    let host = host.filter(|h| !h.is_empty() && h != "127.0.0.1" && h != "localhost");
+    
+    check_daemon_host(host.as_deref())?;
+    
+    let warning = host.as_ref().map(|h| {
+        format!(
+            "The daemon will be reachable on {} from other machines: anyone on this network can control the robot. Restart the daemon to apply.",
+            h
+        )
+    });
+    
+    update(&app_handle, |settings| settings.daemon_host = host)?;
+    Ok(warning)
+}
+
+/// Set the port the daemon API listens on (None = default)
+/// Takes effect on the next daemon start
+#[tauri::command]
+pub fn set_daemon_port(app_handle: AppHandle, port: Option<u16>) -> Result<AppSettings, String> {
+    check_daemon_port(port)?;
+    update(&app_handle, |settings| settings.daemon_port = port)
+}
+
+#[tauri::command]
+pub fn set_developer_mode(app_handle: AppHandle, enabled: bool) -> Result<AppSettings, String> {
+    if enabled && !cfg!(debug_assertions) {
+        println!("[tauri] ⚠️  Developer mode enabled in a release build: signing is skipped");
+    } else {
+        println!("[tauri] 🛠️  Developer mode {}", if enabled { "enabled" } else { "disabled" });
+    }
+    update(&app_handle, |settings| settings.developer_mode = enabled)
+}
+
+/// Choose the camera and microphone the daemon uses (None = the daemon's default)
+/// Takes effect on the next daemon start
+#[tauri::command]
+pub fn set_capture_devices(
+    app_handle: AppHandle,
+    camera: Option<String>,
+    microphone: Option<String>,
+) -> Result<AppSettings, String> {
+    let camera = camera.filter(|c| !c.is_empty());
+    let microphone = microphone.filter(|m| !m.is_empty());
+    update(&app_handle, |settings| {
+        settings.camera_device = camera;
+        settings.microphone_device = microphone;
+    })
+}
+
+/// Change the codesign timeout and timestamp retries used when re-signing the venv
+#[tauri::command]
+pub fn set_signing_config(app_handle: AppHandle, config: SigningConfig) -> Result<AppSettings, String> {
+    check_signing_config(&config)?;
+    update(&app_handle, |settings| settings.signing = config)
+}
+
+/// Parallelism used for uv installs
+#[tauri::command]
+pub fn get_uv_concurrency(app_handle: AppHandle) -> UvConcurrency {
+    current(&app_handle).uv_concurrency
+}
+
+/// Limit the parallel downloads and installs of uv (None for uv's default)
+/// Lower values avoid running out of memory during large installs on small machines
+#[tauri::command]
+pub fn set_uv_concurrency(app_handle: AppHandle, concurrency: UvConcurrency) -> Result<AppSettings, String> {
+    check_uv_concurrency(&concurrency)?;
+    println!("[tauri] ⚙️  uv concurrency: {}", concurrency.describe());
+    update(&app_handle, |settings| settings.uv_concurrency = concurrency)
+}
+
+/// Choose whether logs are written to disk, applied to the logger right away
+/// (already written files stay until purge_log_files or the next launch)
+#[tauri::command]
+pub fn set_log_retention(app_handle: AppHandle, retention: LogRetention) -> Result<AppSettings, String> {
+    let settings = update(&app_handle, |settings| settings.log_retention = retention)?;
+    crate::daemon::logfile::apply_retention(&app_handle, retention);
+    Ok(settings)
+}
+
+/// Force software or hardware rendering in the simulation (applies at the next sim launch)
+#[tauri::command]
+pub fn set_mujoco_rendering(app_handle: AppHandle, rendering: MujocoRendering) -> Result<AppSettings, String> {
+    check_mujoco_rendering(rendering)?;
+    println!("[tauri] 🎨 MuJoCo rendering: MUJOCO_GL={}", rendering.gl_backend().unwrap_or("(default)"));
+    update(&app_handle, |settings| settings.mujoco_rendering = rendering)
+}
+
+/// Turn the daemon error spike alert on or off
+#[tauri::command]
+pub fn set_error_alerts_enabled(app_handle: AppHandle, enabled: bool) -> Result<AppSettings, String> {
+    println!("[tauri] 🚨 Daemon error alerts {}", if enabled { "enabled" } else { "disabled" });
+    update(&app_handle, |settings| settings.error_alerts.enabled = enabled)
+}
+
+/// Alert once `threshold` error lines show up within `window_secs`
+#[tauri::command]
+pub fn set_error_alert_threshold(app_handle: AppHandle, threshold: u32, window_secs: u64) -> Result<AppSettings, String> {
+    check_error_alert_threshold(threshold, window_secs)?;
+    println!("[tauri] 🚨 Daemon error alert: {} errors within {}s", threshold, window_secs);
+    update(&app_handle, |settings| {
+        settings.error_alerts.threshold = threshold;
+        settings.error_alerts.window_secs = window_secs;
+    })
+}
+
+/// Turn automatic restarts of a crashed daemon on or off
+#[tauri::command]
+pub fn set_auto_restart(app_handle: AppHandle, enabled: bool) -> Result<AppSettings, String> {
+    println!("[tauri] 🔁 Daemon auto-restart {}", if enabled { "enabled" } else { "disabled" });
+    update(&app_handle, |settings| settings.auto_restart.enabled = enabled)
+}
+
+/// Choose whether closing the main window stops the daemon or hides the window (macOS)
+#[tauri::command]
+pub fn set_keep_daemon_on_close(app_handle: AppHandle, enabled: bool) -> Result<AppSettings, String> {
+    check_keep_daemon_on_close(enabled)?;
+    println!("[tauri] 🪟 Closing the window will {}", if enabled { "keep the daemon running" } else { "stop the daemon" });
+    update(&app_handle, |settings| settings.keep_daemon_on_close = enabled)
+}
+
+/// Choose the saved pose the robot is parked in on exit (None to disable)
+#[tauri::command]
+pub fn set_rest_pose_on_exit(app_handle: AppHandle, name: Option<String>) -> Result<AppSettings, String> {
+    check_rest_pose(&app_handle, name.as_deref())?;
+    println!("[tauri] 🛏️  Rest pose on exit: {}", name.as_deref().unwrap_or("none"));
+    update(&app_handle, |settings| settings.rest_pose_on_exit = name)
+}
+
+/// Replace all the settings at once, rejected when any value is invalid
+#[tauri::command]
+pub fn set_settings(app_handle: AppHandle, settings: AppSettings) -> Result<AppSettings, String> {
+    validate(&app_handle, &settings)?;
+    update(&app_handle, |current| *current = settings)
+}
+
+// ============================================================================
+// VALIDATION
+// ============================================================================
+
+fn check_daemon_host(host: Option<&str>) -> Result<(), String> {
+    if let Some(host) = host {
+        host.parse::<std::net::IpAddr>()
+            .map_err(|_| format!("Invalid interface address: {}", host))?;
+    }
+    Ok(())
+}
+
+fn check_daemon_port(port: Option<u16>) -> Result<(), String> {
+    match port {
+        Some(port) if port < 1024 => Err(format!("Port {} is reserved, use a port between 1024 and 65535", port)),
+        _ => Ok(()),
+    }
+}
+
+/// The daemon needs some time to park the robot, but a stuck daemon
+/// must not hold the cleanup (and the app exit) for minutes
+fn check_shutdown_config(config: &ShutdownConfig) -> Result<(), String> {
+    if !(100..=60_000).contains(&config.term_wait_ms) {
+        return Err("The shutdown term wait must be between 100 and 60000 ms".to_string());
+    }
+    if config.post_kill_wait_ms > 10_000 {
+        return Err("The post-kill wait must be at most 10000 ms".to_string());
+    }
+    Ok(())
+}
+
+fn check_signing_config(config: &SigningConfig) -> Result<(), String> {
+    if config.timeout_ms < 1000 {
+        return Err("The codesign timeout must be at least 1000 ms".to_string());
+    }
+    Ok(())
+}
+
+fn check_uv_concurrency(concurrency: &UvConcurrency) -> Result<(), String> {
+    if [concurrency.downloads, concurrency.installs].contains(&Some(0)) {
+        return Err("uv concurrency must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+fn check_mujoco_rendering(rendering: MujocoRendering) -> Result<(), String> {
+    if cfg!(target_os = "macos") && rendering == MujocoRendering::Software {
+        return Err("Software rendering (OSMesa) is not available on macOS".to_string());
+    }
+    Ok(())
+}
+
+fn check_error_alert_threshold(threshold: u32, window_secs: u64) -> Result<(), String> {
+    if threshold == 0 || window_secs == 0 {
+        return Err("Threshold and window must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+fn check_keep_daemon_on_close(enabled: bool) -> Result<(), String> {
+    if enabled && !cfg!(target_os = "macos") {
+        return Err("Keeping the daemon running after closing the window is only available on macOS".to_string());
+    }
+    Ok(())
+}
+
+fn check_rest_pose(app_handle: &AppHandle, name: Option<&str>) -> Result<(), String> {
+    if let Some(name) = name {
+        if crate::daemon::pose::find_pose(app_handle, name).is_none() {
+            return Err(format!("No saved pose named '{}'", name));
+        }
+    }
+    Ok(())
+}
+
+/// Same checks as the dedicated setters, for settings replaced as a whole
+fn validate(app_handle: &AppHandle, settings: &AppSettings) -> Result<(), String> {
+    check_daemon_host(settings.daemon_host.as_deref())?;
+    check_daemon_port(settings.daemon_port)?;
+    check_shutdown_config(&settings.shutdown)?;
+    check_signing_config(&settings.signing)?;
+    check_uv_concurrency(&settings.uv_concurrency)?;
+    check_mujoco_rendering(settings.mujoco_rendering)?;
+    check_error_alert_threshold(settings.error_alerts.threshold, settings.error_alerts.window_secs)?;
+    check_keep_daemon_on_close(settings.keep_daemon_on_close)?;
+    check_rest_pose(app_handle, settings.rest_pose_on_exit.as_deref())
+}
+
+// ============================================================================
+// EXPORT / IMPORT
+// ============================================================================
+
+/// Settings tied to this machine, never exported nor imported
+const MACHINE_SPECIFIC_KEYS: &[&str] = &["venv_location", "last_robot_serial", "camera_device", "microphone_device", "uv_concurrency", "mujoco_rendering", "dataset_location"];
+
+#[derive(Serialize)]
+pub struct SettingsImport {
+    pub settings: AppSettings,
+    /// Keys this version doesn't know, or that only apply to the source machine
+    pub ignored: Vec<String>,
+    /// Keys whose value was invalid, the current value was kept
+    pub rejected: Vec<String>,
+}
+
+/// Dotted paths of the fields of `value` that `schema` (the serialized current
+/// settings) doesn't have, nested sections included
+fn unknown_fields(schema: &serde_json::Value, value: &serde_json::Value, path: &str) -> Vec<String> {
+    match (schema, value) {
+        (serde_json::Value::Object(schema), serde_json::Value::Object(fields)) => fields
+            .iter()
+            .flat_map(|(key, value)| {
+                let path = format!("{}.{}", path, key);
+                match schema.get(key) {
+                    Some(known) => unknown_fields(known, value, &path),
+                    None => vec![path],
+                }
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Overlay `patch` on `target`, keeping target fields the patch doesn't mention
+fn merge_json(target: &mut serde_json::Value, patch: serde_json::Value) {
+    match (target, patch) {
+        (serde_json::Value::Object(target), serde_json::Value::Object(patch)) => {
+            for (key, value) in patch {
+                match target.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, patch) => *target = patch,
+    }
+}
+
+/// Write the settings (secrets are never part of them) to a JSON file
+#[tauri::command]
+pub fn export_settings(app_handle: AppHandle, path: String) -> Result<String, String> {
+    let mut exported = serde_json::to_value(current(&app_handle))
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    if let Some(fields) = exported.as_object_mut() {
+        for key in MACHINE_SPECIFIC_KEYS {
+            fields.remove(*key);
+        }
+    }
+
+    let content = serde_json::to_string_pretty(&exported)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+
+    println!("[tauri] 💾 Settings exported to {}", path);
+    Ok(path)
+}
+
+/// Load settings exported by export_settings, one key at a time: unknown
+/// and invalid keys are reported and skipped, missing ones keep their value.
+/// A section with a field this version doesn't know is rejected as a whole.
+#[tauri::command]
+pub fn import_settings(app_handle: AppHandle, path: String) -> Result<SettingsImport, String> {
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let imported: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("{} is not a valid settings file: {}", path, e))?;
+    let imported = match imported {
+        serde_json::Value::Object(fields) => fields,
+        _ => return Err(format!("{} is not a valid settings file", path)),
+    };
+
+    let mut merged = serde_json::to_value(current(&app_handle))
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    let mut ignored = Vec::new();
+    let mut rejected = Vec::new();
+
+    for (key, value) in imported {
+        let known = merged.get(&key).is_some();
+        if !known || MACHINE_SPECIFIC_KEYS.contains(&key.as_str()) {
+            ignored.push(key);
+            continue;
+        }
+        let unknown = unknown_fields(&merged[&key], &value, &key);
+        if !unknown.is_empty() {
+            println!("[tauri] ⚠️  Ignoring imported setting {}: unknown field {}", key, unknown.join(", "));
+            rejected.push(key);
+            continue;
+        }
+
+        let mut candidate = merged.clone();
+        merge_json(&mut candidate[&key], value);
+        let valid = serde_json::from_value::<AppSettings>(candidate.clone())
+            .map_err(|e| e.to_string())
+            .and_then(|settings| validate(&app_handle, &settings));
+        match valid {
+            Ok(()) => merged = candidate,
+            Err(e) => {
+                println!("[tauri] ⚠️  Ignoring imported setting {}: {}", key, e);
+                rejected.push(key);
+            }
+        }
+    }
+
+    let settings: AppSettings = serde_json::from_value(merged)
+        .map_err(|e| format!("Failed to apply imported settings: {}", e))?;
+    let settings = update(&app_handle, |current| *current = settings)?;
+
+    println!(
+        "[tauri] 💾 Settings imported from {} ({} ignored, {} rejected)",
+        path,
+        ignored.len(),
+        rejected.len()
+    );
+    Ok(SettingsImport { settings, ignored, rejected })
+}
@@ -1,4 +1,152 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use serde::Serialize;
+
+/// Outcome of the most recent signing run, kept so failures can be retried
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub struct LastSigningRun {
+    pub signing_identity: String,
+    pub entitlements: Option<PathBuf>,
+    /// Files that failed to sign, with whether they need the Python entitlements
+    pub failed: Vec<(PathBuf, bool)>,
+}
+
+#[derive(Default)]
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub struct SigningState {
+    pub last_run: Mutex<Option<LastSigningRun>>,
+}
+
+#[derive(Serialize)]
+pub struct SigningReport {
+    pub signed_count: usize,
+    pub failed: Vec<String>,
+    pub message: String,
+    pub timestamp: TimestampMode,
+}
+
+/// Timestamping of the signatures made by a run
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampMode {
+    /// Adhoc signatures are never timestamped
+    Adhoc,
+    Timestamped,
+    /// Some files fell back to a signature without timestamp
+    Partial,
+    /// The timestamp server was unreachable for every file
+    Untimestamped,
+}
+
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+impl TimestampMode {
+    fn describe(self) -> &'static str {
+        match self {
+            TimestampMode::Adhoc => "adhoc",
+            TimestampMode::Timestamped => "timestamped",
+            TimestampMode::Partial => "partly without timestamp",
+            TimestampMode::Untimestamped => "without timestamp",
+        }
+    }
+}
+
+/// Identity and limits for the codesign invocations of a run
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+struct Codesign {
+    identity: String,
+    config: crate::settings::SigningConfig,
+    timestamped: std::cell::Cell<usize>,
+    untimestamped: std::cell::Cell<usize>,
+    /// Set on the first timestamp-server failure, the rest of the run signs
+    /// without timestamp instead of waiting on the server again for every file
+    timestamp_unreachable: std::cell::Cell<bool>,
+}
+
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+impl Codesign {
+    fn new(identity: String, config: crate::settings::SigningConfig) -> Self {
+        Self {
+            identity,
+            config,
+            timestamped: Default::default(),
+            untimestamped: Default::default(),
+            timestamp_unreachable: Default::default(),
+        }
+    }
+
+    fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.config.timeout_ms)
+    }
+
+    fn timestamp_mode(&self) -> TimestampMode {
+        if self.identity == "-" {
+            return TimestampMode::Adhoc;
+        }
+        match (self.timestamped.get(), self.untimestamped.get()) {
+            (_, 0) => TimestampMode::Timestamped,
+            (0, _) => TimestampMode::Untimestamped,
+            _ => TimestampMode::Partial,
+        }
+    }
+}
+
+/// Run a command and collect its output, killing it once `timeout` elapses
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn output_with_timeout(cmd: &mut std::process::Command, timeout: std::time::Duration) -> Result<std::process::Output, String> {
+    use std::process::Stdio;
+
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    let start = std::time::Instant::now();
+
+    loop {
+        match child.try_wait().map_err(|e| e.to_string())? {
+            Some(_) => return child.wait_with_output().map_err(|e| e.to_string()),
+            None if start.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("timed out after {}s", timeout.as_secs()));
+            }
+            None => std::thread::sleep(std::time::Duration::from_millis(100)),
+        }
+    }
+}
+
+/// Failures worth retrying: the timestamp server answered badly or not at all
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn is_timestamp_failure(error: &str) -> bool {
+    error.contains("timestamp") || error.contains("timed out")
+}
+
+/// Counts signed binaries and collects the ones that failed
+#[derive(Default)]
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+struct SigningTally {
+    signed_count: usize,
+    failed: Vec<(PathBuf, bool)>,
+}
+
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+impl SigningTally {
+    fn record(&mut self, signed: bool, path: &Path, with_entitlements: bool) {
+        if signed {
+            self.signed_count += 1;
+        } else {
+            self.failed.push((path.to_path_buf(), with_entitlements));
+        }
+    }
+    
+    fn summary(&self) -> String {
+        if self.failed.is_empty() {
+            format!("✅ Successfully signed {} Python binaries", self.signed_count)
+        } else {
+            format!("⚠️  Signed {} binaries, {} failed", self.signed_count, self.failed.len())
+        }
+    }
+}
 
 /// Re-sign Python binaries (.so, .dylib) in .venv after pip install
 /// This fixes the Team ID mismatch issue on macOS where pip-installed binaries
@@ -7,9 +155,17 @@ use std::path::PathBuf;
 /// Runs asynchronously in a background thread to avoid blocking the UI
 #[cfg(target_os = "macos")]
 #[tauri::command]
-pub async fn sign_python_binaries() -> Result<String, String> {
+pub async fn sign_python_binaries(app_handle: tauri::AppHandle) -> Result<String, String> {
     use std::process::Command;
     use std::env;
+    use tauri::Manager;
+    
+    if crate::settings::current(&app_handle).developer_mode {
+        println!("[tauri] 🛠️  Developer mode: skipping Python binaries re-signing");
+        return Ok("Signing skipped (developer mode)".to_string());
+    }
+    
+    let signing_config = crate::settings::current(&app_handle).signing;
     
     // Run the signing work in a blocking thread to avoid blocking the async runtime
     let result = tauri::async_runtime::spawn_blocking(move || {
@@ -19,67 +175,8 @@ pub async fn sign_python_binaries() -> Result<String, String> {
     let exe_path = env::current_exe()
         .map_err(|e| format!("Failed to get current executable path: {}", e))?;
     
-    // Try to find .venv in different locations:
-    // - Production: Contents/Resources/.venv (in .app bundle)
-    // - Dev mode: target/debug/.venv or current_dir/.venv
-    let venv_dir = if exe_path.to_string_lossy().contains(".app/Contents/MacOS") {
-        // Production mode: in app bundle
-        let app_bundle = exe_path
-            .parent() // Contents/MacOS
-            .and_then(|p| p.parent()) // Contents
-            .and_then(|p| p.parent()) // .app bundle
-            .ok_or("Failed to find app bundle path")?;
-        
-        let resources_dir = app_bundle.join("Contents/Resources");
-        resources_dir.join(".venv")
-    } else {
-        // Dev mode: try to find .venv relative to current dir or target/debug
-        let current_dir = env::current_dir()
-            .map_err(|e| format!("Failed to get current directory: {}", e))?;
-        
-        // Try multiple locations in dev mode:
-        // 1. binaries/.venv (if we're in src-tauri/)
-        // 2. src-tauri/binaries/.venv (if we're in project root)
-        // 3. target/debug/.venv
-        // 4. current_dir/.venv
-        
-        // Check if we're in src-tauri/ directory by checking the last component
-        let is_in_src_tauri = current_dir
-            .file_name()
-            .and_then(|name| name.to_str())
-            .map(|name| name == "src-tauri")
-            .unwrap_or(false);
-        
-        // Try multiple locations in dev mode:
-        let binaries_venv = if is_in_src_tauri {
-            // We're in src-tauri/, look for binaries/.venv
-            current_dir.join("binaries/.venv")
-        } else {
-            // We're in project root, look for src-tauri/binaries/.venv
-            current_dir.join("src-tauri/binaries/.venv")
-        };
-        
-        if binaries_venv.exists() {
-            println!("[tauri] 📁 Found .venv at: {}", binaries_venv.display());
-            binaries_venv
-        } else {
-            let target_venv = if is_in_src_tauri {
-                current_dir.join("target/debug/.venv")
-            } else {
-                current_dir.join("src-tauri/target/debug/.venv")
-            };
-            
-            if target_venv.exists() {
-                println!("[tauri] 📁 Found .venv at: {}", target_venv.display());
-                target_venv
-            } else {
-                // Fallback: try current_dir/.venv
-                let fallback_venv = current_dir.join(".venv");
-                println!("[tauri] 📁 Trying fallback .venv at: {}", fallback_venv.display());
-                fallback_venv
-            }
-        }
-    };
+    // Locate .venv (Contents/Resources in the app bundle, binaries/ or target/debug/ in dev)
+    let venv_dir = crate::python::find_venv_dir()?;
     
     if !venv_dir.exists() {
         return Err(format!("Python virtual environment (.venv) not found at: {}", venv_dir.display()));
@@ -206,54 +303,78 @@ pub async fn sign_python_binaries() -> Result<String, String> {
         }
     };
     
+    let codesign = Codesign::new(signing_identity, signing_config);
+    
     // 4. Find and sign all binaries in .venv
     // IMPORTANT: Sign in order: libpython first, then executables, then extensions
     // Python binaries need disable-library-validation entitlement!
-    let mut signed_count = 0;
-    let mut error_count = 0;
+    let mut tally = SigningTally::default();
     
+    // Interpreter file names carry the major.minor version of the cpython
+    // folder the venv was created from (python3.12, libpython3.12.dylib)
+    // The venv may be relocated out of the uv folder, which holds the interpreters
+    let uv_folder = crate::python::find_uv_folder()?;
+    let cpython_folder = uv_wrapper::find_cpython_folder(&uv_folder, &venv_dir).ok();
+    let python_version = cpython_folder.as_deref().and_then(python_minor_version);
+    if python_version.is_none() {
+        println!("[tauri] ⚠️  Python version of the venv interpreter unknown, only python3 is signed with priority");
+    }
+    let python_versioned = python_version.as_ref().map(|version| format!("python{}", version));
+    let libpython_name = python_version.as_ref().map(|version| format!("libpython{}.dylib", version));
+
     // Priority 1: Sign libpython*.dylib FIRST (critical for Python to load)
     // Apply entitlements to libpython for disable-library-validation
-    let libpython_dylib = venv_dir.join("lib/libpython3.12.dylib");
-    if libpython_dylib.exists() {
-        println!("[tauri] 🔐 Signing libpython3.12.dylib with entitlements (priority)...");
-        if sign_binary_with_entitlements(&libpython_dylib, &signing_identity, python_entitlements.as_ref())? {
-            signed_count += 1;
-        } else {
-            error_count += 1;
+    let libpython_dylib = libpython_name.as_ref().map(|name| venv_dir.join("lib").join(name));
+    if let (Some(name), Some(dylib)) = (&libpython_name, &libpython_dylib) {
+        if dylib.exists() {
+            println!("[tauri] 🔐 Signing {} with entitlements (priority)...", name);
+            let signed = sign_binary_with_entitlements(dylib, &codesign, python_entitlements.as_ref())?;
+            tally.record(signed, dylib, true);
         }
     }
     
-    // Priority 2: Sign Python executables (python3, python3.12)
+    // Priority 2: Sign Python executables (python3, python3.X)
     // Apply entitlements to python3 for disable-library-validation
     let python_bin = venv_dir.join("bin/python3");
     if python_bin.exists() {
         println!("[tauri] 🔐 Signing python3 executable with entitlements...");
-        if sign_binary_with_entitlements(&python_bin, &signing_identity, python_entitlements.as_ref())? {
-            signed_count += 1;
-        } else {
-            error_count += 1;
+        let signed = sign_binary_with_entitlements(&python_bin, &codesign, python_entitlements.as_ref())?;
+        tally.record(signed, &python_bin, true);
+    }
+    
+    // Also sign python3.X if it exists and is different from python3
+    if let Some(name) = &python_versioned {
+        let python_versioned_bin = venv_dir.join("bin").join(name);
+        if python_versioned_bin.exists() && python_versioned_bin != python_bin {
+            println!("[tauri] 🔐 Signing {} executable with entitlements...", name);
+            let signed = sign_binary_with_entitlements(&python_versioned_bin, &codesign, python_entitlements.as_ref())?;
+            tally.record(signed, &python_versioned_bin, true);
         }
     }
     
-    // Also sign python3.12 if it exists and is different from python3
-    let python312_bin = venv_dir.join("bin/python3.12");
-    if python312_bin.exists() && python312_bin != python_bin {
-        println!("[tauri] 🔐 Signing python3.12 executable with entitlements...");
-        if sign_binary_with_entitlements(&python312_bin, &signing_identity, python_entitlements.as_ref())? {
-            signed_count += 1;
-        } else {
-            error_count += 1;
+    // An interpreter of another arch (rebuild_venv_for_arch) was downloaded at
+    // runtime and not signed at build time: sign it with the Python entitlements too
+    let foreign_venv_arch = crate::system::detect_venv_arch().filter(|arch| arch != std::env::consts::ARCH);
+    if let (Some(venv_arch), Some(cpython_folder)) = (foreign_venv_arch, &cpython_folder) {
+        let interpreter_dir = uv_folder.join(cpython_folder);
+        println!("[tauri] 🔐 Signing {} interpreter ({})...", cpython_folder, venv_arch);
+        let interpreter_files = python_versioned.iter().map(|name| interpreter_dir.join("bin").join(name))
+            .chain(libpython_name.iter().map(|name| interpreter_dir.join("lib").join(name)));
+        for interpreter_file in interpreter_files {
+            if interpreter_file.exists() {
+                let signed = sign_binary_with_entitlements(&interpreter_file, &codesign, python_entitlements.as_ref())?;
+                tally.record(signed, &interpreter_file, true);
+            }
         }
     }
     
     // Priority 3: Sign all other .dylib files (including libpython in other locations)
-    let dylib_files = find_files(&venv_dir, "*.dylib")
+    let dylib_files = uv_wrapper::find_files(&venv_dir, "*.dylib")
         .map_err(|e| format!("Failed to find .dylib files: {}", e))?;
     
     for dylib_file in dylib_files {
-        // Skip libpython3.12.dylib if already signed above
-        if dylib_file == libpython_dylib {
+        // Skip the libpython dylib if already signed above
+        if libpython_dylib.as_ref() == Some(&dylib_file) {
             continue;
         }
         // Apply entitlements to all libpython*.dylib files
@@ -261,38 +382,28 @@ pub async fn sign_python_binaries() -> Result<String, String> {
             .map(|n| n.to_string_lossy().starts_with("libpython"))
             .unwrap_or(false);
         
-        if use_entitlements {
-            if sign_binary_with_entitlements(&dylib_file, &signing_identity, python_entitlements.as_ref())? {
-                signed_count += 1;
-            } else {
-                error_count += 1;
-            }
-        } else {
-        if sign_binary(&dylib_file, &signing_identity)? {
-            signed_count += 1;
-        } else {
-            error_count += 1;
-            }
-        }
+        let entitlements = if use_entitlements { python_entitlements.as_ref() } else { None };
+        let signed = sign_binary_with_entitlements(&dylib_file, &codesign, entitlements)?;
+        tally.record(signed, &dylib_file, use_entitlements);
     }
     
     // Priority 4: Sign all .so files (Python extensions)
-    let so_files = find_files(&venv_dir, "*.so")
+    let so_files = uv_wrapper::find_files(&venv_dir, "*.so")
         .map_err(|e| format!("Failed to find .so files: {}", e))?;
     
     for so_file in so_files {
-        if sign_binary(&so_file, &signing_identity)? {
-            signed_count += 1;
-        } else {
-            error_count += 1;
-        }
+        let signed = sign_binary(&so_file, &codesign)?;
+        tally.record(signed, &so_file, false);
     }
     
-        let result_msg = if error_count == 0 {
-            format!("✅ Successfully signed {} Python binaries", signed_count)
-        } else {
-            format!("⚠️  Signed {} binaries, {} failed", signed_count, error_count)
-        };
+        let result_msg = format!("{} ({})", tally.summary(), codesign.timestamp_mode().describe());
+        
+        // Keep the failures so resign_failed can retry just those
+        *app_handle.state::<SigningState>().last_run.lock().unwrap() = Some(LastSigningRun {
+            signing_identity: codesign.identity.clone(),
+            entitlements: python_entitlements,
+            failed: tally.failed,
+        });
         
         println!("[tauri] {}", result_msg);
         Ok(result_msg)
@@ -310,53 +421,118 @@ pub fn sign_python_binaries() -> Result<String, String> {
     Ok("Code signing not required on this platform".to_string())
 }
 
-/// Helper to find files matching a pattern recursively
+/// Retry signing only the files that failed during the last signing run
 #[cfg(target_os = "macos")]
-fn find_files(dir: &PathBuf, pattern: &str) -> Result<Vec<PathBuf>, String> {
-    use std::fs;
-    
-    let mut files = Vec::new();
-    
-    if !dir.exists() {
-        return Ok(files);
-    }
-    
-    let entries = fs::read_dir(dir)
-        .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?;
+#[tauri::command]
+pub async fn resign_failed(app_handle: tauri::AppHandle) -> Result<SigningReport, String> {
+    use tauri::Manager;
     
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
-        let path = entry.path();
+    tauri::async_runtime::spawn_blocking(move || {
+        let signing_state = app_handle.state::<SigningState>();
+        let mut last_run_lock = signing_state.last_run.lock().unwrap();
+        let last_run = last_run_lock
+            .as_mut()
+            .ok_or("No signing run recorded yet, run sign_python_binaries first")?;
         
-        if path.is_dir() {
-            // Recursively search subdirectories
-            let mut sub_files = find_files(&path, pattern)?;
-            files.append(&mut sub_files);
-        } else if path.is_file() {
-            // Check if file matches pattern
-            if let Some(file_name) = path.file_name() {
-                if file_name.to_string_lossy().ends_with(&pattern[2..]) { // Remove "*." from pattern
-                    files.push(path);
-                }
-            }
+        println!("[tauri] 🔐 Retrying {} failed signature(s)...", last_run.failed.len());
+        
+        let codesign = Codesign::new(last_run.signing_identity.clone(), crate::settings::current(&app_handle).signing);
+        let mut tally = SigningTally::default();
+        for (path, with_entitlements) in &last_run.failed {
+            let entitlements = if *with_entitlements { last_run.entitlements.as_ref() } else { None };
+            let signed = sign_binary_with_entitlements(path, &codesign, entitlements)?;
+            tally.record(signed, path, *with_entitlements);
         }
-    }
-    
-    Ok(files)
+        
+        let message = tally.summary();
+        println!("[tauri] {}", message);
+        
+        let report = SigningReport {
+            signed_count: tally.signed_count,
+            failed: tally.failed.iter().map(|(p, _)| p.display().to_string()).collect(),
+            message,
+            timestamp: codesign.timestamp_mode(),
+        };
+        last_run.failed = tally.failed;
+        Ok(report)
+    })
+    .await
+    .map_err(|e| format!("Failed to execute signing task: {}", e))?
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+pub fn resign_failed() -> Result<SigningReport, String> {
+    // No-op on non-macOS
+    Ok(SigningReport {
+        signed_count: 0,
+        failed: Vec::new(),
+        message: "Code signing not required on this platform".to_string(),
+        timestamp: TimestampMode::Adhoc,
+    })
+}
+
+/// Major.minor version of a cpython folder ("cpython-3.12.8-macos-aarch64-none" -> "3.12")
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn python_minor_version(cpython_folder: &str) -> Option<String> {
+    let version = uv_wrapper::cpython_folder_version(cpython_folder)?;
+    let mut parts = version.split('.');
+    let (major, minor) = (parts.next()?, parts.next()?);
+    Some(format!("{}.{}", major, minor))
 }
 
 /// Sign a single binary file (without entitlements)
 #[cfg(target_os = "macos")]
-fn sign_binary(binary_path: &PathBuf, signing_identity: &str) -> Result<bool, String> {
-    sign_binary_with_entitlements(binary_path, signing_identity, None)
+fn sign_binary(binary_path: &PathBuf, codesign: &Codesign) -> Result<bool, String> {
+    sign_binary_with_entitlements(binary_path, codesign, None)
+}
+
+/// One codesign invocation, Err with codesign's error output on failure
+#[cfg(target_os = "macos")]
+fn run_codesign(
+    binary_path: &Path,
+    codesign: &Codesign,
+    entitlements_path: Option<&PathBuf>,
+    timestamp_flag: Option<&str>,
+) -> Result<(), String> {
+    use std::process::Command;
+    
+    let mut cmd = Command::new("codesign");
+    cmd.arg("--force")
+        .arg("--sign")
+        .arg(&codesign.identity)
+        .arg("--options")
+       .arg("runtime");
+    
+    // Add entitlements if provided
+    if let Some(entitlements) = entitlements_path.filter(|e| e.exists()) {
+        cmd.arg("--entitlements").arg(entitlements);
+    }
+    
+    if let Some(flag) = timestamp_flag {
+        cmd.arg(flag);
+    }
+    
+    cmd.arg(binary_path);
+    
+    let output = output_with_timeout(&mut cmd, codesign.timeout())?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
 }
 
 /// Sign a single binary file with optional entitlements
 /// entitlements_path: Optional path to .plist file with entitlements
+///
+/// Timestamped signatures are retried when the timestamp server fails, then
+/// made without timestamp if the signing config allows it. After that first
+/// failure the remaining files of the run go straight to no timestamp.
 #[cfg(target_os = "macos")]
 fn sign_binary_with_entitlements(
     binary_path: &PathBuf, 
-    signing_identity: &str,
+    codesign: &Codesign,
     entitlements_path: Option<&PathBuf>
 ) -> Result<bool, String> {
     use std::process::Command;
@@ -373,47 +549,457 @@ fn sign_binary_with_entitlements(
         return Ok(false);
     }
     
-    // Build codesign command
-    let mut cmd = Command::new("codesign");
-    cmd.arg("--force")
-        .arg("--sign")
-        .arg(signing_identity)
-        .arg("--options")
-       .arg("runtime");
+    if let Some(entitlements) = entitlements_path.filter(|e| e.exists()) {
+        println!("[tauri]   📜 Using entitlements: {}", entitlements.display());
+    }
     
-    // Add entitlements if provided
-    if let Some(entitlements) = entitlements_path {
-        if entitlements.exists() {
-            cmd.arg("--entitlements").arg(entitlements);
-            println!("[tauri]   📜 Using entitlements: {}", entitlements.display());
+    // Add timestamp (skip for adhoc as it may not work)
+    let timestamped = codesign.identity != "-";
+    let offline = timestamped && codesign.timestamp_unreachable.get();
+    let attempts = if offline { 0 } else if timestamped { 1 + codesign.config.timestamp_retries } else { 1 };
+    let mut timestamp_failed = offline;
+    
+    for attempt in 1..=attempts {
+        match run_codesign(binary_path, codesign, entitlements_path, timestamped.then_some("--timestamp")) {
+            Ok(()) => {
+                if timestamped {
+                    codesign.timestamped.set(codesign.timestamped.get() + 1);
+                }
+                println!("[tauri]   ✓ Signed: {}", binary_path.display());
+                return Ok(true);
+            }
+            Err(e) => {
+                println!("[tauri]   ⚠️  Failed to sign {} (attempt {}/{}): {}", binary_path.display(), attempt, attempts, e);
+                timestamp_failed = timestamped && is_timestamp_failure(&e);
+                if !timestamp_failed {
+                    break;
+                }
+                if attempt == attempts {
+                    println!("[tauri]   🕒 Timestamp server unreachable, signing the remaining files without timestamp");
+                    codesign.timestamp_unreachable.set(true);
+                }
+            }
         }
     }
     
-    // Add timestamp (skip for adhoc as it may not work)
-    if signing_identity != "-" {
-        cmd.arg("--timestamp");
+    // Offline: a signature without timestamp still lets the binary load
+    if timestamp_failed && codesign.config.allow_untimestamped {
+        if !offline {
+            println!("[tauri]   🕒 Signing {} without timestamp (timestamp server unreachable)", binary_path.display());
+        }
+        match run_codesign(binary_path, codesign, entitlements_path, Some("--timestamp=none")) {
+            Ok(()) => {
+                codesign.untimestamped.set(codesign.untimestamped.get() + 1);
+                println!("[tauri]   ✓ Signed without timestamp: {}", binary_path.display());
+                return Ok(true);
+            }
+            Err(e) => println!("[tauri]   ⚠️  Failed to sign {}: {}", binary_path.display(), e),
+        }
     }
     
-    cmd.arg(binary_path);
+    Ok(false)
+}
+
+
+// ============================================================================
+// APP BUNDLE VERIFICATION
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct SignatureCheck {
+    /// "codesign" (signature and sealed resources) or "spctl" (Gatekeeper)
+    pub tool: String,
+    pub passed: bool,
+    /// First file or subcomponent reported as invalid, when identifiable
+    pub failing_component: Option<String>,
+    pub output: String,
+}
+
+#[derive(Serialize)]
+pub struct AppSignatureReport {
+    /// False on platforms without app bundle signing
+    pub applicable: bool,
+    pub bundle: Option<String>,
+    pub passed: bool,
+    pub checks: Vec<SignatureCheck>,
+    pub message: String,
+}
+
+/// The running app bundle (X.app), None in dev mode
+#[cfg(target_os = "macos")]
+pub fn app_bundle_path() -> Option<PathBuf> {
+    let exe_path = std::env::current_exe().ok()?;
+    if !exe_path.to_string_lossy().contains(".app/Contents/MacOS") {
+        return None;
+    }
+    exe_path.ancestors().nth(3).map(Path::to_path_buf)
+}
+
+/// Pick the offending component out of codesign output, e.g.
+/// "In subcomponent: /…/libfoo.dylib" or "file modified: /…/Info.plist"
+#[cfg(target_os = "macos")]
+fn failing_component(output: &str) -> Option<String> {
+    const MARKERS: &[&str] = &["In subcomponent: ", "file modified: ", "file added: ", "file missing: "];
     
-    // Sign the binary
-    let sign_result = cmd.output();
+    MARKERS
+        .iter()
+        .find_map(|marker| output.lines().find_map(|line| line.trim().strip_prefix(marker)))
+        .or_else(|| output.lines().next().and_then(|line| line.split(": ").next()))
+        .map(|component| component.trim().to_string())
+        .filter(|component| !component.is_empty())
+}
+
+#[cfg(target_os = "macos")]
+fn run_signature_check(tool: &str, args: &[&str], bundle: &Path) -> SignatureCheck {
+    use std::process::Command;
     
-    match sign_result {
+    match Command::new(tool).args(args).arg(bundle).output() {
         Ok(output) => {
-            if output.status.success() {
-                println!("[tauri]   ✓ Signed: {}", binary_path.display());
-                Ok(true)
-            } else {
-                let error = String::from_utf8_lossy(&output.stderr);
-                println!("[tauri]   ⚠️  Failed to sign {}: {}", binary_path.display(), error);
-                Ok(false)
+            // Both tools report on stderr
+            let text = format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            );
+            let passed = output.status.success();
+            SignatureCheck {
+                tool: tool.to_string(),
+                passed,
+                failing_component: if passed { None } else { failing_component(&text) },
+                output: text.trim().to_string(),
+            }
+        }
+        Err(e) => SignatureCheck {
+            tool: tool.to_string(),
+            passed: false,
+            failing_component: None,
+            output: format!("Failed to run {}: {}", tool, e),
+        },
+    }
+}
+
+/// Verify the whole app bundle (codesign --deep --strict, then Gatekeeper),
+/// to diagnose "app is damaged" reports unrelated to the venv signatures
+#[cfg(target_os = "macos")]
+#[tauri::command]
+pub async fn verify_app_signature() -> Result<AppSignatureReport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let bundle = match app_bundle_path() {
+            Some(bundle) => bundle,
+            None => {
+                return Ok(AppSignatureReport {
+                    applicable: false,
+                    bundle: None,
+                    passed: true,
+                    checks: Vec::new(),
+                    message: "Not running from an app bundle (dev mode)".to_string(),
+                });
+            }
+        };
+        
+        println!("[tauri] 🔍 Verifying signature of {}", bundle.display());
+        
+        let checks = vec![
+            run_signature_check("codesign", &["--verify", "--deep", "--strict", "--verbose=2"], &bundle),
+            run_signature_check("spctl", &["--assess", "--type", "execute", "--verbose"], &bundle),
+        ];
+        let passed = checks.iter().all(|check| check.passed);
+        
+        let message = match checks.iter().find(|check| !check.passed) {
+            None => "✅ App bundle signature is valid and accepted by Gatekeeper".to_string(),
+            Some(check) => format!(
+                "❌ {} check failed{}",
+                check.tool,
+                check.failing_component
+                    .as_ref()
+                    .map(|component| format!(" on {}", component))
+                    .unwrap_or_default()
+            ),
+        };
+        println!("[tauri] {}", message);
+        
+        Ok(AppSignatureReport {
+            applicable: true,
+            bundle: Some(bundle.display().to_string()),
+            passed,
+            checks,
+            message,
+        })
+    })
+    .await
+    .map_err(|e| format!("Failed to execute verification task: {}", e))?
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+pub fn verify_app_signature() -> Result<AppSignatureReport, String> {
+    Ok(AppSignatureReport {
+        applicable: false,
+        bundle: None,
+        passed: true,
+        checks: Vec::new(),
+        message: "Not applicable on this platform".to_string(),
+    })
+}
+
+// ============================================================================
+// INTERPRETER ENTITLEMENTS
+// ============================================================================
+
+#[cfg(target_os = "macos")]
+const DISABLE_LIBRARY_VALIDATION: &str = "com.apple.security.cs.disable-library-validation";
+#[cfg(target_os = "macos")]
+const ALLOW_JIT: &str = "com.apple.security.cs.allow-jit";
+
+#[derive(Serialize)]
+pub struct InterpreterEntitlements {
+    pub path: String,
+    /// Authority of the signature, "adhoc" or None when unsigned
+    pub authority: Option<String>,
+    pub entitlements: std::collections::BTreeMap<String, serde_json::Value>,
+    /// Needed to load libraries signed by another team (pip wheels)
+    pub disable_library_validation: bool,
+    /// Needed by MuJoCo's and numba's JIT under the hardened runtime
+    pub allow_jit: bool,
+    pub error: Option<String>,
+}
+
+/// Text between `open` and `close` at the start of `xml`, and the rest after it
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn take_element<'a>(xml: &'a str, open: &str, close: &str) -> Option<(&'a str, &'a str)> {
+    let rest = xml.trim_start().strip_prefix(open)?;
+    let end = rest.find(close)?;
+    Some((&rest[..end], &rest[end + close.len()..]))
+}
+
+/// Entitlements of a codesign XML plist: booleans, strings and string arrays
+/// (anything else is kept as null)
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub fn parse_entitlements(xml: &str) -> std::collections::BTreeMap<String, serde_json::Value> {
+    use serde_json::Value;
+    
+    let mut entitlements = std::collections::BTreeMap::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find("<key>") {
+        let Some((key, after_key)) = take_element(&rest[start..], "<key>", "</key>") else {
+            break;
+        };
+        let after_key = after_key.trim_start();
+        let (value, after_value) = if let Some(after) = after_key.strip_prefix("<true/>") {
+            (Value::Bool(true), after)
+        } else if let Some(after) = after_key.strip_prefix("<false/>") {
+            (Value::Bool(false), after)
+        } else if let Some((text, after)) = take_element(after_key, "<string>", "</string>") {
+            (Value::String(text.to_string()), after)
+        } else if let Some((items, after)) = take_element(after_key, "<array>", "</array>") {
+            let mut strings = Vec::new();
+            let mut items = items;
+            while let Some((text, next)) = take_element(items, "<string>", "</string>") {
+                strings.push(Value::String(text.to_string()));
+                items = next;
             }
+            (Value::Array(strings), after)
+        } else {
+            (Value::Null, after_key)
+        };
+        entitlements.insert(key.trim().to_string(), value);
+        rest = after_value;
+    }
+    entitlements
+}
+
+#[cfg(target_os = "macos")]
+fn interpreter_entitlements(path: &Path) -> InterpreterEntitlements {
+    use std::process::Command;
+    
+    let mut report = InterpreterEntitlements {
+        path: path.display().to_string(),
+        authority: None,
+        entitlements: Default::default(),
+        disable_library_validation: false,
+        allow_jit: false,
+        error: None,
+    };
+    if !path.exists() {
+        report.error = Some("Not found".to_string());
+        return report;
+    }
+    
+    // Signature details are printed on stderr
+    if let Ok(output) = Command::new("codesign").args(["-d", "-vv"]).arg(path).output() {
+        let details = String::from_utf8_lossy(&output.stderr);
+        report.authority = details
+            .lines()
+            .find_map(|line| line.strip_prefix("Authority="))
+            .map(str::to_string)
+            .or_else(|| details.contains("Signature=adhoc").then(|| "adhoc".to_string()));
+    }
+    
+    // ":-" prints the raw XML plist on every macOS version
+    match Command::new("codesign").args(["-d", "--entitlements", ":-"]).arg(path).output() {
+        Ok(output) if output.status.success() => {
+            report.entitlements = parse_entitlements(&String::from_utf8_lossy(&output.stdout));
+            let enabled = |key: &str| report.entitlements.get(key) == Some(&serde_json::Value::Bool(true));
+            report.disable_library_validation = enabled(DISABLE_LIBRARY_VALIDATION);
+            report.allow_jit = enabled(ALLOW_JIT);
         }
-        Err(e) => {
-            println!("[tauri]   ⚠️  Error signing {}: {}", binary_path.display(), e);
-            Ok(false)
+        Ok(output) => report.error = Some(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+        Err(e) => report.error = Some(format!("Failed to run codesign: {}", e)),
+    }
+    report
+}
+
+/// Entitlements of the venv's python3 and mjpython, the binaries the daemon
+/// is launched with, to diagnose JIT and library-validation failures
+#[cfg(target_os = "macos")]
+#[tauri::command]
+pub async fn get_interpreter_entitlements() -> Result<Vec<InterpreterEntitlements>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let venv_dir = crate::python::find_venv_dir()?;
+        let reports: Vec<InterpreterEntitlements> = ["bin/python3", "bin/mjpython"]
+            .iter()
+            .map(|binary| {
+                // The venv entries are symlinks, check the file actually executed
+                let path = venv_dir.join(binary);
+                interpreter_entitlements(&path.canonicalize().unwrap_or(path))
+            })
+            .collect();
+        
+        for report in &reports {
+            println!(
+                "[tauri] 📜 {}: disable-library-validation={}, allow-jit={}",
+                report.path, report.disable_library_validation, report.allow_jit
+            );
         }
+        Ok(reports)
+    })
+    .await
+    .map_err(|e| format!("Failed to execute entitlements task: {}", e))?
+}
+
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+pub fn get_interpreter_entitlements() -> Result<Vec<InterpreterEntitlements>, String> {
+    // Entitlements only exist on macOS
+    Ok(Vec::new())
+}
+
+// ============================================================================
+// QUARANTINE
+// ============================================================================
+
+#[cfg(target_os = "macos")]
+pub const QUARANTINE_ATTRIBUTE: &str = "com.apple.quarantine";
+
+#[derive(Serialize)]
+pub struct QuarantineStatus {
+    /// False on platforms without quarantine, or in dev mode
+    pub applicable: bool,
+    pub bundle: Option<String>,
+    /// The bundle carries com.apple.quarantine (downloaded, never cleared)
+    pub quarantined: bool,
+    /// macOS runs the app from a read-only randomized copy (AppTranslocation)
+    pub translocated: bool,
+    /// True when clear_quarantine is allowed (app moved out of Downloads/translocation)
+    pub can_clear: bool,
+    pub guidance: Option<String>,
+}
+
+/// Quarantine and translocation state of the app bundle
+pub fn quarantine_status() -> QuarantineStatus {
+    #[cfg(target_os = "macos")]
+    {
+        let bundle = match app_bundle_path() {
+            Some(bundle) => bundle,
+            None => return QuarantineStatus::not_applicable(),
+        };
+        
+        let quarantined = std::process::Command::new("xattr")
+            .arg("-p")
+            .arg(QUARANTINE_ATTRIBUTE)
+            .arg(&bundle)
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false);
+        let translocated = uv_wrapper::is_app_translocation_path(&bundle);
+        let can_clear = quarantined && !translocated;
+        
+        let guidance = if translocated {
+            Some("macOS runs this app from a read-only copy. Quit it, drag it to the Applications folder and open it from there.".to_string())
+        } else if quarantined {
+            Some("The app is still flagged as downloaded from the internet, which can make its Python environment read-only. Clear the flag to fix it.".to_string())
+        } else {
+            None
+        };
+        
+        return QuarantineStatus {
+            applicable: true,
+            bundle: Some(bundle.display().to_string()),
+            quarantined,
+            translocated,
+            can_clear,
+            guidance,
+        };
+    }
+    
+    #[cfg(not(target_os = "macos"))]
+    QuarantineStatus::not_applicable()
+}
+
+impl QuarantineStatus {
+    fn not_applicable() -> Self {
+        QuarantineStatus {
+            applicable: false,
+            bundle: None,
+            quarantined: false,
+            translocated: false,
+            can_clear: false,
+            guidance: None,
+        }
+    }
+}
+
+#[tauri::command]
+pub fn is_quarantined() -> QuarantineStatus {
+    quarantine_status()
+}
+
+/// Remove com.apple.quarantine from the app bundle
+///
+/// Refused while the app is translocated: the flag must only be cleared once
+/// the user moved the app (usually to Applications) themselves.
+#[cfg(target_os = "macos")]
+#[tauri::command]
+pub fn clear_quarantine() -> Result<QuarantineStatus, String> {
+    let status = quarantine_status();
+    if !status.quarantined {
+        return Ok(status);
+    }
+    if !status.can_clear {
+        return Err(status.guidance.unwrap_or_else(|| "The quarantine flag can't be cleared".to_string()));
     }
+    
+    let bundle = status.bundle.ok_or("Failed to find app bundle path")?;
+    let output = std::process::Command::new("xattr")
+        .arg("-dr")
+        .arg(QUARANTINE_ATTRIBUTE)
+        .arg(&bundle)
+        .output()
+        .map_err(|e| format!("Failed to run xattr: {}", e))?;
+    
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to clear the quarantine flag: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    
+    println!("[tauri] ✅ Quarantine flag cleared on {}", bundle);
+    Ok(quarantine_status())
 }
 
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+pub fn clear_quarantine() -> Result<QuarantineStatus, String> {
+    Ok(QuarantineStatus::not_applicable())
+}
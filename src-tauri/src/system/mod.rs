@@ -0,0 +1,1125 @@
+use serde::Serialize;
+
+// ============================================================================
+// ARCHITECTURE DETECTION
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct RuntimeArch {
+    /// Architecture the app binary was built for (e.g. "x86_64", "aarch64")
+    pub app_arch: String,
+    /// Native architecture of the host machine
+    pub host_arch: String,
+    /// True when the app runs translated (Rosetta on macOS)
+    pub is_translated: bool,
+    /// Architecture of the venv interpreter, None if it could not be located
+    pub venv_arch: Option<String>,
+    /// Human-readable warning when app and host architectures differ
+    pub warning: Option<String>,
+}
+
+/// Normalize architecture names reported by the different platform tools
+fn normalize_arch(arch: &str) -> String {
+    match arch.trim() {
+        "arm64" | "ARM64" | "aarch64" => "aarch64".to_string(),
+        "x86_64" | "AMD64" | "amd64" | "x64" => "x86_64".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Check if the current process is translated by Rosetta (macOS)
+#[cfg(target_os = "macos")]
+fn is_process_translated() -> bool {
+    use std::process::Command;
+
+    // sysctl.proc_translated is 1 under Rosetta, 0 when native, missing on Intel Macs
+    Command::new("sysctl")
+        .arg("-n")
+        .arg("sysctl.proc_translated")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim() == "1")
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_os = "macos"))]
+fn is_process_translated() -> bool {
+    false
+}
+
+/// Detect the native architecture of the host
+#[cfg(target_os = "macos")]
+fn detect_host_arch() -> String {
+    use std::process::Command;
+
+    // hw.optional.arm64 is 1 on Apple Silicon, even when queried from a translated process
+    let is_apple_silicon = Command::new("sysctl")
+        .arg("-n")
+        .arg("hw.optional.arm64")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim() == "1")
+        .unwrap_or(false);
+
+    if is_apple_silicon {
+        "aarch64".to_string()
+    } else {
+        "x86_64".to_string()
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn detect_host_arch() -> String {
+    // PROCESSOR_ARCHITEW6432 is only set for 32-bit processes on a 64-bit host
+    std::env::var("PROCESSOR_ARCHITEW6432")
+        .or_else(|_| std::env::var("PROCESSOR_ARCHITECTURE"))
+        .map(|arch| normalize_arch(&arch))
+        .unwrap_or_else(|_| std::env::consts::ARCH.to_string())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn detect_host_arch() -> String {
+    use std::process::Command;
+
+    Command::new("uname")
+        .arg("-m")
+        .output()
+        .map(|output| normalize_arch(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or_else(|_| std::env::consts::ARCH.to_string())
+}
+
+/// Architecture of the interpreter the venv was created from
+pub fn detect_venv_arch() -> Option<String> {
+    let uv_folder = crate::python::find_uv_folder().ok()?;
+    let cpython_folder = uv_wrapper::find_cpython_folder(&uv_folder, &crate::python::venv_dir(&uv_folder)).ok()?;
+    uv_wrapper::cpython_folder_arch(&cpython_folder).map(normalize_arch)
+}
+
+/// Report the app, host and venv architectures and Rosetta translation
+#[tauri::command]
+pub fn get_runtime_arch() -> RuntimeArch {
+    let app_arch = normalize_arch(std::env::consts::ARCH);
+    let host_arch = detect_host_arch();
+    let is_translated = is_process_translated();
+    let venv_arch = detect_venv_arch();
+
+    let warning = if is_translated {
+        Some(format!(
+            "App is running under Rosetta ({} build on {} host). Native Python extensions may fail to load, please install the {} build.",
+            app_arch, host_arch, host_arch
+        ))
+    } else if app_arch != host_arch {
+        Some(format!(
+            "Architecture mismatch: app is built for {} but host is {}",
+            app_arch, host_arch
+        ))
+    } else {
+        venv_arch
+            .as_ref()
+            .filter(|arch| **arch != host_arch)
+            .map(|arch| format!("Python venv is built for {} and runs translated on this {} host", arch, host_arch))
+    };
+
+    if let Some(ref w) = warning {
+        println!("[tauri] ⚠️  {}", w);
+    }
+
+    RuntimeArch {
+        app_arch,
+        host_arch,
+        is_translated,
+        venv_arch,
+        warning,
+    }
+}
+
+// ============================================================================
+// DISK SPACE
+// ============================================================================
+
+/// Free space required before starting a dependency install (MuJoCo, upgrades)
+pub const MIN_FREE_BYTES_FOR_INSTALL: u64 = 2 * 1024 * 1024 * 1024;
+
+#[derive(Serialize)]
+pub struct DiskStatus {
+    /// Path used to determine the volume (the venv, or its closest existing parent)
+    pub path: String,
+    pub free_bytes: u64,
+    pub required_bytes: u64,
+    pub sufficient: bool,
+}
+
+/// Query the free space of the volume hosting a path
+#[cfg(not(target_os = "windows"))]
+pub fn free_space_bytes(path: &std::path::Path) -> Result<u64, String> {
+    use std::process::Command;
+
+    // POSIX output: Filesystem 1024-blocks Used Available Capacity Mounted-on
+    let output = Command::new("df")
+        .arg("-Pk")
+        .arg(path)
+        .output()
+        .map_err(|e| format!("Failed to run df: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!("df failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let available_kb = stdout
+        .lines()
+        .nth(1)
+        .and_then(|line| line.split_whitespace().nth(3))
+        .and_then(|value| value.parse::<u64>().ok())
+        .ok_or_else(|| format!("Unexpected df output: {}", stdout.trim()))?;
+
+    Ok(available_kb * 1024)
+}
+
+#[cfg(target_os = "windows")]
+pub fn free_space_bytes(path: &std::path::Path) -> Result<u64, String> {
+    use std::process::Command;
+
+    let drive_letter = path
+        .to_string_lossy()
+        .chars()
+        .next()
+        .filter(|c| c.is_ascii_alphabetic())
+        .ok_or_else(|| format!("Unable to determine drive of {}", path.display()))?;
+
+    let output = Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg(format!("(Get-PSDrive -Name {}).Free", drive_letter))
+        .output()
+        .map_err(|e| format!("Failed to query free space: {}", e))?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| format!("Unexpected free space output: {}", e))
+}
+
+/// Free space on the volume hosting the venv
+pub fn install_disk_status() -> Result<DiskStatus, String> {
+    let venv_dir = crate::python::find_venv_dir()?;
+
+    // The venv may not exist yet, measure its closest existing ancestor
+    let existing_path = venv_dir
+        .ancestors()
+        .find(|p| p.exists())
+        .ok_or_else(|| format!("No existing parent for {}", venv_dir.display()))?;
+
+    let free_bytes = free_space_bytes(existing_path)?;
+
+    Ok(DiskStatus {
+        path: existing_path.display().to_string(),
+        free_bytes,
+        required_bytes: MIN_FREE_BYTES_FOR_INSTALL,
+        sufficient: free_bytes >= MIN_FREE_BYTES_FOR_INSTALL,
+    })
+}
+
+/// Return an error early if there isn't enough free space for an install
+pub fn ensure_disk_space_for_install() -> Result<(), String> {
+    let status = install_disk_status()?;
+
+    if !status.sufficient {
+        const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+        return Err(format!(
+            "Not enough disk space: {:.1} GB free on {}, at least {:.1} GB needed",
+            status.free_bytes as f64 / GB,
+            status.path,
+            status.required_bytes as f64 / GB
+        ));
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_install_disk_status() -> Result<DiskStatus, String> {
+    install_disk_status()
+}
+
+// ============================================================================
+// VENV WRITABILITY
+// ============================================================================
+
+#[derive(Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteFailure {
+    PermissionDenied,
+    ReadOnly,
+    /// macOS runs the app from a read-only translocated copy
+    Translocated,
+    NotFound,
+    Other,
+}
+
+#[derive(Serialize)]
+pub struct VenvWritable {
+    pub path: String,
+    pub writable: bool,
+    pub failure: Option<WriteFailure>,
+    pub error: Option<String>,
+}
+
+fn write_failure(error: &std::io::Error) -> WriteFailure {
+    // EROFS, ErrorKind::ReadOnlyFilesystem needs a newer toolchain
+    #[cfg(unix)]
+    if error.raw_os_error() == Some(30) {
+        return WriteFailure::ReadOnly;
+    }
+
+    match error.kind() {
+        std::io::ErrorKind::PermissionDenied => WriteFailure::PermissionDenied,
+        std::io::ErrorKind::NotFound => WriteFailure::NotFound,
+        _ => WriteFailure::Other,
+    }
+}
+
+/// Create and delete a scratch file in the venv, as an install would
+pub fn venv_writable() -> Result<VenvWritable, String> {
+    let venv_dir = crate::python::find_venv_dir()?;
+    let path = venv_dir.display().to_string();
+
+    if uv_wrapper::is_app_translocation_path(&venv_dir) {
+        return Ok(VenvWritable {
+            path,
+            writable: false,
+            failure: Some(WriteFailure::Translocated),
+            error: Some("The app runs from a translocated copy, move it to the Applications folder".to_string()),
+        });
+    }
+
+    // A venv about to be created is written to its closest existing parent
+    let existing_dir = venv_dir.ancestors().find(|p| p.exists()).unwrap_or(&venv_dir);
+    let probe = existing_dir.join(format!(".write-test-{}", std::process::id()));
+    let result = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .and_then(|_| std::fs::remove_file(&probe));
+
+    Ok(match result {
+        Ok(()) => VenvWritable { path, writable: true, failure: None, error: None },
+        Err(e) => VenvWritable {
+            path,
+            writable: false,
+            failure: Some(write_failure(&e)),
+            error: Some(e.to_string()),
+        },
+    })
+}
+
+/// Return an error early if an install could not write to the venv
+pub fn ensure_venv_writable() -> Result<(), String> {
+    let status = venv_writable()?;
+
+    match status.error {
+        Some(error) if !status.writable => Err(format!("The Python environment at {} is not writable: {}", status.path, error)),
+        _ => Ok(()),
+    }
+}
+
+#[tauri::command]
+pub fn check_venv_writable() -> Result<VenvWritable, String> {
+    venv_writable()
+}
+
+// ============================================================================
+// MEMORY
+// ============================================================================
+
+/// Available memory below which MuJoCo simulation (models, viewer, datasets)
+/// risks getting the daemon killed by the OS
+pub const MIN_AVAILABLE_BYTES_FOR_SIM: u64 = 3 * 1024 * 1024 * 1024;
+
+#[derive(Serialize)]
+pub struct SystemMemory {
+    pub total_bytes: u64,
+    /// Memory that can be given to a new process without swapping
+    pub available_bytes: u64,
+    pub sim_required_bytes: u64,
+    pub sufficient_for_sim: bool,
+}
+
+/// Total and available memory in bytes
+#[cfg(target_os = "linux")]
+fn read_memory() -> Result<(u64, u64), String> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo")
+        .map_err(|e| format!("Failed to read /proc/meminfo: {}", e))?;
+    let field_kb = |key: &str| {
+        meminfo
+            .lines()
+            .find_map(|line| line.strip_prefix(key))
+            .and_then(|value| value.trim_start_matches(':').split_whitespace().next())
+            .and_then(|value| value.parse::<u64>().ok())
+    };
+
+    match (field_kb("MemTotal"), field_kb("MemAvailable")) {
+        (Some(total), Some(available)) => Ok((total * 1024, available * 1024)),
+        _ => Err("Unexpected /proc/meminfo content".to_string()),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn read_memory() -> Result<(u64, u64), String> {
+    use std::process::Command;
+
+    let total = Command::new("sysctl")
+        .arg("-n")
+        .arg("hw.memsize")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8_lossy(&output.stdout).trim().parse::<u64>().ok())
+        .ok_or("Failed to read hw.memsize")?;
+
+    // "Mach Virtual Memory Statistics: (page size of 16384 bytes)", then "Pages free: 1234."
+    let output = Command::new("vm_stat")
+        .output()
+        .map_err(|e| format!("Failed to run vm_stat: {}", e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let page_size = stdout
+        .split("page size of ")
+        .nth(1)
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(4096);
+    let pages = |key: &str| {
+        stdout
+            .lines()
+            .find_map(|line| line.strip_prefix(key))
+            .and_then(|value| value.trim_start_matches(':').trim().trim_end_matches('.').parse::<u64>().ok())
+            .unwrap_or(0)
+    };
+
+    // Free, plus pages the OS reclaims without swapping
+    let available_pages = pages("Pages free") + pages("Pages inactive") + pages("Pages speculative") + pages("Pages purgeable");
+    Ok((total, available_pages * page_size))
+}
+
+#[cfg(target_os = "windows")]
+fn read_memory() -> Result<(u64, u64), String> {
+    use std::process::Command;
+
+    let output = Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg("$os = Get-CimInstance Win32_OperatingSystem; \"$($os.TotalVisibleMemorySize) $($os.FreePhysicalMemory)\"")
+        .output()
+        .map_err(|e| format!("Failed to query memory: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut fields = stdout.split_whitespace().map(|v| v.parse::<u64>().ok());
+    match (fields.next().flatten(), fields.next().flatten()) {
+        (Some(total_kb), Some(free_kb)) => Ok((total_kb * 1024, free_kb * 1024)),
+        _ => Err(format!("Unexpected memory output: {}", stdout.trim())),
+    }
+}
+
+pub fn system_memory() -> Result<SystemMemory, String> {
+    let (total_bytes, available_bytes) = read_memory()?;
+
+    Ok(SystemMemory {
+        total_bytes,
+        available_bytes,
+        sim_required_bytes: MIN_AVAILABLE_BYTES_FOR_SIM,
+        sufficient_for_sim: available_bytes >= MIN_AVAILABLE_BYTES_FOR_SIM,
+    })
+}
+
+/// Total and available RAM, with what MuJoCo simulation needs
+#[tauri::command]
+pub fn get_system_memory() -> Result<SystemMemory, String> {
+    system_memory()
+}
+
+// ============================================================================
+// DATASET CACHE
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct CacheCleanup {
+    pub path: String,
+    pub freed_bytes: u64,
+}
+
+/// Hugging Face hub cache used by the daemon, resolved like huggingface_hub
+/// does from the environment the daemon gets (dataset_location setting, then
+/// HF_HUB_CACHE, then HF_HOME/hub, then XDG_CACHE_HOME or ~/.cache)
+pub fn dataset_cache_dir(settings: &crate::settings::AppSettings) -> Option<std::path::PathBuf> {
+    use std::path::PathBuf;
+
+    let non_empty = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty()).map(PathBuf::from);
+
+    if let Some(ref location) = settings.dataset_location {
+        return Some(location.clone());
+    }
+    if let Some(hub_cache) = non_empty(HUB_CACHE_ENV) {
+        return Some(hub_cache);
+    }
+    // HF_HOME the sidecars get for a relocated venv (trampoline_env)
+    if let Some(ref location) = settings.venv_location {
+        return Some(crate::python::relocated_hf_home(location).join("hub"));
+    }
+    if let Some(hf_home) = non_empty("HF_HOME") {
+        return Some(hf_home.join("hub"));
+    }
+
+    let cache_home = non_empty("XDG_CACHE_HOME")
+        .or_else(|| non_empty("HOME").or_else(|| non_empty("USERPROFILE")).map(|home| home.join(".cache")))?;
+    Some(cache_home.join("huggingface").join("hub"))
+}
+
+/// Total size of a directory tree, without following symlinks (the hub cache
+/// links snapshots to blobs, which would otherwise be counted twice)
+pub fn dir_size(dir: &std::path::Path) -> u64 {
+    let mut total = 0;
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let entries = match std::fs::read_dir(&current) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            match entry.file_type() {
+                Ok(file_type) if file_type.is_dir() => pending.push(entry.path()),
+                Ok(_) => total += entry.metadata().map(|m| m.len()).unwrap_or(0),
+                Err(_) => {}
+            }
+        }
+    }
+
+    total
+}
+
+/// Top-level entries huggingface_hub creates in its cache: one folder per
+/// repo ("datasets--org--name", "models--...", "spaces--...") and the locks
+fn is_hub_cache_entry(name: &str) -> bool {
+    name == ".locks" || ["datasets--", "models--", "spaces--"].iter().any(|prefix| name.starts_with(prefix))
+}
+
+/// Remove the Hugging Face cache (preloaded datasets, models), returning the bytes freed
+///
+/// Only the entries huggingface_hub created are deleted: the cache folder
+/// may be one the user chose (dataset_location) and hold other files.
+/// Refuses while a daemon is running or attached, its files may be in use.
+#[tauri::command]
+pub fn clear_dataset_cache(app_handle: tauri::AppHandle, state: tauri::State<crate::daemon::DaemonState>) -> Result<CacheCleanup, String> {
+    if state.process.lock().unwrap().is_some() || *state.attached.lock().unwrap() {
+        return Err("Stop the daemon before clearing the dataset cache".to_string());
+    }
+
+    let cache_dir = dataset_cache_dir(&crate::settings::current(&app_handle)).ok_or("Unable to locate the Hugging Face cache")?;
+    let path = cache_dir.display().to_string();
+
+    if !cache_dir.exists() {
+        return Ok(CacheCleanup { path, freed_bytes: 0 });
+    }
+
+    let entries = std::fs::read_dir(&cache_dir).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let mut freed_bytes = 0;
+    for entry in entries.flatten() {
+        if !is_hub_cache_entry(&entry.file_name().to_string_lossy()) {
+            continue;
+        }
+        let entry_path = entry.path();
+        let removed = match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => {
+                let size = dir_size(&entry_path);
+                std::fs::remove_dir_all(&entry_path).map(|()| size)
+            }
+            _ => {
+                let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+                std::fs::remove_file(&entry_path).map(|()| size)
+            }
+        };
+        freed_bytes += removed.map_err(|e| format!("Failed to remove {}: {}", entry_path.display(), e))?;
+    }
+
+    println!("[tauri] 🧹 Cleared dataset cache {} ({} MB freed)", path, freed_bytes / (1024 * 1024));
+
+    Ok(CacheCleanup { path, freed_bytes })
+}
+
+/// Environment variable huggingface_hub reads the dataset cache location from
+pub const HUB_CACHE_ENV: &str = "HF_HUB_CACHE";
+
+#[derive(Serialize)]
+pub struct DatasetLocation {
+    /// None when no cache location could be resolved
+    pub path: Option<String>,
+    /// Where the location comes from: "setting", "HF_HUB_CACHE", "HF_HOME" or "default"
+    pub source: String,
+    pub exists: bool,
+    pub size_bytes: u64,
+}
+
+fn dataset_location(app_handle: &tauri::AppHandle) -> DatasetLocation {
+    let settings = crate::settings::current(app_handle);
+    let is_set = |name: &str| std::env::var_os(name).is_some_and(|value| !value.is_empty());
+    let source = if settings.dataset_location.is_some() {
+        "setting"
+    } else if is_set(HUB_CACHE_ENV) {
+        HUB_CACHE_ENV
+    } else if is_set("HF_HOME") || settings.venv_location.is_some() {
+        "HF_HOME"
+    } else {
+        "default"
+    };
+
+    let path = dataset_cache_dir(&settings);
+    let exists = path.as_deref().is_some_and(std::path::Path::is_dir);
+    DatasetLocation {
+        size_bytes: path.as_deref().filter(|_| exists).map(dir_size).unwrap_or(0),
+        path: path.map(|path| path.display().to_string()),
+        source: source.to_string(),
+        exists,
+    }
+}
+
+/// Directory the daemon stores its preloaded datasets in, and the space it uses
+#[tauri::command]
+pub async fn get_dataset_location(app_handle: tauri::AppHandle) -> Result<DatasetLocation, String> {
+    tauri::async_runtime::spawn_blocking(move || dataset_location(&app_handle))
+        .await
+        .map_err(|e| format!("Failed to execute dataset location task: {}", e))
+}
+
+/// Store the daemon's datasets in another directory (e.g. a larger drive),
+/// None for the default location. Datasets already downloaded are not moved,
+/// they are downloaded again when missing.
+#[tauri::command]
+pub async fn set_dataset_location(app_handle: tauri::AppHandle, path: Option<String>) -> Result<DatasetLocation, String> {
+    use tauri::Manager;
+
+    {
+        let state = app_handle.state::<crate::daemon::DaemonState>();
+        if state.process.lock().unwrap().is_some() || *state.attached.lock().unwrap() {
+            return Err("Stop the daemon before changing the dataset location".to_string());
+        }
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let location = match path {
+            Some(path) => {
+                let location = std::path::PathBuf::from(&path);
+                if !location.is_absolute() {
+                    return Err(format!("{} is not an absolute path", path));
+                }
+                std::fs::create_dir_all(&location).map_err(|e| format!("Failed to create {}: {}", location.display(), e))?;
+                let probe = location.join(".reachy-mini-write-test");
+                std::fs::write(&probe, b"").map_err(|e| format!("{} is not writable: {}", location.display(), e))?;
+                let _ = std::fs::remove_file(&probe);
+                Some(location)
+            }
+            None => None,
+        };
+
+        crate::settings::update(&app_handle, |settings| settings.dataset_location = location.clone())?;
+        match location {
+            Some(ref location) => println!("[tauri] 📦 Dataset cache set to {}", location.display()),
+            None => println!("[tauri] 📦 Dataset cache back to its default location"),
+        }
+        Ok(dataset_location(&app_handle))
+    })
+    .await
+    .map_err(|e| format!("Failed to execute dataset location task: {}", e))?
+}
+
+// ============================================================================
+// GRAPHICS CAPABILITY
+// ============================================================================
+
+/// Renderer names reported by software (CPU) OpenGL implementations
+const SOFTWARE_RENDERERS: &[&str] = &[
+    "llvmpipe",
+    "softpipe",
+    "swrast",
+    "software rasterizer",
+    "microsoft basic render",
+    "microsoft basic display",
+    "apple software renderer",
+];
+
+#[derive(Serialize)]
+pub struct GraphicsCapability {
+    pub vendor: Option<String>,
+    pub renderer: Option<String>,
+    pub version: Option<String>,
+    /// True when rendering falls back to a CPU rasterizer (very slow for MuJoCo)
+    pub is_software: bool,
+    /// False on headless sessions (no display server to open the viewer on)
+    pub has_display: bool,
+    /// Human-readable warning when MuJoCo sim is likely to fail or be unusable
+    pub warning: Option<String>,
+}
+
+fn is_software_renderer(renderer: &str) -> bool {
+    let renderer = renderer.to_lowercase();
+    SOFTWARE_RENDERERS.iter().any(|name| renderer.contains(name))
+}
+
+/// Value of a "Key: value" line in a tool's output
+#[cfg(not(target_os = "macos"))]
+fn field_value(output: &str, key: &str) -> Option<String> {
+    output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix(key))
+        .map(|value| value.trim_start_matches(':').trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// GL_VENDOR, GL_RENDERER and GL_VERSION of a throwaway CGL context, the
+/// renderer MuJoCo's OpenGL context will get (the chipset model doesn't tell
+/// a software fallback apart)
+#[cfg(target_os = "macos")]
+fn probe_graphics() -> GraphicsCapability {
+    use std::ffi::{c_void, CStr};
+    use std::os::raw::{c_int, c_uint};
+
+    #[link(name = "OpenGL", kind = "framework")]
+    extern "C" {
+        fn CGLChoosePixelFormat(attribs: *const c_int, pix: *mut *mut c_void, npix: *mut c_int) -> c_int;
+        fn CGLDestroyPixelFormat(pix: *mut c_void) -> c_int;
+        fn CGLCreateContext(pix: *mut c_void, share: *mut c_void, ctx: *mut *mut c_void) -> c_int;
+        fn CGLDestroyContext(ctx: *mut c_void) -> c_int;
+        fn CGLGetCurrentContext() -> *mut c_void;
+        fn CGLSetCurrentContext(ctx: *mut c_void) -> c_int;
+        fn glGetString(name: c_uint) -> *const u8;
+    }
+    const GL_VENDOR: c_uint = 0x1F00;
+    const GL_RENDERER: c_uint = 0x1F01;
+    const GL_VERSION: c_uint = 0x1F02;
+
+    let (mut vendor, mut renderer, mut version) = (None, None, None);
+    unsafe {
+        let gl_string = |name| {
+            let value = glGetString(name);
+            (!value.is_null()).then(|| CStr::from_ptr(value as *const _).to_string_lossy().into_owned())
+        };
+
+        let attribs: [c_int; 1] = [0];
+        let (mut pixel_format, mut count) = (std::ptr::null_mut(), 0);
+        if CGLChoosePixelFormat(attribs.as_ptr(), &mut pixel_format, &mut count) == 0 && !pixel_format.is_null() {
+            let mut context = std::ptr::null_mut();
+            if CGLCreateContext(pixel_format, std::ptr::null_mut(), &mut context) == 0 && !context.is_null() {
+                let previous = CGLGetCurrentContext();
+                CGLSetCurrentContext(context);
+                vendor = gl_string(GL_VENDOR);
+                renderer = gl_string(GL_RENDERER);
+                version = gl_string(GL_VERSION);
+                CGLSetCurrentContext(previous);
+                CGLDestroyContext(context);
+            }
+            CGLDestroyPixelFormat(pixel_format);
+        }
+    }
+
+    GraphicsCapability {
+        vendor,
+        is_software: renderer.as_deref().map(is_software_renderer).unwrap_or(false),
+        renderer,
+        version,
+        has_display: true,
+        warning: None,
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn probe_graphics() -> GraphicsCapability {
+    use std::process::Command;
+
+    let output = Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg("Get-CimInstance Win32_VideoController | Select-Object -First 1 | ForEach-Object { \"Vendor: $($_.AdapterCompatibility)\"; \"Renderer: $($_.Name)\"; \"Version: $($_.DriverVersion)\" }")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        .unwrap_or_default();
+
+    let renderer = field_value(&output, "Renderer");
+
+    GraphicsCapability {
+        vendor: field_value(&output, "Vendor"),
+        is_software: renderer.as_deref().map(is_software_renderer).unwrap_or(false),
+        renderer,
+        version: field_value(&output, "Version"),
+        has_display: true,
+        warning: None,
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn probe_graphics() -> GraphicsCapability {
+    use std::process::Command;
+
+    let has_display = std::env::var_os("DISPLAY").is_some() || std::env::var_os("WAYLAND_DISPLAY").is_some();
+
+    // glxinfo needs an X/XWayland display, eglinfo also works headless
+    let output = Command::new("glxinfo")
+        .arg("-B")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .or_else(|| Command::new("eglinfo").arg("-B").output().ok().filter(|output| output.status.success()))
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        .unwrap_or_default();
+
+    let renderer = field_value(&output, "OpenGL renderer string");
+
+    GraphicsCapability {
+        vendor: field_value(&output, "OpenGL vendor string"),
+        is_software: renderer.as_deref().map(is_software_renderer).unwrap_or(false),
+        renderer,
+        version: field_value(&output, "OpenGL version string"),
+        has_display,
+        warning: None,
+    }
+}
+
+/// Probe the graphics stack so the UI can warn before launching MuJoCo sim
+#[tauri::command]
+pub fn check_graphics_capability() -> GraphicsCapability {
+    let mut capability = probe_graphics();
+
+    capability.warning = if !capability.has_display {
+        Some("No display available: the MuJoCo viewer cannot open, use the mockup simulation instead".to_string())
+    } else if capability.renderer.is_none() {
+        Some("Unable to detect an OpenGL renderer, MuJoCo simulation may fail to start".to_string())
+    } else if capability.is_software {
+        Some(format!(
+            "Software rendering detected ({}), MuJoCo simulation will be very slow",
+            capability.renderer.as_deref().unwrap_or_default()
+        ))
+    } else {
+        None
+    };
+
+    if let Some(ref w) = capability.warning {
+        println!("[tauri] ⚠️  {}", w);
+    }
+
+    capability
+}
+
+// ============================================================================
+// NETWORK REACHABILITY
+// ============================================================================
+
+/// Services contacted during installs and updates
+const REQUIRED_ENDPOINTS: &[(&str, &str)] = &[
+    ("PyPI", "https://pypi.org/simple/"),
+    ("uv (Astral)", "https://astral.sh/"),
+    ("GitHub", "https://github.com/"),
+    ("Hugging Face", "https://huggingface.co/"),
+];
+
+const NETWORK_TIMEOUT_SECS: u64 = 5;
+/// Responses slower than this are reported as slow
+const SLOW_LATENCY_MS: u64 = 2000;
+
+#[derive(Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Reachability {
+    Reachable,
+    Slow,
+    Blocked,
+}
+
+#[derive(Serialize)]
+pub struct EndpointCheck {
+    pub name: String,
+    pub url: String,
+    pub status: Reachability,
+    pub latency_ms: Option<u64>,
+    /// Failure reason when blocked
+    pub error: Option<String>,
+}
+
+#[cfg(target_os = "windows")]
+const NULL_DEVICE: &str = "NUL";
+#[cfg(not(target_os = "windows"))]
+const NULL_DEVICE: &str = "/dev/null";
+
+/// HEAD request through curl (bundled with macOS, Windows 10+ and most distros)
+fn check_endpoint(name: &str, url: &str) -> EndpointCheck {
+    use std::process::Command;
+
+    let output = Command::new("curl")
+        .args(["-s", "-I", "-o", NULL_DEVICE, "-w", "%{http_code} %{time_total}"])
+        .args(["-m", &NETWORK_TIMEOUT_SECS.to_string()])
+        .arg(url)
+        .output();
+
+    let (status, latency_ms, error) = match output {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let mut fields = stdout.split_whitespace();
+            let http_code = fields.next().and_then(|v| v.parse::<u16>().ok()).unwrap_or(0);
+            let latency_ms = fields
+                .next()
+                .and_then(|v| v.replace(',', ".").parse::<f64>().ok())
+                .map(|secs| (secs * 1000.0) as u64);
+
+            if http_code == 0 {
+                // curl exit codes: 6 = DNS failure, 7 = connection refused, 28 = timeout, 35/60 = TLS
+                let reason = match output.status.code() {
+                    Some(6) => "DNS resolution failed".to_string(),
+                    Some(7) => "connection refused".to_string(),
+                    Some(28) => format!("no response within {}s", NETWORK_TIMEOUT_SECS),
+                    Some(35) | Some(60) => "TLS handshake failed (proxy or SSL inspection?)".to_string(),
+                    code => format!("curl failed (exit code {:?})", code),
+                };
+                (Reachability::Blocked, None, Some(reason))
+            } else if latency_ms.unwrap_or(0) > SLOW_LATENCY_MS {
+                (Reachability::Slow, latency_ms, None)
+            } else {
+                (Reachability::Reachable, latency_ms, None)
+            }
+        }
+        Err(e) => (Reachability::Blocked, None, Some(format!("Failed to run curl: {}", e))),
+    };
+
+    EndpointCheck {
+        name: name.to_string(),
+        url: url.to_string(),
+        status,
+        latency_ms,
+        error,
+    }
+}
+
+/// Check every required endpoint in parallel
+pub fn network_endpoints_status() -> Vec<EndpointCheck> {
+    let handles: Vec<_> = REQUIRED_ENDPOINTS
+        .iter()
+        .map(|(name, url)| std::thread::spawn(move || check_endpoint(name, url)))
+        .collect();
+
+    handles
+        .into_iter()
+        .zip(REQUIRED_ENDPOINTS)
+        .map(|(handle, (name, url))| {
+            handle.join().unwrap_or_else(|_| EndpointCheck {
+                name: name.to_string(),
+                url: url.to_string(),
+                status: Reachability::Blocked,
+                latency_ms: None,
+                error: Some("check failed".to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Report which of the services needed for installs are reachable
+#[tauri::command]
+pub async fn check_network_endpoints() -> Result<Vec<EndpointCheck>, String> {
+    tauri::async_runtime::spawn_blocking(network_endpoints_status)
+        .await
+        .map_err(|e| format!("Failed to check network: {}", e))
+}
+
+// ============================================================================
+// SANDBOX DETECTION
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct SandboxStatus {
+    pub sandboxed: bool,
+    /// "macos-app-sandbox", "flatpak" or "snap", None when not sandboxed
+    pub kind: Option<String>,
+    /// What gave it away (environment variable, entitlement, marker file)
+    pub evidence: Vec<String>,
+    pub warning: Option<String>,
+}
+
+#[cfg(target_os = "macos")]
+fn detect_sandbox() -> (Option<&'static str>, Vec<String>) {
+    use std::process::Command;
+
+    let mut evidence = Vec::new();
+
+    // Set by the system for every sandboxed process
+    if std::env::var_os("APP_SANDBOX_CONTAINER_ID").is_some() {
+        evidence.push("APP_SANDBOX_CONTAINER_ID is set".to_string());
+    }
+    if std::env::var("HOME").is_ok_and(|home| home.contains("/Library/Containers/")) {
+        evidence.push("HOME is inside a sandbox container".to_string());
+    }
+    if let Some(bundle) = crate::signing::app_bundle_path() {
+        let entitlements = Command::new("codesign")
+            .args(["-d", "--entitlements", ":-"])
+            .arg(&bundle)
+            .output()
+            .map(|output| crate::signing::parse_entitlements(&String::from_utf8_lossy(&output.stdout)))
+            .unwrap_or_default();
+        if entitlements.get("com.apple.security.app-sandbox") == Some(&serde_json::Value::Bool(true)) {
+            evidence.push("app signed with com.apple.security.app-sandbox".to_string());
+        }
+    }
+
+    let kind = (!evidence.is_empty()).then_some("macos-app-sandbox");
+    (kind, evidence)
+}
+
+#[cfg(target_os = "linux")]
+fn detect_sandbox() -> (Option<&'static str>, Vec<String>) {
+    if std::path::Path::new("/.flatpak-info").exists() || std::env::var_os("FLATPAK_ID").is_some() {
+        return (Some("flatpak"), vec!["Flatpak runtime detected (/.flatpak-info, FLATPAK_ID)".to_string()]);
+    }
+    if std::env::var_os("SNAP").is_some() {
+        return (Some("snap"), vec!["SNAP is set".to_string()]);
+    }
+    (None, Vec::new())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn detect_sandbox() -> (Option<&'static str>, Vec<String>) {
+    (None, Vec::new())
+}
+
+/// Whether the app runs inside a sandbox that blocks spawning the daemon and
+/// USB access, which fails differently from translocation or signing issues
+#[tauri::command]
+pub fn check_sandbox() -> SandboxStatus {
+    let (kind, evidence) = detect_sandbox();
+
+    let warning = kind.map(|kind| {
+        println!("[tauri] ⚠️  Running inside a {} sandbox", kind);
+        format!(
+            "The app runs inside a {} sandbox: starting the daemon and reaching the robot over USB may be blocked",
+            kind
+        )
+    });
+
+    SandboxStatus {
+        sandboxed: kind.is_some(),
+        kind: kind.map(str::to_string),
+        evidence,
+        warning,
+    }
+}
+
+// ============================================================================
+// FILE DESCRIPTOR LIMITS
+// ============================================================================
+
+/// Open files the daemon gets (cameras, sockets, dataset files), raised
+/// from conservative defaults such as macOS's 256
+pub const DAEMON_NOFILE_TARGET: u64 = 4096;
+
+#[derive(Clone, Serialize)]
+pub struct FdLimits {
+    /// False on Windows, which has no RLIMIT_NOFILE
+    pub supported: bool,
+    /// Soft limit now in effect, inherited by the daemon
+    pub soft: Option<u64>,
+    pub hard: Option<u64>,
+    /// Soft limit before this call, when it was raised
+    pub raised_from: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[cfg(unix)]
+fn read_nofile() -> Result<libc::rlimit, String> {
+    let mut limit = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+    // SAFETY: getrlimit only writes to the struct we pass
+    if unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, &mut limit) } != 0 {
+        return Err(format!("getrlimit failed: {}", std::io::Error::last_os_error()));
+    }
+    Ok(limit)
+}
+
+/// Finite value of a limit, None for unlimited
+/// (rlim_t is 32-bit on some targets, hence the conversion)
+#[cfg(unix)]
+#[allow(clippy::useless_conversion)]
+fn limit_value(value: libc::rlim_t) -> Option<u64> {
+    (value != libc::RLIM_INFINITY).then(|| u64::from(value))
+}
+
+/// Soft and hard open-files limits of this process, which spawned sidecars inherit
+#[cfg(unix)]
+fn read_fd_limits() -> FdLimits {
+    let mut limits = FdLimits { supported: true, soft: None, hard: None, raised_from: None, error: None };
+    match read_nofile() {
+        Ok(limit) => {
+            limits.soft = limit_value(limit.rlim_cur);
+            limits.hard = limit_value(limit.rlim_max);
+        }
+        Err(e) => limits.error = Some(e),
+    }
+    limits
+}
+
+#[cfg(not(unix))]
+fn read_fd_limits() -> FdLimits {
+    FdLimits { supported: false, soft: None, hard: None, raised_from: None, error: None }
+}
+
+/// Raise this process's soft open-files limit to `target` (capped by the hard
+/// limit); spawned sidecars, and so the daemon, inherit it
+#[cfg(unix)]
+pub fn raise_fd_limit(target: u64) -> FdLimits {
+    let mut limits = read_fd_limits();
+    if limits.error.is_some() {
+        return limits;
+    }
+
+    // macOS rejects soft limits above OPEN_MAX even with an unlimited hard limit
+    let cap = if cfg!(target_os = "macos") { limits.hard.unwrap_or(10240).min(10240) } else { limits.hard.unwrap_or(target) };
+    let wanted = target.min(cap);
+    if limits.soft.is_none_or(|soft| soft >= wanted) {
+        return limits;
+    }
+
+    let limit = libc::rlimit {
+        rlim_cur: libc::rlim_t::try_from(wanted).unwrap_or(libc::RLIM_INFINITY),
+        rlim_max: limits.hard.and_then(|hard| libc::rlim_t::try_from(hard).ok()).unwrap_or(libc::RLIM_INFINITY),
+    };
+    // SAFETY: setrlimit only reads the struct we pass
+    if unsafe { libc::setrlimit(libc::RLIMIT_NOFILE, &limit) } != 0 {
+        limits.error = Some(format!("setrlimit to {} failed: {}", wanted, std::io::Error::last_os_error()));
+        return limits;
+    }
+    limits.raised_from = limits.soft;
+    limits.soft = Some(wanted);
+    limits
+}
+
+#[cfg(not(unix))]
+pub fn raise_fd_limit(_target: u64) -> FdLimits {
+    read_fd_limits()
+}
+
+/// Raise the open-files limit before spawning the daemon, logging the outcome
+pub fn ensure_daemon_fd_limit() {
+    let limits = raise_fd_limit(DAEMON_NOFILE_TARGET);
+    let show = |value: Option<u64>| value.map_or_else(|| "unlimited".to_string(), |value| value.to_string());
+    match (&limits.error, limits.raised_from) {
+        (Some(e), _) => println!("[tauri] ⚠️  Open files limit stays at {}: {}", show(limits.soft), e),
+        (None, Some(from)) => println!("[tauri] 📂 Open files limit raised from {} to {}", from, show(limits.soft)),
+        (None, None) if limits.supported => println!("[tauri] 📂 Open files limit: {} (hard {})", show(limits.soft), show(limits.hard)),
+        (None, None) => {}
+    }
+}
+
+/// Current open-files limits, read only. A running daemon keeps the limit it
+/// was started with.
+#[tauri::command]
+pub fn get_fd_limits() -> FdLimits {
+    read_fd_limits()
+}
+
+/// Raise the soft open-files limit to `target` (default DAEMON_NOFILE_TARGET)
+/// where the hard limit allows, for the next daemon start
+#[tauri::command]
+pub fn raise_fd_limits(target: Option<u64>) -> FdLimits {
+    let limits = raise_fd_limit(target.unwrap_or(DAEMON_NOFILE_TARGET));
+    if let Some(from) = limits.raised_from {
+        println!("[tauri] 📂 Open files limit raised from {} to {}", from, limits.soft.unwrap_or_default());
+    }
+    limits
+}
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+use crate::settings::UpdateCheckInterval;
+
+const UPDATE_HISTORY_FILE: &str = "update_history.json";
+/// Version of the previous launch, also kept for launches that aren't updates
+const LAST_VERSION_FILE: &str = "last_version";
+/// Unix timestamp (seconds) of the last completed update check
+const LAST_CHECK_FILE: &str = "last_update_check";
+/// Entries kept in the history, oldest are dropped first
+const MAX_HISTORY_ENTRIES: usize = 20;
+
+// ============================================================================
+// UPDATE HISTORY
+// ============================================================================
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct UpdateEntry {
+    /// Version running before the update (None in entries written by older
+    /// versions for the first launch)
+    pub from_version: Option<String>,
+    pub to_version: String,
+    /// Unix timestamp (seconds) of the first launch of `to_version`
+    pub date: u64,
+    /// "stable", or "prerelease" for versions with a pre-release tag (e.g. 1.2.0-beta.1)
+    pub channel: String,
+}
+
+fn data_file(app_handle: &AppHandle, name: &str) -> Result<PathBuf, String> {
+    app_handle
+        .path()
+        .app_data_dir()
+        .map(|dir| dir.join(name))
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn channel_of(version: &str) -> &'static str {
+    if version.contains('-') {
+        "prerelease"
+    } else {
+        "stable"
+    }
+}
+
+/// Recorded updates, oldest first (empty when the file is missing or unreadable)
+pub fn history(app_handle: &AppHandle) -> Vec<UpdateEntry> {
+    data_file(app_handle, UPDATE_HISTORY_FILE)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Version of the previous launch, from the history for launches recorded
+/// before LAST_VERSION_FILE existed
+fn last_version(app_handle: &AppHandle, entries: &[UpdateEntry]) -> Option<String> {
+    data_file(app_handle, LAST_VERSION_FILE)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .map(|version| version.trim().to_string())
+        .filter(|version| !version.is_empty())
+        .or_else(|| entries.last().map(|entry| entry.to_version.clone()))
+}
+
+fn write_data_file(app_handle: &AppHandle, name: &str, content: &str) -> Result<(), String> {
+    let path = data_file(app_handle, name)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create app data dir {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Append an entry when the running version is newer than the previous launch's
+///
+/// The updater relaunches the app after installing, so the first launch of a
+/// new version is where a successful update is observed. A fresh install or a
+/// downgrade is not an update and only becomes the version later ones compare to.
+pub fn record_current_version(app_handle: &AppHandle) -> Result<(), String> {
+    let current = app_handle.package_info().version.clone();
+    let mut entries = history(app_handle);
+
+    let previous = last_version(app_handle, &entries);
+    if previous.as_deref() == Some(current.to_string().as_str()) {
+        return Ok(());
+    }
+    write_data_file(app_handle, LAST_VERSION_FILE, &current.to_string())?;
+
+    let from_version = match previous {
+        Some(previous) if semver::Version::parse(&previous).is_ok_and(|previous| current > previous) => previous,
+        Some(previous) => {
+            println!("[tauri] 📝 Running {} after {}, not recorded as an update", current, previous);
+            return Ok(());
+        }
+        None => {
+            println!("[tauri] 📝 First launch of {}", current);
+            return Ok(());
+        }
+    };
+
+    entries.push(UpdateEntry {
+        from_version: Some(from_version.clone()),
+        channel: channel_of(&current.to_string()).to_string(),
+        to_version: current.to_string(),
+        date: now_secs(),
+    });
+    if entries.len() > MAX_HISTORY_ENTRIES {
+        entries.drain(..entries.len() - MAX_HISTORY_ENTRIES);
+    }
+
+    let content = serde_json::to_string_pretty(&entries)
+        .map_err(|e| format!("Failed to serialize update history: {}", e))?;
+    write_data_file(app_handle, UPDATE_HISTORY_FILE, &content)?;
+
+    println!("[tauri] ⬆️  Updated from {} to {}", from_version, current);
+    Ok(())
+}
+
+/// Versions installed by the updater, most recent first
+#[tauri::command]
+pub fn get_update_history(app_handle: AppHandle) -> Vec<UpdateEntry> {
+    let mut entries = history(&app_handle);
+    entries.reverse();
+    entries
+}
+
+// ============================================================================
+// UPDATE CHECK SCHEDULE
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct UpdateCheckSchedule {
+    pub interval: UpdateCheckInterval,
+    /// Unix timestamp (seconds) of the last completed check, None if never checked
+    pub last_check: Option<u64>,
+}
+
+fn last_check(app_handle: &AppHandle) -> Option<u64> {
+    let path = data_file(app_handle, LAST_CHECK_FILE).ok()?;
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+#[tauri::command]
+pub fn get_update_check_interval(app_handle: AppHandle) -> UpdateCheckSchedule {
+    UpdateCheckSchedule {
+        interval: crate::settings::current(&app_handle).update_check_interval,
+        last_check: last_check(&app_handle),
+    }
+}
+
+#[tauri::command]
+pub fn set_update_check_interval(app_handle: AppHandle, interval: UpdateCheckInterval) -> Result<UpdateCheckSchedule, String> {
+    crate::settings::update(&app_handle, |settings| settings.update_check_interval = interval)?;
+    Ok(get_update_check_interval(app_handle))
+}
+
+/// Whether an automatic update check is due (`on_launch` for the check made at startup)
+/// Checks requested by the user don't go through this.
+#[tauri::command]
+pub fn should_check_for_updates(app_handle: AppHandle, on_launch: bool) -> bool {
+    const HOUR: u64 = 60 * 60;
+
+    let elapsed = last_check(&app_handle).map(|last| now_secs().saturating_sub(last));
+    let due_after = |period: u64| elapsed.map(|elapsed| elapsed >= period).unwrap_or(true);
+
+    match crate::settings::current(&app_handle).update_check_interval {
+        UpdateCheckInterval::Hourly => on_launch || due_after(HOUR),
+        UpdateCheckInterval::OnLaunch => on_launch,
+        UpdateCheckInterval::Daily => due_after(24 * HOUR),
+        UpdateCheckInterval::Weekly => due_after(7 * 24 * HOUR),
+        UpdateCheckInterval::Never => false,
+    }
+}
+
+/// Remember when the updater last completed a check
+#[tauri::command]
+pub fn record_update_check(app_handle: AppHandle) -> Result<(), String> {
+    let path = data_file(&app_handle, LAST_CHECK_FILE)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create app data dir {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(&path, now_secs().to_string())
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
@@ -1,20 +1,289 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 use serialport;
+use serde::Serialize;
+
+// Reachy Mini CH340 USB identifiers
+const ROBOT_VID: u16 = 0x1a86;
+const ROBOT_PID: u16 = 0x55d3;
+
+/// Give up on serial port enumeration after this long
+const ENUMERATION_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Set when an enumeration outlived ENUMERATION_TIMEOUT and is still running,
+/// so a hung one isn't joined by more
+static ENUMERATION_HUNG: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Serialize)]
+pub struct RobotDevice {
+    pub port_name: String,
+    pub serial_number: Option<String>,
+    pub manufacturer: Option<String>,
+    pub product: Option<String>,
+}
+
+impl RobotDevice {
+    /// True if the selector is this robot's serial number or port name
+    pub fn matches(&self, selector: &str) -> bool {
+        self.serial_number.as_deref() == Some(selector) || self.port_name == selector
+    }
+}
+
+/// serialport::available_ports on a background thread, bounded by ENUMERATION_TIMEOUT
+///
+/// Some Linux hosts have serial devices that make enumeration block; the
+/// stuck thread is left behind and further calls fail fast until it returns.
+/// Overlapping calls (link monitor, preflight, start_daemon) each enumerate.
+fn available_ports() -> Result<Vec<serialport::SerialPortInfo>, String> {
+    if ENUMERATION_HUNG.load(Ordering::SeqCst) {
+        return Err("USB detection error: a previous enumeration is still hanging".to_string());
+    }
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = sender.send(serialport::available_ports());
+        ENUMERATION_HUNG.store(false, Ordering::SeqCst);
+    });
+
+    let received = match receiver.recv_timeout(ENUMERATION_TIMEOUT) {
+        Ok(result) => Some(result),
+        Err(_) => {
+            ENUMERATION_HUNG.store(true, Ordering::SeqCst);
+            // Finished right at the deadline: the thread may have cleared the flag already
+            let late = receiver.try_recv().ok();
+            if late.is_some() {
+                ENUMERATION_HUNG.store(false, Ordering::SeqCst);
+            }
+            late
+        }
+    };
+
+    match received {
+        Some(Ok(ports)) => Ok(ports),
+        Some(Err(e)) => {
+            eprintln!("[tauri] ⚠️  Serial port enumeration failed: {}", e);
+            Err(format!("USB detection error: {}", e))
+        }
+        None => {
+            eprintln!("[tauri] ⚠️  Serial port enumeration timed out after {}s", ENUMERATION_TIMEOUT.as_secs());
+            Err(format!("USB detection error: enumeration timed out after {}s", ENUMERATION_TIMEOUT.as_secs()))
+        }
+    }
+}
+
+/// Find all connected Reachy Mini robots
+pub fn find_robots() -> Result<Vec<RobotDevice>, String> {
+    let ports = available_ports()?;
+
+    Ok(ports
+        .into_iter()
+        .filter_map(|port| match port.port_type {
+            serialport::SerialPortType::UsbPort(usb_info)
+                if usb_info.vid == ROBOT_VID && usb_info.pid == ROBOT_PID =>
+            {
+                Some(RobotDevice {
+                    port_name: port.port_name,
+                    serial_number: usb_info.serial_number,
+                    manufacturer: usb_info.manufacturer,
+                    product: usb_info.product,
+                })
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+/// find_robots off the command thread
+async fn find_robots_async() -> Result<Vec<RobotDevice>, String> {
+    tauri::async_runtime::spawn_blocking(find_robots)
+        .await
+        .map_err(|e| format!("Failed to execute USB detection task: {}", e))?
+}
 
 #[tauri::command]
-pub fn check_usb_robot() -> Result<Option<String>, String> {
-    match serialport::available_ports() {
-        Ok(ports) => {
-            // Look for USB device with VID:PID = 1a86:55d3 (Reachy Mini CH340)
-            for port in ports {
-                if let serialport::SerialPortType::UsbPort(usb_info) = &port.port_type {
-                    if usb_info.vid == 0x1a86 && usb_info.pid == 0x55d3 {
-                        return Ok(Some(port.port_name.clone()));
-                    }
+pub async fn check_usb_robot() -> Result<Option<String>, String> {
+    // Look for USB device with VID:PID = 1a86:55d3 (Reachy Mini CH340)
+    Ok(find_robots_async().await?.into_iter().next().map(|robot| robot.port_name))
+}
+
+/// List every connected robot, for hosts with several units plugged in
+#[tauri::command]
+pub async fn list_robots() -> Result<Vec<RobotDevice>, String> {
+    find_robots_async().await
+}
+
+/// Pick the robot to launch the daemon against
+/// 
+/// An explicit selector (serial number or port) must match a connected robot.
+/// Without one, the last used robot is chosen again when it's still connected;
+/// otherwise None lets the daemon pick the first robot it finds.
+pub fn select_robot(selector: Option<&str>, last_serial: Option<&str>) -> Result<Option<RobotDevice>, String> {
+    let robots = match find_robots() {
+        Ok(robots) => robots,
+        // Without an explicit choice, let the daemon do its own detection
+        Err(e) if selector.is_none() => {
+            println!("[tauri] ⚠️  {}", e);
+            return Ok(None);
+        }
+        Err(e) => return Err(e),
+    };
+
+    if let Some(selector) = selector {
+        return robots
+            .into_iter()
+            .find(|robot| robot.matches(selector))
+            .map(Some)
+            .ok_or_else(|| format!("Robot '{}' is not connected", selector));
+    }
+
+    Ok(last_serial.and_then(|serial| robots.into_iter().find(|robot| robot.matches(serial))))
+}
+
+// ============================================================================
+// USB TREE
+// ============================================================================
+
+/// Largest platform listing kept (ioreg output can be huge)
+const MAX_PLATFORM_LISTING_BYTES: usize = 256 * 1024;
+
+#[derive(Serialize)]
+pub struct SerialPortEntry {
+    pub port_name: String,
+    /// "usb", "pci", "bluetooth" or "unknown"
+    pub port_type: String,
+    pub vid: Option<u16>,
+    pub pid: Option<u16>,
+    pub serial_number: Option<String>,
+    pub manufacturer: Option<String>,
+    pub product: Option<String>,
+    /// Matches the Reachy Mini VID:PID
+    pub is_robot: bool,
+}
+
+#[derive(Serialize)]
+pub struct UsbTree {
+    pub serial_ports: Vec<SerialPortEntry>,
+    pub serial_error: Option<String>,
+    /// Every USB device seen by the OS, serial or not (ioreg, sysfs, PnP)
+    pub platform_listing: Option<String>,
+    pub platform_error: Option<String>,
+}
+
+fn serial_port_entry(port: serialport::SerialPortInfo) -> SerialPortEntry {
+    let mut entry = SerialPortEntry {
+        port_name: port.port_name,
+        port_type: "unknown".to_string(),
+        vid: None,
+        pid: None,
+        serial_number: None,
+        manufacturer: None,
+        product: None,
+        is_robot: false,
+    };
+
+    match port.port_type {
+        serialport::SerialPortType::UsbPort(usb_info) => {
+            entry.port_type = "usb".to_string();
+            entry.is_robot = usb_info.vid == ROBOT_VID && usb_info.pid == ROBOT_PID;
+            entry.vid = Some(usb_info.vid);
+            entry.pid = Some(usb_info.pid);
+            entry.serial_number = usb_info.serial_number;
+            entry.manufacturer = usb_info.manufacturer;
+            entry.product = usb_info.product;
+        }
+        serialport::SerialPortType::PciPort => entry.port_type = "pci".to_string(),
+        serialport::SerialPortType::BluetoothPort => entry.port_type = "bluetooth".to_string(),
+        serialport::SerialPortType::Unknown => {}
+    }
+
+    entry
+}
+
+#[cfg(target_os = "macos")]
+fn platform_usb_listing() -> Result<String, String> {
+    let output = std::process::Command::new("ioreg")
+        .args(["-p", "IOUSB", "-l", "-w", "0"])
+        .output()
+        .map_err(|e| format!("Failed to run ioreg: {}", e))?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// lsusb equivalent read from sysfs (lsusb itself isn't always installed)
+#[cfg(target_os = "linux")]
+fn platform_usb_listing() -> Result<String, String> {
+    let entries = std::fs::read_dir("/sys/bus/usb/devices")
+        .map_err(|e| format!("Failed to read /sys/bus/usb/devices: {}", e))?;
+    let mut devices: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+    devices.sort();
+
+    let mut listing = String::new();
+    for device in devices {
+        let read = |file: &str| std::fs::read_to_string(device.join(file)).map(|v| v.trim().to_string()).ok();
+        // Interfaces (e.g. 1-1:1.0) have no idVendor, only devices are listed
+        let (vid, pid) = match (read("idVendor"), read("idProduct")) {
+            (Some(vid), Some(pid)) => (vid, pid),
+            _ => continue,
+        };
+        listing.push_str(&format!(
+            "{} {}:{} {} {} serial={} speed={}Mb/s\n",
+            device.file_name().unwrap_or_default().to_string_lossy(),
+            vid,
+            pid,
+            read("manufacturer").unwrap_or_default(),
+            read("product").unwrap_or_default(),
+            read("serial").unwrap_or_default(),
+            read("speed").unwrap_or_default(),
+        ));
+    }
+    Ok(listing)
+}
+
+#[cfg(target_os = "windows")]
+fn platform_usb_listing() -> Result<String, String> {
+    let output = std::process::Command::new("powershell")
+        .arg("-NoProfile")
+        .arg("-Command")
+        .arg("Get-PnpDevice -PresentOnly | Where-Object { $_.InstanceId -like 'USB*' } | ForEach-Object { \"$($_.InstanceId) [$($_.Status)] $($_.Class): $($_.FriendlyName)\" }")
+        .output()
+        .map_err(|e| format!("Failed to query PnP devices: {}", e))?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Serial ports with all their USB fields, plus the OS view of the USB bus
+pub fn usb_tree() -> UsbTree {
+    let (serial_ports, serial_error) = match available_ports() {
+        Ok(ports) => (ports.into_iter().map(serial_port_entry).collect(), None),
+        Err(e) => (Vec::new(), Some(e)),
+    };
+
+    let (platform_listing, platform_error) = match platform_usb_listing() {
+        Ok(mut listing) => {
+            if listing.len() > MAX_PLATFORM_LISTING_BYTES {
+                let mut end = MAX_PLATFORM_LISTING_BYTES;
+                while !listing.is_char_boundary(end) {
+                    end -= 1;
                 }
+                listing.truncate(end);
+                listing.push_str("\n[truncated]");
             }
-            Ok(None)
+            (Some(listing), None)
         }
-        Err(e) => Err(format!("USB detection error: {}", e)),
+        Err(e) => (None, Some(e)),
+    };
+
+    UsbTree {
+        serial_ports,
+        serial_error,
+        platform_listing,
+        platform_error,
     }
 }
 
+/// Dump everything on the USB bus, to tell a robot enumerating with an
+/// unexpected VID:PID from one that doesn't enumerate at all
+#[tauri::command]
+pub async fn dump_usb_tree() -> Result<UsbTree, String> {
+    tauri::async_runtime::spawn_blocking(usb_tree)
+        .await
+        .map_err(|e| format!("Failed to execute USB dump task: {}", e))
+}
@@ -1,34 +1,48 @@
 use tauri::{Manager, AppHandle};
 
+/// NSWindowStyleMaskFullSizeContentView
+#[cfg(target_os = "macos")]
+const FULL_SIZE_CONTENT_VIEW: u64 = 1 << 15;
+
+/// Switch a window between the transparent full-size-content titlebar and
+/// the standard macOS titlebar
+#[cfg(target_os = "macos")]
+pub fn set_titlebar_style(window: &tauri::WebviewWindow, transparent: bool) -> Result<(), String> {
+    use cocoa::base::{id, NO, YES};
+    use objc::{msg_send, sel, sel_impl};
+    
+    let ns_window_ptr = window.ns_window().map_err(|e| format!("Failed to get ns_window: {}", e))?;
+    
+    unsafe {
+        let ns_window = ns_window_ptr as id;
+        
+        // Transparent titlebar and fullscreen content
+        let _: () = msg_send![ns_window, setTitlebarAppearsTransparent: if transparent { YES } else { NO }];
+        
+        // Full size content view so content goes under titlebar
+        let style_mask: u64 = msg_send![ns_window, styleMask];
+        let new_style = if transparent {
+            style_mask | FULL_SIZE_CONTENT_VIEW
+        } else {
+            style_mask & !FULL_SIZE_CONTENT_VIEW
+        };
+        let _: () = msg_send![ns_window, setStyleMask: new_style];
+    }
+    
+    Ok(())
+}
+
 #[tauri::command]
 pub fn apply_transparent_titlebar(_app: AppHandle, _window_label: String) -> Result<(), String> {
     #[cfg(target_os = "macos")]
     {
-        if let Some(window) = _app.get_webview_window(&_window_label) {
-            use cocoa::base::{id, YES};
-            use objc::{msg_send, sel, sel_impl};
-            
-            let ns_window_result = window.ns_window();
-            match ns_window_result {
-                Ok(ns_window_ptr) => {
-                    unsafe {
-                        let ns_window = ns_window_ptr as id;
-                        
-                        // Transparent titlebar and fullscreen content
-                        let _: () = msg_send![ns_window, setTitlebarAppearsTransparent: YES];
-                        
-                        // Full size content view so content goes under titlebar
-                        let style_mask: u64 = msg_send![ns_window, styleMask];
-                        let new_style = style_mask | (1 << 15); // NSWindowStyleMaskFullSizeContentView
-                        let _: () = msg_send![ns_window, setStyleMask: new_style];
-                    }
-                    Ok(())
-                }
-                Err(e) => Err(format!("Failed to get ns_window: {}", e)),
-            }
-        } else {
-            Err(format!("Window '{}' not found", _window_label))
-        }
+        let window = _app
+            .get_webview_window(&_window_label)
+            .ok_or_else(|| format!("Window '{}' not found", _window_label))?;
+        
+        // Respect the user's choice of a standard titlebar
+        let transparent = !crate::settings::current(&_app).standard_titlebar;
+        set_titlebar_style(&window, transparent)
     }
     
     #[cfg(not(target_os = "macos"))]
@@ -38,6 +52,20 @@ pub fn apply_transparent_titlebar(_app: AppHandle, _window_label: String) -> Res
     }
 }
 
+/// Toggle the transparent titlebar on every window and remember the choice
+/// (a standard titlebar keeps window controls visible on light backgrounds)
+#[tauri::command]
+pub fn set_transparent_titlebar(app: AppHandle, enabled: bool) -> Result<(), String> {
+    crate::settings::update(&app, |settings| settings.standard_titlebar = !enabled)?;
+    
+    #[cfg(target_os = "macos")]
+    for window in app.webview_windows().values() {
+        set_titlebar_style(window, enabled)?;
+    }
+    
+    Ok(())
+}
+
 #[tauri::command]
 pub fn close_window(app: AppHandle, window_label: String) -> Result<(), String> {
     if let Some(window) = app.get_webview_window(&window_label) {
@@ -3,6 +3,7 @@
  */
 
 import { logApiCall, logPermission, logTimeout, logError, logSuccess } from '../utils/logging';
+import { invoke, listen, isWebMode } from '../utils/tauriCompat';
 
 export const DAEMON_CONFIG = {
   // API timeouts (in milliseconds)
@@ -135,6 +136,38 @@ export function setAppStoreInstance(store) {
   appStoreInstance = store;
 }
 
+/**
+ * API recording (start_api_recording): daemon calls made here are reported to
+ * the backend so they end up in the recording
+ */
+let apiRecordingActive = false;
+if (!isWebMode) {
+  listen('api-recording-active', (event) => {
+    apiRecordingActive = event.payload === true;
+  });
+}
+
+function reportRecordedCall(method, endpoint, body, status, duration) {
+  if (!apiRecordingActive || !endpoint.startsWith('/api/')) return;
+
+  let parsedBody = null;
+  if (typeof body === 'string') {
+    try {
+      parsedBody = JSON.parse(body);
+    } catch {
+      parsedBody = body;
+    }
+  }
+
+  invoke('record_daemon_call', {
+    method,
+    path: endpoint,
+    body: parsedBody,
+    status,
+    durationMs: duration,
+  }).catch(() => {});
+}
+
 /**
  * Helper to create a fetch with timeout AND automatic logging
  * @param {string} url - Full URL
@@ -222,6 +255,7 @@ export async function fetchWithTimeout(url, options = {}, timeoutMs, logOptions
     
     clearTimeout(timeoutId);
     const duration = Date.now() - startTime;
+    reportRecordedCall(method, endpoint, options.body, response.status, duration);
     
     // Log result if not silent
     if (!shouldBeSilent) {
@@ -242,6 +276,7 @@ export async function fetchWithTimeout(url, options = {}, timeoutMs, logOptions
     return response;
   } catch (error) {
     const duration = Date.now() - startTime;
+    reportRecordedCall(method, endpoint, options.body, null, duration);
     
     // Log error if not silent
     if (!shouldBeSilent) {
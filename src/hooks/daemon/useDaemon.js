@@ -304,8 +304,8 @@ export const useDaemon = () => {
       const simMode = isSimulationMode();
 
       // Launch new daemon (non-blocking - we don't wait for it)
-      // Pass sim_mode parameter to backend
-      invoke('start_daemon', { simMode: simMode }).then(() => {
+      // Pass the launch options to backend as a single config
+      invoke('start_daemon', { config: { sim_mode: simMode } }).then(() => {
         // ✅ Emit success event (handler will log sim mode message)
         eventBus.emit('daemon:start:success', { existing: false, simMode });
       }).catch((e) => {
@@ -1,4 +1,5 @@
 import { useState, useEffect, useCallback, useRef } from 'react';
+import { invoke } from '@tauri-apps/api/core';
 import { check } from '@tauri-apps/plugin-updater';
 import { relaunch } from '@tauri-apps/plugin-process';
 import { extractErrorMessage, formatUserErrorMessage, isRecoverableError as checkRecoverableError, getDetailedUpdateErrorMessage } from '../../utils/errorUtils';
@@ -108,6 +109,7 @@ export const useUpdater = ({
       // Reset retry count on success
       retryCountRef.current = 0;
       lastCheckTimeRef.current = Date.now();
+      invoke('record_update_check').catch(() => {});
       isCheckingRef.current = false;
       setIsChecking(false); // ✅ Ensure isChecking is always set to false on success
       
@@ -463,17 +465,25 @@ export const useUpdater = ({
     };
   }, [autoCheck, checkForUpdates]);
 
+  // Automatic checks follow the user's update check interval setting
+  const checkIfDue = useCallback(async (onLaunch) => {
+    const due = await invoke('should_check_for_updates', { onLaunch }).catch(() => true);
+    if (due) {
+      checkForUpdates();
+    }
+  }, [checkForUpdates]);
+
   // Automatic check on startup (with delay to avoid blocking startup)
   useEffect(() => {
     if (autoCheck && !isCheckingRef.current) {
       // Wait for app to be fully loaded before checking
       const timeout = setTimeout(() => {
-        checkForUpdates();
+        checkIfDue(true);
       }, DAEMON_CONFIG.UPDATE_CHECK.STARTUP_DELAY);
       
       return () => clearTimeout(timeout);
     }
-  }, [autoCheck, checkForUpdates]);
+  }, [autoCheck, checkIfDue]);
 
   // Periodic check (only if no recent check)
   useEffect(() => {
@@ -486,12 +496,12 @@ export const useUpdater = ({
         : Infinity;
       
       if (timeSinceLastCheck > 5 * 60 * 1000) { // 5 minutes
-        checkForUpdates();
+        checkIfDue(false);
       }
     }, checkInterval);
 
     return () => clearInterval(interval);
-  }, [autoCheck, checkInterval, checkForUpdates]);
+  }, [autoCheck, checkInterval, checkIfDue]);
 
   return {
     updateAvailable,
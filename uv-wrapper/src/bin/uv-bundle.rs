@@ -1,5 +1,5 @@
 use clap::Parser;
-use uv_wrapper::run_command;
+use uv_wrapper::{python_request, run_command};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -16,21 +16,103 @@ struct Args {
     #[arg(short, long, value_delimiter = ' ', num_args = 1..)]
     dependencies: Vec<String>,
 
-    /// Source for reachy-mini package: 'pypi' (default) or a GitHub branch name (e.g., 'develop', 'main')
+    /// Source for reachy-mini package: 'pypi' (default), a GitHub branch name (e.g., 'develop', 'main'),
+    /// 'path:<dir>' for a local checkout or 'editable:<dir>' for an editable install of a local checkout
     #[arg(long, default_value = "pypi")]
     reachy_mini_source: String,
+
+    /// Interpreter architecture ("x86_64" or "aarch64"), defaults to the host's.
+    /// Lets an Apple Silicon build ship an x86_64 venv (Rosetta) and vice versa.
+    #[arg(long)]
+    python_arch: Option<String>,
+
+    /// Pass --verbose to uv pip install for detailed resolution and download logs
+    #[arg(long)]
+    verbose: bool,
+
+    /// Copy this uv binary instead of downloading the latest one (offline
+    /// reinstalls from the copy the app caches after a successful setup)
+    #[arg(long)]
+    uv_binary: Option<std::path::PathBuf>,
+
+    /// Maximum parallel downloads (UV_CONCURRENT_DOWNLOADS), uv's default when unset
+    #[arg(long)]
+    concurrent_downloads: Option<u32>,
+
+    /// Maximum parallel package installs (UV_CONCURRENT_INSTALLS), uv's default when unset
+    #[arg(long)]
+    concurrent_installs: Option<u32>,
 }
 
-fn main() {
-    let args = Args::parse();
+/// Where the reachy-mini package is installed from
+enum ReachyMiniSource {
+    Pypi,
+    GitHub(String),
+    Local { path: std::path::PathBuf, editable: bool },
+}
 
-    let install_dir = args.install_dir.clone();
-    let python_version = args.python_version.clone();
+impl ReachyMiniSource {
+    /// Parse the --reachy-mini-source value
+    /// Local paths are resolved against the directory uv-bundle was launched from
+    fn parse(source: &str) -> Result<Self, String> {
+        let local = source
+            .strip_prefix("path:")
+            .map(|p| (p, false))
+            .or_else(|| source.strip_prefix("editable:").map(|p| (p, true)));
 
-    // Changing to the installation directory
-    std::env::set_current_dir(&install_dir).expect("Failed to change directory");
+        match local {
+            Some((path, editable)) => {
+                let path = std::fs::canonicalize(path)
+                    .map_err(|e| format!("Local reachy-mini path '{}' is not accessible: {}", path, e))?;
+                validate_reachy_mini_checkout(&path)?;
+                Ok(Self::Local { path, editable })
+            }
+            None if source == "pypi" => Ok(Self::Pypi),
+            None => Ok(Self::GitHub(source.to_string())),
+        }
+    }
 
-    // Install uv
+    fn is_github(&self) -> bool {
+        matches!(self, Self::GitHub(_))
+    }
+
+    /// Requirement replacing `reachy-mini[...]`, keeping the extras
+    fn requirement(&self, extras: &str) -> Option<String> {
+        match self {
+            Self::Pypi => None,
+            Self::GitHub(branch) => Some(format!(
+                "git+https://github.com/pollen-robotics/reachy_mini.git@{}{}",
+                branch, extras
+            )),
+            Self::Local { path, editable } => {
+                let editable_flag = if *editable { "-e " } else { "" };
+                // Quote the path, it may contain spaces
+                Some(format!("{}'{}{}'", editable_flag, path.display(), extras))
+            }
+        }
+    }
+}
+
+/// Check that a local directory is a reachy-mini package checkout
+fn validate_reachy_mini_checkout(path: &std::path::Path) -> Result<(), String> {
+    let pyproject = path.join("pyproject.toml");
+    let content = std::fs::read_to_string(&pyproject)
+        .map_err(|e| format!("No pyproject.toml found in {:?}: {}", path, e))?;
+
+    let is_reachy_mini = content.lines().any(|line| {
+        let line = line.replace(' ', "");
+        line == "name=\"reachy_mini\"" || line == "name=\"reachy-mini\""
+    });
+
+    if !is_reachy_mini {
+        return Err(format!("{:?} is not a reachy-mini package checkout", path));
+    }
+
+    Ok(())
+}
+
+/// Download the latest uv into the current directory
+fn download_uv() {
     #[cfg(not(target_os = "windows"))]
     run_command(
         "curl -LsSf https://astral.sh/uv/install.sh | env UV_INSTALL_DIR=. UV_NO_MODIFY_PATH=1 sh",
@@ -55,6 +137,56 @@ fn main() {
         
         println!("✅ uv installed successfully on Windows");
     }
+}
+
+fn main() {
+    let args = Args::parse();
+
+    // Resolve the source before changing directory so relative local paths work
+    let reachy_mini_source = match ReachyMiniSource::parse(&args.reachy_mini_source) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // Inherited by every uv command run below
+    for (env, value) in [
+        (uv_wrapper::CONCURRENT_DOWNLOADS_ENV, args.concurrent_downloads),
+        (uv_wrapper::CONCURRENT_INSTALLS_ENV, args.concurrent_installs),
+    ] {
+        if let Some(value) = value {
+            println!("⚙️  {}={}", env, value);
+            std::env::set_var(env, value.to_string());
+        }
+    }
+
+    // Resolved before changing directory so a relative path works
+    let local_uv = args.uv_binary.as_ref().map(|path| match uv_wrapper::check_uv(path) {
+        Ok(version) => {
+            println!("📦 Using local {} from {:?}", version, path);
+            std::fs::canonicalize(path).expect("Failed to resolve the uv binary path")
+        }
+        Err((_, e)) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    });
+
+    let install_dir = args.install_dir.clone();
+    let python_version = python_request(&args.python_version, args.python_arch.as_deref());
+
+    // Changing to the installation directory
+    std::env::set_current_dir(&install_dir).expect("Failed to change directory");
+
+    // Install uv
+    match local_uv {
+        Some(uv) => {
+            std::fs::copy(&uv, uv_wrapper::UV_EXE).expect("Failed to copy the uv binary");
+        }
+        None => download_uv(),
+    }
 
     // Install Python using uv
     #[cfg(not(target_os = "windows"))]
@@ -72,42 +204,44 @@ fn main() {
 
     // Creating a venv
     #[cfg(not(target_os = "windows"))]
-    run_command("UV_PYTHON_INSTALL_DIR=. UV_WORKING_DIR=. ./uv venv")
-        .expect("Failed to create virtual environment");
+    run_command(&format!(
+        "UV_PYTHON_INSTALL_DIR=. UV_WORKING_DIR=. ./uv venv --python {}",
+        python_version
+    ))
+    .expect("Failed to create virtual environment");
     #[cfg(target_os = "windows")]
-    run_command("$env:UV_PYTHON_INSTALL_DIR = '.'; $env:UV_WORKING_DIR = '.'; ./uv.exe venv")
-        .expect("Failed to create virtual environment");
+    run_command(&format!(
+        "$env:UV_PYTHON_INSTALL_DIR = '.'; $env:UV_WORKING_DIR = '.'; ./uv.exe venv --python {}",
+        python_version
+    ))
+    .expect("Failed to create virtual environment");
 
     // Installing dependencies
     if !args.dependencies.is_empty() {
         let mut deps = args.dependencies;
         
-        // Replace reachy-mini with GitHub version if a branch is specified (not "pypi")
-        let is_github_source = args.reachy_mini_source != "pypi";
-        if is_github_source {
-            let branch = &args.reachy_mini_source;
-            let github_url = format!("git+https://github.com/pollen-robotics/reachy_mini.git@{}", branch);
-            deps = deps
-                .iter()
-                .map(|dep| {
-                    // Replace reachy-mini[...] with git+https://...@<branch>[...]
-                    if dep.starts_with("reachy-mini") {
-                        if let Some(extras_start) = dep.find('[') {
-                            // Has extras like [placo_kinematics]
-                            let extras = &dep[extras_start..];
-                            format!("{}{}", github_url, extras)
-                        } else {
-                            // No extras
-                            github_url.clone()
-                        }
-                    } else {
-                        dep.clone()
-                    }
-                })
-                .collect();
-        }
+        // Replace reachy-mini with the GitHub branch or local checkout if one is specified (not "pypi")
+        let is_github_source = reachy_mini_source.is_github();
+        deps = deps
+            .iter()
+            .map(|dep| {
+                // Replace reachy-mini[...] with git+https://...@<branch>[...] or <local path>[...]
+                if dep.starts_with("reachy-mini") {
+                    // Keep extras like [placo_kinematics]
+                    let extras = dep.find('[').map(|start| &dep[start..]).unwrap_or("");
+                    reachy_mini_source
+                        .requirement(extras)
+                        .unwrap_or_else(|| dep.clone())
+                } else {
+                    dep.clone()
+                }
+            })
+            .collect();
         
-        let deps_str = deps.join(" ");
+        let mut deps_str = deps.join(" ");
+        if args.verbose {
+            deps_str.push_str(" --verbose");
+        }
         #[cfg(not(target_os = "windows"))]
         {
             // For GitHub installs, configure git to skip LFS smudge to avoid errors with missing LFS files
@@ -3,11 +3,14 @@ use std::path::PathBuf;
 use std::process::{Command, ExitCode};
 use std::fs;
 
-use uv_wrapper::{find_cpython_folder, lookup_bin_folder, patching_pyvenv_cfg};
+use uv_wrapper::{find_cpython_folder, lookup_bin_folder, patching_pyvenv_cfg, verbose_requested, Layout};
 
 #[cfg(not(target_os = "windows"))]
 use signal_hook::{consts::TERM_SIGNALS, flag::register};
 
+/// Subcommands of `uv python`, to tell them apart from a bare "python" interpreter
+const UV_PYTHON_SUBCOMMANDS: &[&str] = &["list", "install", "upgrade", "find", "pin", "dir", "uninstall"];
+
 /// Determines possible folders according to the platform
 /// 
 /// The uv installation script can install the executable:
@@ -111,36 +114,6 @@ fn resign_all_venv_binaries(venv_dir: &PathBuf, signing_identity: &str) -> Resul
             }
         });
     
-    // Helper to find files recursively
-    fn find_files(dir: &PathBuf, pattern: &str) -> Result<Vec<PathBuf>, String> {
-        let mut files = Vec::new();
-        
-        if !dir.exists() {
-            return Ok(files);
-        }
-        
-        let entries = fs::read_dir(dir)
-            .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?;
-        
-        for entry in entries {
-            let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
-            let path = entry.path();
-            
-            if path.is_dir() {
-                let mut sub_files = find_files(&path, pattern)?;
-                files.append(&mut sub_files);
-            } else if path.is_file() {
-                if let Some(file_name) = path.file_name() {
-                    if file_name.to_string_lossy().ends_with(&pattern[2..]) {
-                        files.push(path);
-                    }
-                }
-            }
-        }
-        
-        Ok(files)
-    }
-    
     // Helper to sign a binary with optional entitlements
     fn sign_binary_with_entitlements(
         binary_path: &PathBuf, 
@@ -233,7 +206,7 @@ fn resign_all_venv_binaries(venv_dir: &PathBuf, signing_identity: &str) -> Resul
     }
     
     // Sign all .dylib files
-    let dylib_files = find_files(venv_dir, "*.dylib")?;
+    let dylib_files = uv_wrapper::find_files(venv_dir, "*.dylib")?;
     for dylib_file in dylib_files {
         // Skip libpython if already signed above
         if dylib_file == libpython {
@@ -256,7 +229,7 @@ fn resign_all_venv_binaries(venv_dir: &PathBuf, signing_identity: &str) -> Resul
     }
     
     // Sign all .so files (Python extensions)
-    let so_files = find_files(venv_dir, "*.so")?;
+    let so_files = uv_wrapper::find_files(venv_dir, "*.so")?;
     for so_file in so_files {
         if sign_binary_with_entitlements(&so_file, signing_identity, None)? {
             signed_count += 1;
@@ -283,16 +256,19 @@ fn resign_all_venv_binaries(_venv_dir: &PathBuf, _signing_identity: &str) -> Res
 fn main() -> ExitCode {
     let args = env::args().skip(1).collect::<Vec<String>>();
 
-    let uv_exe = if cfg!(target_os = "windows") {
-        "uv.exe"
-    } else {
-        "uv"
-    };
+    let uv_exe = uv_wrapper::UV_EXE;
     
     let possible_folders = get_possible_bin_folders();
+    let mut layout = Layout {
+        exe_folder: uv_wrapper::get_current_folder(),
+        searched: uv_wrapper::possible_abs_bin(&possible_folders),
+        ..Default::default()
+    };
     let uv_folder = match lookup_bin_folder(&possible_folders, uv_exe) {
         Some(folder) => folder,
         None => {
+            layout.error = Some(format!("Unable to find '{}'", uv_exe));
+            layout.write_manifest();
             eprintln!("❌ Error: Unable to find '{}' in the following locations:", uv_exe);
             for folder in &possible_folders {
                 eprintln!("   - {}", folder);
@@ -305,24 +281,35 @@ fn main() -> ExitCode {
         }
     };
 
+    layout.uv_folder = Some(uv_folder.clone());
+
     if let Err(e) = env::set_current_dir(&uv_folder) {
+        layout.error = Some(format!("Unable to change working directory: {}", e));
+        layout.write_manifest();
         eprintln!("❌ Error: Unable to change working directory to {:?}: {}", uv_folder, e);
         return ExitCode::FAILURE;
     }
 
     println!("📂 Running from {:?}", uv_folder);
 
-    let cpython_folder = match find_cpython_folder(&uv_folder) {
+    let venv_dir = uv_wrapper::venv_dir(&uv_folder);
+    let cpython_folder = match find_cpython_folder(&uv_folder, &venv_dir) {
         Ok(folder) => folder,
         Err(e) => {
+            layout.error = Some(e.clone());
+            layout.write_manifest();
             eprintln!("❌ Error: Unable to find cpython folder: {}", e);
             return ExitCode::FAILURE;
         }
     };
     
-    if let Err(e) = patching_pyvenv_cfg(&uv_folder, &cpython_folder) {
+    layout.cpython_folder = Some(cpython_folder.clone());
+    
+    if let Err(e) = patching_pyvenv_cfg(&uv_folder, &venv_dir, &cpython_folder) {
         // Check if this is an AppTranslocation error
         if e.contains("APP_TRANSLOCATION_ERROR") {
+            layout.error = Some(e.clone());
+            layout.write_manifest();
             eprintln!("❌ AppTranslocation Error: {}", e);
             eprintln!("");
             eprintln!("📱 Please move the app to the Applications folder:");
@@ -341,20 +328,28 @@ fn main() -> ExitCode {
     let working_dir = match env::current_dir() {
         Ok(dir) => dir,
         Err(e) => {
+            layout.error = Some(format!("Unable to get working directory: {}", e));
+            layout.write_manifest();
             eprintln!("❌ Error: Unable to get working directory: {}", e);
             return ExitCode::FAILURE;
         }
     };
+    layout.venv_dir = Some(uv_wrapper::venv_dir(&working_dir));
+    layout.working_dir = Some(working_dir.clone());
+    layout.write_manifest();
 
     // Check if the first argument is a Python executable path (e.g., .venv/bin/python3)
     // If so, execute it directly instead of passing through uv
     println!("🔍 Checking args: {:?}", args);
-    let mut cmd = if !args.is_empty() && (args[0].contains("python") || args[0].contains("mjpython")) {
+    // `python install|find|list|...` are uv subcommands, not an interpreter
+    let is_uv_python_subcommand = args.first().map(|a| a == "python").unwrap_or(false)
+        && args.get(1).map(|a| UV_PYTHON_SUBCOMMANDS.contains(&a.as_str())).unwrap_or(false);
+    let mut cmd = if !args.is_empty() && !is_uv_python_subcommand && (args[0].contains("python") || args[0].contains("mjpython")) {
         println!("✅ Detected Python executable: {}", args[0]);
         // First argument is a Python executable - execute it directly
         let python_path = if args[0].starts_with("/") || args[0].starts_with(".") {
             // Relative or absolute path - resolve relative to working_dir
-            let python_exe = working_dir.join(&args[0]);
+            let python_exe = uv_wrapper::resolve_venv_path(&working_dir, &uv_wrapper::venv_dir(&working_dir), &args[0]);
             println!("🔍 Resolved Python path: {:?}", python_exe);
             if !python_exe.exists() {
                 eprintln!("❌ Error: Python executable not found at {:?}", python_exe);
@@ -382,8 +377,8 @@ fn main() -> ExitCode {
                         .unwrap_or(false);
                     
                     // In production: verify that binaries are signed with correct entitlements
-                    // In dev: skip signing/verification entirely
-                    if is_production {
+                    // In dev (or developer mode): skip signing/verification entirely
+                    if is_production && !uv_wrapper::skip_signing_requested() {
                         // Check signature
                         let check_signature = Command::new("codesign")
                             .arg("-d")
@@ -435,6 +430,12 @@ fn main() -> ExitCode {
         cmd.env("UV_WORKING_DIR", &working_dir)
            .env("UV_PYTHON_INSTALL_DIR", &working_dir)
            .args(&args[1..]); // Pass remaining arguments
+        // Relative paths of the daemon (datasets, app assets) resolve from the
+        // directory chosen by the user, the uv folder stays ours
+        if let Some(daemon_dir) = uv_wrapper::daemon_working_dir() {
+            println!("📂 Python working directory: {:?}", daemon_dir);
+            cmd.current_dir(daemon_dir);
+        }
         cmd
     } else {
         println!("ℹ️  Using normal uv command execution");
@@ -443,7 +444,13 @@ fn main() -> ExitCode {
     let mut cmd = Command::new(&uv_exe_path);
     cmd.env("UV_WORKING_DIR", &working_dir)
        .env("UV_PYTHON_INSTALL_DIR", &working_dir)
+       .env("VIRTUAL_ENV", uv_wrapper::venv_dir(&working_dir))
        .args(&args);
+        // Detailed resolution/download logs when requested by the app
+        if verbose_requested() {
+            println!("🔊 Verbose uv logging enabled");
+            cmd.arg("--verbose");
+        }
         cmd
     };
     
@@ -543,7 +550,7 @@ fn main() -> ExitCode {
                                 };
                                 
                                             // Find .venv directory (working_dir is already set to Contents/Resources in production)
-                                            let venv_dir = working_dir.join(".venv");
+                                            let venv_dir = uv_wrapper::venv_dir(&working_dir);
                                             
                                             if venv_dir.exists() {
                                     // Re-sign all binaries with entitlements
@@ -1,5 +1,130 @@
 use std::{env, process::Command};
 
+/// Environment variable set by the app to request verbose uv output
+pub const VERBOSE_ENV: &str = "REACHY_MINI_UV_VERBOSE";
+
+/// Whether verbose uv logging was requested by the app
+pub fn verbose_requested() -> bool {
+    env::var(VERBOSE_ENV).map(|v| v == "1").unwrap_or(false)
+}
+
+/// Environment variable set by the app in developer mode to skip codesign steps
+pub const SKIP_SIGNING_ENV: &str = "REACHY_MINI_SKIP_SIGNING";
+
+/// Whether the app asked to skip signature checks (developer mode)
+pub fn skip_signing_requested() -> bool {
+    env::var(SKIP_SIGNING_ENV).map(|v| v == "1").unwrap_or(false)
+}
+
+/// uv's own concurrency controls, passed through to lower the memory and
+/// network spike of large installs on small machines
+pub const CONCURRENT_DOWNLOADS_ENV: &str = "UV_CONCURRENT_DOWNLOADS";
+pub const CONCURRENT_INSTALLS_ENV: &str = "UV_CONCURRENT_INSTALLS";
+
+/// Environment variable pointing to a venv relocated outside the uv folder
+pub const VENV_ENV: &str = "REACHY_MINI_VENV";
+
+/// Location of the venv: the REACHY_MINI_VENV override, or .venv in the uv folder
+pub fn venv_dir(uv_folder: &std::path::Path) -> std::path::PathBuf {
+    env::var_os(VENV_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| uv_folder.join(".venv"))
+}
+
+/// Resolve a path given relative to the uv folder (e.g. ".venv/bin/python3"),
+/// ".venv" standing for `venv_dir` (which may be relocated elsewhere)
+pub fn resolve_venv_path(uv_folder: &std::path::Path, venv_dir: &std::path::Path, path: &str) -> std::path::PathBuf {
+    match path.strip_prefix(".venv/").or_else(|| path.strip_prefix(".venv\\")) {
+        Some(rest) => venv_dir.join(rest),
+        None => uv_folder.join(path),
+    }
+}
+
+/// Environment variable set by the app to run the Python process (the daemon)
+/// from another directory than the uv folder the trampoline works in
+pub const DAEMON_CWD_ENV: &str = "REACHY_MINI_DAEMON_CWD";
+
+/// Working directory requested for the Python process, if any
+pub fn daemon_working_dir() -> Option<std::path::PathBuf> {
+    env::var_os(DAEMON_CWD_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(std::path::PathBuf::from)
+}
+
+/// Environment variable set by the app to the file the trampoline describes
+/// its resolved layout in (not written when unset)
+pub const LAYOUT_FILE_ENV: &str = "REACHY_MINI_LAYOUT_FILE";
+
+/// Folders the trampoline resolved, written for the app's diagnostics
+#[derive(Default)]
+pub struct Layout {
+    /// Folder of the trampoline executable, base of the relative candidates
+    pub exe_folder: std::path::PathBuf,
+    /// Absolute candidates searched for uv, in order
+    pub searched: Vec<std::path::PathBuf>,
+    pub uv_folder: Option<std::path::PathBuf>,
+    pub cpython_folder: Option<String>,
+    /// Directory the trampoline switched to before running the command
+    pub working_dir: Option<std::path::PathBuf>,
+    pub venv_dir: Option<std::path::PathBuf>,
+    /// Why resolution stopped, None when everything was found
+    pub error: Option<String>,
+}
+
+/// Quote a string as a JSON string literal
+fn json_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn json_path(path: &std::path::Path) -> String {
+    json_string(&path.to_string_lossy())
+}
+
+fn json_option<T>(value: Option<T>, to_json: impl FnOnce(T) -> String) -> String {
+    value.map(to_json).unwrap_or_else(|| "null".to_string())
+}
+
+impl Layout {
+    pub fn to_json(&self) -> String {
+        let searched: Vec<String> = self.searched.iter().map(|p| json_path(p)).collect();
+        format!(
+            "{{\"exe_folder\":{},\"searched\":[{}],\"uv_folder\":{},\"cpython_folder\":{},\"working_dir\":{},\"venv_dir\":{},\"error\":{}}}",
+            json_path(&self.exe_folder),
+            searched.join(","),
+            json_option(self.uv_folder.as_deref(), json_path),
+            json_option(self.cpython_folder.as_deref(), json_string),
+            json_option(self.working_dir.as_deref(), json_path),
+            json_option(self.venv_dir.as_deref(), json_path),
+            json_option(self.error.as_deref(), json_string),
+        )
+    }
+
+    /// Write the manifest where the app asked for it, best effort
+    pub fn write_manifest(&self) {
+        let Some(path) = env::var_os(LAYOUT_FILE_ENV).filter(|p| !p.is_empty()) else {
+            return;
+        };
+        if let Err(e) = std::fs::write(&path, self.to_json()) {
+            eprintln!("⚠️  Unable to write layout manifest {:?}: {}", path, e);
+        }
+    }
+}
+
 /// Gets the folder containing the current executable
 /// 
 /// Returns the parent directory of the executable, or the current directory
@@ -24,11 +149,89 @@ pub fn lookup_bin_folder(possible_folders: &[&str], bin: &str) -> Option<std::pa
     None
 }
 
-fn possible_abs_bin(possible_folders: &[&str]) -> Vec<std::path::PathBuf> {
+/// Absolute candidates for the given folders, relative to the executable
+pub fn possible_abs_bin(possible_folders: &[&str]) -> Vec<std::path::PathBuf> {
     let cur_folder = get_current_folder();
     possible_folders.iter().map(|p| cur_folder.join(p)).collect()
 }
 
+/// File name of the bundled uv binary
+pub const UV_EXE: &str = if cfg!(target_os = "windows") { "uv.exe" } else { "uv" };
+
+/// Why a uv binary that exists on disk can't be used
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UvFailure {
+    Missing,
+    /// No execute permission (lost when the archive was extracted)
+    NotExecutable,
+    /// Built for another CPU or not an executable at all (damaged download)
+    WrongArchitecture,
+    /// Started but exited with an error or was killed (e.g. by code signing)
+    Crashed,
+    /// Ran but didn't print a uv version
+    UnexpectedOutput,
+}
+
+impl UvFailure {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UvFailure::Missing => "missing",
+            UvFailure::NotExecutable => "not_executable",
+            UvFailure::WrongArchitecture => "wrong_architecture",
+            UvFailure::Crashed => "crashed",
+            UvFailure::UnexpectedOutput => "unexpected_output",
+        }
+    }
+}
+
+/// OS errors for a binary of the wrong format: ENOEXEC, EBADARCH on macOS,
+/// ERROR_BAD_EXE_FORMAT and ERROR_EXE_MACHINE_TYPE_MISMATCH on Windows
+fn is_bad_executable_error(e: &std::io::Error) -> bool {
+    match e.raw_os_error() {
+        Some(code) if cfg!(target_os = "windows") => code == 193 || code == 216,
+        Some(code) if cfg!(target_os = "macos") => code == 8 || code == 86,
+        Some(code) => code == 8,
+        None => false,
+    }
+}
+
+/// Run `uv --version` to check that the binary actually runs on this machine
+/// Returns the version line (e.g. "uv 0.5.4 (c62c83c37 2024-11-20)")
+pub fn check_uv(uv_path: &std::path::Path) -> Result<String, (UvFailure, String)> {
+    if !uv_path.is_file() {
+        return Err((UvFailure::Missing, format!("{} does not exist", uv_path.display())));
+    }
+
+    let output = Command::new(uv_path).arg("--version").output().map_err(|e| {
+        let failure = if e.kind() == std::io::ErrorKind::PermissionDenied {
+            UvFailure::NotExecutable
+        } else if is_bad_executable_error(&e) {
+            UvFailure::WrongArchitecture
+        } else {
+            UvFailure::Crashed
+        };
+        (failure, format!("Unable to run {}: {}", uv_path.display(), e))
+    })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err((
+            UvFailure::Crashed,
+            format!("{} --version failed ({}): {}", uv_path.display(), output.status, stderr.trim()),
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let version = stdout.lines().next().unwrap_or("").trim();
+    if !version.starts_with("uv ") {
+        return Err((
+            UvFailure::UnexpectedOutput,
+            format!("{} --version printed {:?} instead of a uv version", uv_path.display(), version),
+        ));
+    }
+    Ok(version.to_string())
+}
+
 pub fn run_command(cmd: &str) -> Result<std::process::ExitStatus, std::io::Error> {
     println!("Running command: {}", cmd);
 
@@ -44,10 +247,36 @@ pub fn run_command(cmd: &str) -> Result<std::process::ExitStatus, std::io::Error
     Command::new("sh").arg("-c").arg(cmd).status()
 }
 
-pub fn find_cpython_folder(uv_folder: &std::path::Path) -> Result<String, String> {
+/// cpython folder the venv was created from, taken from pyvenv.cfg's `home`
+/// (only the folder name is used, the absolute path may be stale)
+fn venv_cpython_folder(uv_folder: &std::path::Path, venv_dir: &std::path::Path) -> Option<String> {
+    let home = read_pyvenv_home(venv_dir)?;
+    std::path::Path::new(&home)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .find(|name| name.starts_with("cpython-"))
+        .filter(|name| uv_folder.join(name).is_dir())
+}
+
+/// Find the interpreter folder, preferring the one the venv uses when
+/// several are installed (e.g. after rebuilding the venv for another arch)
+pub fn find_cpython_folder(uv_folder: &std::path::Path, venv_dir: &std::path::Path) -> Result<String, String> {
+    if let Some(folder) = venv_cpython_folder(uv_folder, venv_dir) {
+        return Ok(folder);
+    }
+
+    list_cpython_folders(uv_folder)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| format!("Unable to find cpython folder in {:?}", uv_folder))
+}
+
+/// Every interpreter folder (cpython-*) installed in the uv folder
+pub fn list_cpython_folders(uv_folder: &std::path::Path) -> Result<Vec<String>, String> {
     let entries = std::fs::read_dir(uv_folder)
         .map_err(|e| format!("Unable to read uv folder for cpython lookup: {}", e))?;
 
+    let mut folders = Vec::new();
     for entry in entries {
         let entry = entry
             .map_err(|e| format!("Unable to read entry in uv folder: {}", e))?;
@@ -55,14 +284,37 @@ pub fn find_cpython_folder(uv_folder: &std::path::Path) -> Result<String, String
         let file_name_str = file_name.to_string_lossy();
 
         if file_name_str.starts_with("cpython-") && entry.path().is_dir() {
-            return Ok(file_name_str.to_string());
+            folders.push(file_name_str.to_string());
         }
     }
 
-    Err(format!(
-        "Unable to find cpython folder in {:?}",
-        uv_folder
-    ))
+    Ok(folders)
+}
+
+/// Python executable of an interpreter folder
+pub fn cpython_executable(uv_folder: &std::path::Path, cpython_folder: &str) -> std::path::PathBuf {
+    let exe = if cfg!(target_os = "windows") { "python.exe" } else { "python3" };
+    pyvenv_home(uv_folder, cpython_folder).join(exe)
+}
+
+/// Python version of a cpython folder (cpython-<version>-<os>-<arch>-<libc>)
+pub fn cpython_folder_version(cpython_folder: &str) -> Option<&str> {
+    cpython_folder.split('-').nth(1)
+}
+
+/// Architecture of a cpython folder, as named by uv ("x86_64", "aarch64")
+pub fn cpython_folder_arch(cpython_folder: &str) -> Option<&str> {
+    cpython_folder.split('-').nth(3)
+}
+
+/// uv python request for a version, pinned to an architecture when given
+/// (e.g. "cpython-3.12-macos-x86_64-none")
+pub fn python_request(version: &str, arch: Option<&str>) -> String {
+    let libc = if cfg!(target_os = "linux") { "gnu" } else { "none" };
+    match arch {
+        Some(arch) => format!("cpython-{}-{}-{}-{}", version, env::consts::OS, arch, libc),
+        None => version.to_string(),
+    }
 }
 
 /// Check if the current path is in AppTranslocation (macOS security feature)
@@ -76,8 +328,26 @@ pub fn is_app_translocation_path(_path: &std::path::Path) -> bool {
     false
 }
 
-pub fn patching_pyvenv_cfg(uv_folder: &std::path::Path, cpython_folder: &str) -> Result<(), String> {
-    let pyvenv_cfg_path = uv_folder.join(".venv").join("pyvenv.cfg");
+/// Current `home` entry of the venv's pyvenv.cfg, if any
+pub fn read_pyvenv_home(venv_dir: &std::path::Path) -> Option<String> {
+    let content = std::fs::read_to_string(venv_dir.join("pyvenv.cfg")).ok()?;
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("home = "))
+        .map(|home| home.trim().to_string())
+}
+
+/// Interpreter folder pyvenv.cfg's `home` must point to
+pub fn pyvenv_home(uv_folder: &std::path::Path, cpython_folder: &str) -> std::path::PathBuf {
+    #[cfg(target_os = "windows")]
+    return uv_folder.join(cpython_folder);
+    #[cfg(not(target_os = "windows"))]
+    uv_folder.join(cpython_folder).join("bin")
+}
+
+/// Point the `home` of `venv_dir`'s pyvenv.cfg to an interpreter folder of the uv folder
+pub fn patching_pyvenv_cfg(uv_folder: &std::path::Path, venv_dir: &std::path::Path, cpython_folder: &str) -> Result<(), String> {
+    let pyvenv_cfg_path = venv_dir.join("pyvenv.cfg");
     
     // Check if file exists before trying to patch it
     if !pyvenv_cfg_path.exists() {
@@ -87,15 +357,10 @@ pub fn patching_pyvenv_cfg(uv_folder: &std::path::Path, cpython_folder: &str) ->
         ));
     }
     
-    println!("🔧 Patching pyvenv.cfg at {:?}", pyvenv_cfg_path);
-
     let content = std::fs::read_to_string(&pyvenv_cfg_path)
         .map_err(|e| format!("Unable to read pyvenv.cfg for patching: {}", e))?;
 
-    #[cfg(target_os = "windows")]
-    let home = uv_folder.join(cpython_folder);
-    #[cfg(not(target_os = "windows"))]
-    let home = uv_folder.join(cpython_folder).join("bin");
+    let home = pyvenv_home(uv_folder, cpython_folder);
 
     let new_content = content
         .lines()
@@ -109,10 +374,25 @@ pub fn patching_pyvenv_cfg(uv_folder: &std::path::Path, cpython_folder: &str) ->
         .collect::<Vec<String>>()
         .join("\n");
 
-    // Try to write the patched file
-    match std::fs::write(&pyvenv_cfg_path, new_content) {
+    // Nothing to do when home already points to the interpreter
+    if new_content == content.trim_end_matches('\n') {
+        return Ok(());
+    }
+
+    println!("🔧 Patching pyvenv.cfg at {:?}", pyvenv_cfg_path);
+
+    // Keep the previous version, then write atomically so an interrupted
+    // patch never leaves a truncated pyvenv.cfg behind
+    let backup_path = pyvenv_cfg_path.with_extension("cfg.bak");
+    let tmp_path = pyvenv_cfg_path.with_extension("cfg.tmp");
+    let result = std::fs::copy(&pyvenv_cfg_path, &backup_path)
+        .and_then(|_| std::fs::write(&tmp_path, &new_content))
+        .and_then(|_| std::fs::rename(&tmp_path, &pyvenv_cfg_path));
+
+    match result {
         Ok(_) => Ok(()),
         Err(e) => {
+            let _ = std::fs::remove_file(&tmp_path);
             let error_msg = format!("Unable to write patched pyvenv.cfg: {}", e);
     
             // Check if we're in AppTranslocation and the error is read-only
@@ -128,3 +408,109 @@ pub fn patching_pyvenv_cfg(uv_folder: &std::path::Path, cpython_folder: &str) ->
     }
 }
 
+/// Directories nested deeper than this are skipped by `find_files`
+const MAX_FIND_DEPTH: usize = 64;
+
+/// Identity of a directory, used to detect symlink cycles
+#[cfg(unix)]
+fn dir_identity(path: &std::path::Path) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn dir_identity(path: &std::path::Path) -> Option<std::path::PathBuf> {
+    std::fs::canonicalize(path).ok()
+}
+
+/// Find files with the extension of `pattern` (e.g. "*.so" matches "x.so", not "x.xso")
+///
+/// Walks the tree with an explicit work queue rather than recursion, skips
+/// directories already visited (symlink loops) and stops at MAX_FIND_DEPTH.
+pub fn find_files(dir: &std::path::Path, pattern: &str) -> Result<Vec<std::path::PathBuf>, String> {
+    let extension = pattern.strip_prefix("*.").unwrap_or(pattern);
+    let mut files = Vec::new();
+
+    if !dir.exists() {
+        return Ok(files);
+    }
+
+    let mut visited = std::collections::HashSet::new();
+    let mut pending = vec![(dir.to_path_buf(), 0)];
+
+    while let Some((current, depth)) = pending.pop() {
+        if let Some(identity) = dir_identity(&current) {
+            if !visited.insert(identity) {
+                continue;
+            }
+        }
+
+        let entries = std::fs::read_dir(&current)
+            .map_err(|e| format!("Failed to read directory {}: {}", current.display(), e))?;
+
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+            let path = entry.path();
+
+            if path.is_dir() {
+                if depth + 1 > MAX_FIND_DEPTH {
+                    eprintln!("⚠️  Skipping {} (deeper than {} levels)", path.display(), MAX_FIND_DEPTH);
+                    continue;
+                }
+                pending.push((path, depth + 1));
+            } else if path.is_file() && path.extension().is_some_and(|ext| ext == extension) {
+                files.push(path);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scratch directory removed on drop
+    struct ScratchDir(std::path::PathBuf);
+
+    impl ScratchDir {
+        fn new(name: &str) -> Self {
+            let dir = env::temp_dir().join(format!("uv-wrapper-{}-{}", name, std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            ScratchDir(dir)
+        }
+    }
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn repatches_a_relocated_venv() {
+        let scratch = ScratchDir::new("relocated-venv");
+        let uv_folder = scratch.0.join("uv");
+        let cpython_folder = "cpython-3.12.8-macos-aarch64-none";
+        std::fs::create_dir_all(pyvenv_home(&uv_folder, cpython_folder)).unwrap();
+        let venv = uv_folder.join(".venv");
+        std::fs::create_dir_all(&venv).unwrap();
+        std::fs::write(venv.join("pyvenv.cfg"), "home = /old/place/cpython-3.12.8-macos-aarch64-none/bin\nversion = 3.12.8\n").unwrap();
+
+        // Relocate: the venv no longer sits in the uv folder
+        let relocated = scratch.0.join("elsewhere").join(".venv");
+        std::fs::create_dir_all(relocated.parent().unwrap()).unwrap();
+        std::fs::rename(&venv, &relocated).unwrap();
+
+        let found = find_cpython_folder(&uv_folder, &relocated).unwrap();
+        assert_eq!(found, cpython_folder);
+        patching_pyvenv_cfg(&uv_folder, &relocated, &found).unwrap();
+
+        let home = pyvenv_home(&uv_folder, cpython_folder).display().to_string();
+        assert_eq!(read_pyvenv_home(&relocated), Some(home));
+        assert!(!venv.exists());
+    }
+}